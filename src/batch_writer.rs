@@ -0,0 +1,170 @@
+//! [`BatchWriter<R>`]: row builders that emit bounded `RecordBatch`es.
+//!
+//! `BuildRows::new_builders` grows one set of builders until `finish` is called.
+//! `BatchWriter` caps each batch by row count and, optionally, by memory, handing
+//! back a finished batch as soon as a cap is reached. Memory is measured after every
+//! row with [`ReusableRowBuilder::memory_size`], so rows of very different sizes
+//! still produce batches of about the same size.
+//!
+//! ```
+//! use typed_arrow::{batch_writer::BatchWriter, prelude::*};
+//!
+//! #[derive(Record)]
+//! struct Row {
+//!     id: i64,
+//!     name: String,
+//! }
+//!
+//! let mut writer = BatchWriter::<Row>::new(4);
+//! let mut batches = Vec::new();
+//! for id in 0..10 {
+//!     let row = Row {
+//!         id,
+//!         name: format!("r{id}"),
+//!     };
+//!     if let Some(batch) = writer.write(row) {
+//!         batches.push(batch);
+//!     }
+//! }
+//! batches.extend(writer.finish());
+//!
+//! let sizes: Vec<usize> = batches.iter().map(|b| b.num_rows()).collect();
+//! assert_eq!(sizes, vec![4, 4, 2]);
+//! ```
+
+use arrow_array::RecordBatch;
+
+use crate::{
+    error::AppendError,
+    schema::{BuildRows, IntoRecordBatch, ReusableRowBuilder, RowBuilder},
+};
+#[cfg(feature = "views")]
+use crate::{
+    error::ViewAccessError,
+    schema::{AppendViewRow, StructView},
+};
+
+/// Rows the builders are sized for up front; they grow past this as rows arrive.
+const INITIAL_CAPACITY: usize = 1024;
+
+/// Accepts rows of `R` and emits a `RecordBatch` each time a size cap is reached.
+pub struct BatchWriter<R: BuildRows> {
+    builders: R::Builders,
+    pending: usize,
+    max_rows: usize,
+    max_bytes: Option<usize>,
+}
+
+impl<R: BuildRows> BatchWriter<R> {
+    /// Create a writer that emits a batch every `max_rows` rows.
+    ///
+    /// The builders reserve room for at most 1024 rows up front, so a writer capped
+    /// only by [`with_max_bytes`](Self::with_max_bytes) can pass `usize::MAX`.
+    ///
+    /// # Panics
+    /// Panics if `max_rows` is zero.
+    #[must_use]
+    pub fn new(max_rows: usize) -> Self {
+        assert!(max_rows > 0, "BatchWriter max_rows must be positive");
+        Self {
+            builders: R::new_builders(max_rows.min(INITIAL_CAPACITY)),
+            pending: 0,
+            max_rows,
+            max_bytes: None,
+        }
+    }
+
+    /// Also emit a batch once the builders hold `max_bytes` or more.
+    ///
+    /// The size is checked after each row, so a batch exceeds `max_bytes` by at most
+    /// its last row, and always holds at least one row.
+    #[must_use]
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Rows appended since the last emitted batch.
+    #[must_use]
+    pub fn pending_rows(&self) -> usize {
+        self.pending
+    }
+}
+
+impl<R: BuildRows> BatchWriter<R>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    /// Bytes held by the builders for the pending rows; see
+    /// [`ReusableRowBuilder::memory_size`].
+    #[must_use]
+    pub fn pending_bytes(&self) -> usize {
+        self.builders.memory_size()
+    }
+
+    /// Append a row, returning the finished batch if it filled one.
+    pub fn write(&mut self, row: R) -> Option<RecordBatch> {
+        self.builders.append_row(row);
+        self.pending += 1;
+        self.emit_if_full()
+    }
+
+    /// Append a row, returning an error instead of panicking when a value cannot be
+    /// represented. A rejected row is not written.
+    ///
+    /// # Errors
+    /// Returns the [`AppendError`] from [`RowBuilder::try_append_row`].
+    pub fn try_write(&mut self, row: R) -> Result<Option<RecordBatch>, AppendError> {
+        self.builders.try_append_row(row)?;
+        self.pending += 1;
+        Ok(self.emit_if_full())
+    }
+
+    /// Append a row view, e.g. from a batch read earlier, without converting it to an
+    /// owned row, returning the finished batch if it filled one.
+    ///
+    /// # Errors
+    /// Returns the `ViewAccessError` from [`AppendViewRow::append_view`]; the view is
+    /// not counted as a row.
+    #[cfg(feature = "views")]
+    pub fn write_view(&mut self, view: R::View<'_>) -> Result<Option<RecordBatch>, ViewAccessError>
+    where
+        R: StructView,
+        R::Builders: AppendViewRow<R>,
+    {
+        self.builders.append_view(view)?;
+        self.pending += 1;
+        Ok(self.emit_if_full())
+    }
+
+    /// Append every row, returning the batches filled along the way.
+    pub fn write_rows<I: IntoIterator<Item = R>>(&mut self, rows: I) -> Vec<RecordBatch> {
+        rows.into_iter().filter_map(|row| self.write(row)).collect()
+    }
+
+    /// Emit the pending rows as a batch, or `None` if there are none.
+    pub fn flush(&mut self) -> Option<RecordBatch> {
+        if self.pending == 0 {
+            return None;
+        }
+        self.pending = 0;
+        Some(self.builders.finish_and_reset().into_record_batch())
+    }
+
+    /// Emit the remaining rows, or `None` if there are none.
+    #[must_use]
+    pub fn finish(mut self) -> Option<RecordBatch> {
+        self.flush()
+    }
+
+    fn emit_if_full(&mut self) -> Option<RecordBatch> {
+        let over_bytes = self
+            .max_bytes
+            .is_some_and(|max| self.builders.memory_size() >= max);
+        if self.pending >= self.max_rows || over_bytes {
+            self.flush()
+        } else {
+            None
+        }
+    }
+}
@@ -31,6 +31,12 @@ impl ArrowBinding for Vec<u8> {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::byte_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -78,6 +84,12 @@ impl<const N: usize> super::ArrowBinding for [u8; N] {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        b.values_slice().len() + super::column::validity_size(b.validity_slice())
+    }
 }
 
 #[cfg(feature = "views")]
@@ -156,6 +168,12 @@ impl ArrowBinding for LargeBinary {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::byte_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -183,3 +201,43 @@ impl ArrowBindingView for LargeBinary {
         Ok(array.value(index))
     }
 }
+
+#[cfg(feature = "views")]
+impl super::AppendView for Vec<u8> {
+    #[inline]
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError> {
+        b.append_value(v);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "views")]
+impl<const N: usize> super::AppendView for [u8; N] {
+    #[inline]
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError> {
+        b.append_value(v)
+            .map_err(|_| crate::schema::ViewAccessError::TypeMismatch {
+                expected: <Self as ArrowBinding>::data_type(),
+                actual: DataType::FixedSizeBinary(i32::try_from(v.len()).unwrap_or(i32::MAX)),
+                field_name: None,
+            })
+    }
+}
+
+#[cfg(feature = "views")]
+impl super::AppendView for LargeBinary {
+    #[inline]
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError> {
+        b.append_value(v);
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
-//! Column-level helpers: `data_type_of<R, I>()` and `ColumnBuilder<R, I>`.
+//! Column-level helpers: `data_type_of<R, I>()`, `estimated_value_size<T>()` and
+//! `ColumnBuilder<R, I>`.
 
 use std::marker::PhantomData;
 
+use arrow_array::{
+    ArrowPrimitiveType,
+    builder::{GenericByteBuilder, PrimitiveBuilder},
+    types::ByteArrayType,
+};
 use arrow_schema::DataType;
 
 use super::ArrowBinding;
@@ -16,6 +22,41 @@ where
     <<R as ColAt<I>>::Native as ArrowBinding>::data_type()
 }
 
+/// Estimated bytes one value of `T` occupies once appended: the fixed width of its
+/// Arrow type (summed over struct fields) plus [`ArrowBinding::estimated_bytes_per_value`].
+#[must_use]
+pub fn estimated_value_size<T: ArrowBinding>() -> usize {
+    fixed_width(&T::data_type()) + T::estimated_bytes_per_value()
+}
+
+fn fixed_width(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Struct(fields) => fields.iter().map(|f| fixed_width(f.data_type())).sum(),
+        DataType::FixedSizeBinary(n) => usize::try_from(*n).unwrap_or(0),
+        DataType::FixedSizeList(item, n) => {
+            usize::try_from(*n).unwrap_or(0) * fixed_width(item.data_type())
+        }
+        other => other.primitive_width().unwrap_or(0),
+    }
+}
+
+/// Bytes held by a primitive builder's value and validity buffers.
+pub(crate) fn primitive_builder_size<T: ArrowPrimitiveType>(b: &PrimitiveBuilder<T>) -> usize {
+    std::mem::size_of_val(b.values_slice()) + validity_size(b.validity_slice())
+}
+
+/// Bytes held by a string or binary builder's value, offset and validity buffers.
+pub(crate) fn byte_builder_size<T: ByteArrayType>(b: &GenericByteBuilder<T>) -> usize {
+    b.values_slice().len()
+        + std::mem::size_of_val(b.offsets_slice())
+        + validity_size(b.validity_slice())
+}
+
+/// Bytes held by a validity bitmap; `None` until the first null is appended.
+pub(crate) fn validity_size(validity: Option<&[u8]>) -> usize {
+    validity.map_or(0, <[u8]>::len)
+}
+
 /// A typed column builder for column `I` of record `R`.
 pub struct ColumnBuilder<R: Record + ColAt<I>, const I: usize>
 where
@@ -5,6 +5,7 @@ use arrow_array::Array;
 use arrow_array::{
     Decimal128Array, Decimal256Array,
     builder::{Decimal128Builder, Decimal256Builder},
+    types::{Decimal128Type, Decimal256Type, DecimalType},
 };
 use arrow_buffer::i256;
 use arrow_schema::DataType;
@@ -12,9 +13,37 @@ use arrow_schema::DataType;
 use super::ArrowBinding;
 #[cfg(feature = "views")]
 use super::ArrowBindingView;
+use crate::error::{AppendError, DecimalPrecisionError};
+
+fn check_precision_128<const P: u8>(value: i128) -> Result<(), DecimalPrecisionError> {
+    if Decimal128Type::is_valid_decimal_precision(value, P) {
+        Ok(())
+    } else {
+        Err(DecimalPrecisionError {
+            value: value.to_string(),
+            precision: P,
+        })
+    }
+}
+
+fn check_precision_256<const P: u8>(value: i256) -> Result<(), DecimalPrecisionError> {
+    if Decimal256Type::is_valid_decimal_precision(value, P) {
+        Ok(())
+    } else {
+        Err(DecimalPrecisionError {
+            value: value.to_string(),
+            precision: P,
+        })
+    }
+}
 
 /// Fixed-precision decimal stored in 128 bits.
 /// The value is represented as a scaled integer of type `i128`.
+///
+/// [`new`](Self::new) does not check the value against `P`; use
+/// [`try_new`](Self::try_new) or the builders' `try_append_row` to reject values
+/// that Arrow would consider invalid. Views return stored values unchecked; pass
+/// a read value through [`checked`](Self::checked) to verify it.
 pub struct Decimal128<const P: u8, const S: i8>(i128);
 impl<const P: u8, const S: i8> Decimal128<P, S> {
     /// Construct a new `Decimal128<P,S>` from a scaled integer value.
@@ -23,6 +52,33 @@ impl<const P: u8, const S: i8> Decimal128<P, S> {
     pub fn new(value: i128) -> Self {
         Self(value)
     }
+    /// Construct a new `Decimal128<P,S>`, rejecting values with more than `P` digits.
+    ///
+    /// # Errors
+    /// Returns [`DecimalPrecisionError`] if `value` does not fit precision `P`.
+    #[inline]
+    pub fn try_new(value: i128) -> Result<Self, DecimalPrecisionError> {
+        check_precision_128::<P>(value)?;
+        Ok(Self(value))
+    }
+    /// Whether the scaled value fits the declared precision `P`.
+    #[inline]
+    #[must_use]
+    pub fn fits_precision(&self) -> bool {
+        Decimal128Type::is_valid_decimal_precision(self.0, P)
+    }
+    /// Return `self` if it fits the declared precision `P`.
+    ///
+    /// Views read values as stored; call this on a read value to opt into the
+    /// check. The error converts into `ViewAccessError::DecimalPrecision` with `?`.
+    ///
+    /// # Errors
+    /// Returns [`DecimalPrecisionError`] if the value has more than `P` digits.
+    #[inline]
+    pub fn checked(self) -> Result<Self, DecimalPrecisionError> {
+        check_precision_128::<P>(self.0)?;
+        Ok(self)
+    }
     /// Return the scaled integer value.
     #[inline]
     #[must_use]
@@ -53,6 +109,10 @@ impl<const P: u8, const S: i8> ArrowBinding for Decimal128<P, S> {
         b.append_value(v.0);
     }
 
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        Ok(check_precision_128::<P>(v.0)?)
+    }
+
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
@@ -60,6 +120,12 @@ impl<const P: u8, const S: i8> ArrowBinding for Decimal128<P, S> {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -90,6 +156,8 @@ impl<const P: u8, const S: i8> ArrowBindingView for Decimal128<P, S> {
 
 /// Fixed-precision decimal stored in 256 bits.
 /// The value is represented as a scaled integer of type `i256`.
+///
+/// Precision handling matches [`Decimal128`].
 pub struct Decimal256<const P: u8, const S: i8>(i256);
 impl<const P: u8, const S: i8> Decimal256<P, S> {
     /// Construct a new `Decimal256<P,S>` from a scaled integer value.
@@ -98,6 +166,33 @@ impl<const P: u8, const S: i8> Decimal256<P, S> {
     pub fn new(value: i256) -> Self {
         Self(value)
     }
+    /// Construct a new `Decimal256<P,S>`, rejecting values with more than `P` digits.
+    ///
+    /// # Errors
+    /// Returns [`DecimalPrecisionError`] if `value` does not fit precision `P`.
+    #[inline]
+    pub fn try_new(value: i256) -> Result<Self, DecimalPrecisionError> {
+        check_precision_256::<P>(value)?;
+        Ok(Self(value))
+    }
+    /// Whether the scaled value fits the declared precision `P`.
+    #[inline]
+    #[must_use]
+    pub fn fits_precision(&self) -> bool {
+        Decimal256Type::is_valid_decimal_precision(self.0, P)
+    }
+    /// Return `self` if it fits the declared precision `P`.
+    ///
+    /// Views read values as stored; call this on a read value to opt into the
+    /// check. The error converts into `ViewAccessError::DecimalPrecision` with `?`.
+    ///
+    /// # Errors
+    /// Returns [`DecimalPrecisionError`] if the value has more than `P` digits.
+    #[inline]
+    pub fn checked(self) -> Result<Self, DecimalPrecisionError> {
+        check_precision_256::<P>(self.0)?;
+        Ok(self)
+    }
     /// Return the scaled integer value.
     #[inline]
     #[must_use]
@@ -128,6 +223,10 @@ impl<const P: u8, const S: i8> ArrowBinding for Decimal256<P, S> {
         b.append_value(v.0);
     }
 
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        Ok(check_precision_256::<P>(v.0)?)
+    }
+
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
@@ -135,6 +234,12 @@ impl<const P: u8, const S: i8> ArrowBinding for Decimal256<P, S> {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -162,3 +267,8 @@ impl<const P: u8, const S: i8> ArrowBindingView for Decimal256<P, S> {
         Ok(Decimal256::new(array.value(index)))
     }
 }
+
+#[cfg(feature = "views")]
+impl_append_view_by_value!([const P: u8, const S: i8] Decimal128<P, S>);
+#[cfg(feature = "views")]
+impl_append_view_by_value!([const P: u8, const S: i8] Decimal256<P, S>);
@@ -1,7 +1,13 @@
 //! Dictionary-encoded column bindings and key mapping.
 
 use std::marker::PhantomData;
+#[cfg(not(feature = "arrow-57"))]
+use std::{any::Any, collections::HashSet, sync::Arc};
 
+#[cfg(not(feature = "arrow-57"))]
+use arrow_array::{
+    ArrayRef, DictionaryArray, builder::ArrayBuilder, cast::AsArray, types::ArrowDictionaryKeyType,
+};
 use arrow_array::{
     builder::{
         BinaryDictionaryBuilder, FixedSizeBinaryDictionaryBuilder, LargeBinaryDictionaryBuilder,
@@ -12,9 +18,12 @@ use arrow_array::{
         UInt32Type, UInt64Type,
     },
 };
-use arrow_schema::DataType;
+#[cfg(not(feature = "arrow-57"))]
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType};
 
 use super::{ArrowBinding, binary::LargeBinary, strings::LargeUtf8};
+use crate::error::AppendError;
 
 /// Wrapper denoting an Arrow Dictionary column with key type `K` and values of `V`.
 ///
@@ -23,6 +32,12 @@ use super::{ArrowBinding, binary::LargeBinary, strings::LargeUtf8};
 ///
 /// This prevents accidental reliance on representation details (e.g., raw keys) and
 /// keeps the API focused on appending logical values. The builder handles interning to keys.
+///
+/// Once the key type `K` cannot index another distinct value (e.g. the 129th distinct
+/// value with `i8` keys), `ArrowBinding::append_value` (and so `append_row`) drops the
+/// value, leaving the column a row short, and `AppendView::append_view` panics.
+/// `try_append_value` and `try_append_row` report this as
+/// [`AppendError::DictionaryKeyOverflow`] instead.
 #[repr(transparent)]
 pub struct Dictionary<K, V>(V, PhantomData<K>);
 
@@ -82,6 +97,22 @@ impl_dict_key!(u16, UInt16Type, DataType::UInt16);
 impl_dict_key!(u32, UInt32Type, DataType::UInt32);
 impl_dict_key!(u64, UInt64Type, DataType::UInt64);
 
+/// Map a dictionary builder error to an `AppendError` naming the key type.
+fn dictionary_append_error<K: DictKey>(e: ArrowError) -> AppendError {
+    match e {
+        ArrowError::DictionaryKeyOverflowError => AppendError::DictionaryKeyOverflow {
+            key_type: <K as DictKey>::data_type(),
+        },
+        other => AppendError::Builder {
+            message: other.to_string(),
+        },
+    }
+}
+
+#[cfg(feature = "views")]
+const VIEW_KEY_OVERFLOW: &str = "dictionary key overflow while appending a view; \
+    use a wider key type or convert the view and call try_append_value";
+
 // Utf8 values
 impl<K> ArrowBinding for Dictionary<K, String>
 where
@@ -102,12 +133,20 @@ where
     fn append_value(b: &mut Self::Builder, v: &Self) {
         let _ = b.append(v.value().as_str());
     }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        b.append_n(v.value().as_str(), 0)
+            .map(|_| ())
+            .map_err(dictionary_append_error::<K>)
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 // Binary values
@@ -130,12 +169,20 @@ where
     fn append_value(b: &mut Self::Builder, v: &Self) {
         let _ = b.append(v.value().as_slice());
     }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        b.append_n(v.value().as_slice(), 0)
+            .map(|_| ())
+            .map_err(dictionary_append_error::<K>)
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 // FixedSizeBinary values: [u8; N]
@@ -144,7 +191,10 @@ where
     K: DictKey,
     <K as DictKey>::ArrowKey: arrow_array::types::ArrowDictionaryKeyType,
 {
+    #[cfg(feature = "arrow-57")]
     type Builder = FixedSizeBinaryDictionaryBuilder<<K as DictKey>::ArrowKey>;
+    #[cfg(not(feature = "arrow-57"))]
+    type Builder = FixedSizeBinaryDictBuilder<<K as DictKey>::ArrowKey>;
     type Array = arrow_array::DictionaryArray<<K as DictKey>::ArrowKey>;
     fn data_type() -> DataType {
         DataType::Dictionary(
@@ -156,17 +206,132 @@ where
     }
     fn new_builder(_capacity: usize) -> Self::Builder {
         // Builder enforces width on appended values; pass byte width
-        FixedSizeBinaryDictionaryBuilder::new(i32::try_from(N).expect("width fits i32"))
+        Self::Builder::new(i32::try_from(N).expect("width fits i32"))
     }
     fn append_value(b: &mut Self::Builder, v: &Self) {
         let _ = b.append(*v.value());
     }
+    #[cfg(feature = "arrow-57")]
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        b.append_n(*v.value(), 0)
+            .map(|_| ())
+            .map_err(dictionary_append_error::<K>)
+    }
+    #[cfg(not(feature = "arrow-57"))]
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        if b.can_take(v.value()) {
+            Ok(())
+        } else {
+            Err(dictionary_append_error::<K>(
+                ArrowError::DictionaryKeyOverflowError,
+            ))
+        }
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+}
+
+/// Dictionary builder for `[u8; N]` values on Arrow 55 and 56, whose
+/// `FixedSizeBinaryDictionaryBuilder` cannot intern a value without appending a key.
+///
+/// It counts distinct values as they are appended, so `try_append_value` can tell
+/// whether the key type is full without finishing the builder.
+#[cfg(not(feature = "arrow-57"))]
+#[derive(Debug)]
+pub struct FixedSizeBinaryDictBuilder<K: ArrowDictionaryKeyType> {
+    inner: FixedSizeBinaryDictionaryBuilder<K>,
+    distinct: usize,
+    // Values of a full dictionary, collected once: no value can be added after that.
+    full: Option<HashSet<Vec<u8>>>,
+}
+
+#[cfg(not(feature = "arrow-57"))]
+impl<K: ArrowDictionaryKeyType> FixedSizeBinaryDictBuilder<K> {
+    /// Create an empty builder for values of `byte_width` bytes.
+    #[must_use]
+    pub fn new(byte_width: i32) -> Self {
+        Self {
+            inner: FixedSizeBinaryDictionaryBuilder::new(byte_width),
+            distinct: 0,
+            full: None,
+        }
+    }
+
+    /// Append a value, returning its key.
+    ///
+    /// # Errors
+    /// Returns `ArrowError::DictionaryKeyOverflowError` if the value is new and the key
+    /// type cannot index it, or an error if the value has the wrong width.
+    pub fn append(&mut self, value: impl AsRef<[u8]>) -> Result<K::Native, ArrowError> {
+        let key = self.inner.append(value)?;
+        // New values take the next key; known values reuse a smaller one.
+        if key.as_usize() == self.distinct {
+            self.distinct += 1;
+        }
+        Ok(key)
+    }
+
+    /// Append a null.
+    pub fn append_null(&mut self) {
+        self.inner.append_null();
+    }
+
+    /// Build the array and reset the builder.
+    pub fn finish(&mut self) -> DictionaryArray<K> {
+        self.distinct = 0;
+        self.full = None;
+        self.inner.finish()
+    }
+
+    /// Build the array without resetting the builder.
+    #[must_use]
+    pub fn finish_cloned(&self) -> DictionaryArray<K> {
+        self.inner.finish_cloned()
+    }
+
+    /// Whether appending `value` cannot overflow the key type.
+    fn can_take(&mut self, value: &[u8]) -> bool {
+        if K::Native::from_usize(self.distinct).is_some() {
+            return true;
+        }
+        let inner = &self.inner;
+        self.full
+            .get_or_insert_with(|| {
+                let dict = inner.finish_cloned();
+                let values = dict.values().as_fixed_size_binary();
+                values.iter().flatten().map(<[u8]>::to_vec).collect()
+            })
+            .contains(value)
+    }
+}
+
+#[cfg(not(feature = "arrow-57"))]
+impl<K: ArrowDictionaryKeyType> ArrayBuilder for FixedSizeBinaryDictBuilder<K> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Self::finish(self))
+    }
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Self::finish_cloned(self))
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
 }
 
 // LargeBinary values
@@ -189,12 +354,20 @@ where
     fn append_value(b: &mut Self::Builder, v: &Self) {
         let _ = b.append(v.value().as_slice());
     }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        b.append_n(v.value().as_slice(), 0)
+            .map(|_| ())
+            .map_err(dictionary_append_error::<K>)
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 // LargeUtf8 values
@@ -217,12 +390,20 @@ where
     fn append_value(b: &mut Self::Builder, v: &Self) {
         let _ = b.append(v.value().as_str());
     }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        b.append_n(v.value().as_str(), 0)
+            .map(|_| ())
+            .map_err(dictionary_append_error::<K>)
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append_null();
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 // Primitive values via macro
@@ -244,12 +425,20 @@ macro_rules! impl_dict_primitive_value {
             fn append_value(b: &mut Self::Builder, v: &Self) {
                 let _ = b.append(*v.value());
             }
+            fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+                b.append_n(*v.value(), 0)
+                    .map(|_| ())
+                    .map_err(dictionary_append_error::<K>)
+            }
             fn append_null(b: &mut Self::Builder) {
                 b.append_null();
             }
             fn finish(mut b: Self::Builder) -> Self::Array {
                 b.finish()
             }
+            fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+                b.finish()
+            }
         }
     };
 }
@@ -322,6 +511,49 @@ where
     }
 }
 
+// Views decode to the logical value, which the builder re-interns.
+#[cfg(feature = "views")]
+macro_rules! impl_dict_append_view {
+    ([$($g:tt)*] $v:ty) => {
+        impl<K, $($g)*> super::AppendView for Dictionary<K, $v>
+        where
+            K: DictKey + 'static,
+            <K as DictKey>::ArrowKey: arrow_array::types::ArrowDictionaryKeyType,
+        {
+            fn append_view(
+                b: &mut Self::Builder,
+                v: Self::View<'_>,
+            ) -> Result<(), crate::schema::ViewAccessError> {
+                b.append(v).expect(VIEW_KEY_OVERFLOW);
+                Ok(())
+            }
+        }
+    };
+    ($($v:ty),* $(,)?) => {
+        $( impl_dict_append_view!([] $v); )*
+    };
+}
+
+#[cfg(feature = "views")]
+impl_dict_append_view!(
+    String,
+    Vec<u8>,
+    LargeBinary,
+    LargeUtf8,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+);
+#[cfg(feature = "views")]
+impl_dict_append_view!([const N: usize] [u8; N]);
+
 // TryFrom implementations for converting views to owned Dictionary types
 // Note: Dictionary<K, V> only stores V at runtime; K is a compile-time marker
 // for the encoding strategy. The view is just V::View, so we convert from that.
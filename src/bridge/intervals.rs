@@ -54,6 +54,12 @@ impl ArrowBinding for IntervalYearMonth {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -123,6 +129,12 @@ impl ArrowBinding for IntervalDayTime {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -192,6 +204,12 @@ impl ArrowBinding for IntervalMonthDayNano {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -219,3 +237,6 @@ impl ArrowBindingView for IntervalMonthDayNano {
         Ok(IntervalMonthDayNano::new(array.value(index)))
     }
 }
+
+#[cfg(feature = "views")]
+impl_append_view_by_value!(IntervalYearMonth, IntervalDayTime, IntervalMonthDayNano);
@@ -5,7 +5,8 @@ use arrow_schema::{DataType, Field};
 
 use super::ArrowBinding;
 #[cfg(feature = "views")]
-use super::ArrowBindingView;
+use super::{AppendView, ArrowBindingView};
+use crate::error::AppendError;
 
 /// Wrapper denoting an Arrow `ListArray` column with elements of `T`.
 ///
@@ -76,12 +77,27 @@ where
         }
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        v.0.iter().try_for_each(<T as ArrowBinding>::validate_value)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .try_for_each(|it| <T as ArrowBinding>::reserve_value(b.values(), it))
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        std::mem::size_of_val(b.offsets_slice())
+            + super::column::validity_size(b.validity_slice())
+            + <T as ArrowBinding>::builder_memory_size(b.values_ref())
+    }
 }
 
 /// Iterator over views of list elements.
@@ -261,12 +277,30 @@ where
         }
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .flatten()
+            .try_for_each(<T as ArrowBinding>::validate_value)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .flatten()
+            .try_for_each(|it| <T as ArrowBinding>::reserve_value(b.values(), it))
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        std::mem::size_of_val(b.offsets_slice())
+            + super::column::validity_size(b.validity_slice())
+            + <T as ArrowBinding>::builder_memory_size(b.values_ref())
+    }
 }
 
 /// Iterator over views of list elements with nullable items.
@@ -489,6 +523,13 @@ where
         }
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        v.0.iter().try_for_each(<T as ArrowBinding>::validate_value)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .try_for_each(|it| <T as ArrowBinding>::reserve_value(b.values(), it))
+    }
     fn append_null(b: &mut Self::Builder) {
         for _ in 0..N {
             <T as ArrowBinding>::append_null(b.values());
@@ -498,6 +539,9 @@ where
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 /// Iterator over views of fixed-size list elements.
@@ -718,6 +762,16 @@ where
         }
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .flatten()
+            .try_for_each(<T as ArrowBinding>::validate_value)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .flatten()
+            .try_for_each(|it| <T as ArrowBinding>::reserve_value(b.values(), it))
+    }
     fn append_null(b: &mut Self::Builder) {
         for _ in 0..N {
             <T as ArrowBinding>::append_null(b.values());
@@ -727,6 +781,9 @@ where
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 /// Iterator over views of fixed-size list elements with nullable items.
@@ -963,12 +1020,27 @@ where
         }
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        v.0.iter().try_for_each(<T as ArrowBinding>::validate_value)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .try_for_each(|it| <T as ArrowBinding>::reserve_value(b.values(), it))
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        std::mem::size_of_val(b.offsets_slice())
+            + super::column::validity_size(b.validity_slice())
+            + <T as ArrowBinding>::builder_memory_size(b.values_ref())
+    }
 }
 
 /// Iterator over views of large list elements.
@@ -1148,12 +1220,30 @@ where
         }
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .flatten()
+            .try_for_each(<T as ArrowBinding>::validate_value)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        v.0.iter()
+            .flatten()
+            .try_for_each(|it| <T as ArrowBinding>::reserve_value(b.values(), it))
+    }
     fn append_null(b: &mut Self::Builder) {
         b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        std::mem::size_of_val(b.offsets_slice())
+            + super::column::validity_size(b.validity_slice())
+            + <T as ArrowBinding>::builder_memory_size(b.values_ref())
+    }
 }
 
 /// Iterator over views of large list elements with nullable items.
@@ -1310,3 +1400,61 @@ where
         Ok(LargeListViewNullable::new(values_array, start, end))
     }
 }
+
+// Appending a list view copies each item view into the child builder, then
+// closes the list slot.
+#[cfg(feature = "views")]
+macro_rules! impl_list_append_view {
+    ([$($g:tt)*] $list:ty, non_null) => {
+        impl<T, $($g)*> AppendView for $list
+        where
+            T: AppendView + 'static,
+            <T as ArrowBinding>::Builder: ArrayBuilder,
+        {
+            fn append_view(
+                b: &mut Self::Builder,
+                v: Self::View<'_>,
+            ) -> Result<(), crate::schema::ViewAccessError> {
+                for item in v {
+                    <T as AppendView>::append_view(b.values(), item?)?;
+                }
+                b.append(true);
+                Ok(())
+            }
+        }
+    };
+    ([$($g:tt)*] $list:ty, nullable) => {
+        impl<T, $($g)*> AppendView for $list
+        where
+            T: AppendView + 'static,
+            <T as ArrowBinding>::Builder: ArrayBuilder,
+        {
+            fn append_view(
+                b: &mut Self::Builder,
+                v: Self::View<'_>,
+            ) -> Result<(), crate::schema::ViewAccessError> {
+                for item in v {
+                    match item? {
+                        Some(inner) => <T as AppendView>::append_view(b.values(), inner)?,
+                        None => <T as ArrowBinding>::append_null(b.values()),
+                    }
+                }
+                b.append(true);
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "views")]
+impl_list_append_view!([] List<T>, non_null);
+#[cfg(feature = "views")]
+impl_list_append_view!([] List<Option<T>>, nullable);
+#[cfg(feature = "views")]
+impl_list_append_view!([] LargeList<T>, non_null);
+#[cfg(feature = "views")]
+impl_list_append_view!([] LargeList<Option<T>>, nullable);
+#[cfg(feature = "views")]
+impl_list_append_view!([const N: usize] FixedSizeList<T, N>, non_null);
+#[cfg(feature = "views")]
+impl_list_append_view!([const N: usize] FixedSizeListNullable<T, N>, nullable);
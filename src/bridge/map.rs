@@ -6,6 +6,7 @@ use arrow_array::{MapArray, builder::MapBuilder};
 use arrow_schema::{DataType, Field};
 
 use super::ArrowBinding;
+use crate::error::AppendError;
 
 /// Wrapper denoting an Arrow `MapArray` column with entries `(K, V)`.
 ///
@@ -93,12 +94,29 @@ where
         }
         let _ = b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        for (k, val) in &v.0 {
+            <K as ArrowBinding>::validate_value(k)?;
+            <V as ArrowBinding>::validate_value(val)?;
+        }
+        Ok(())
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        for (k, val) in &v.0 {
+            <K as ArrowBinding>::reserve_value(b.keys(), k)?;
+            <V as ArrowBinding>::reserve_value(b.values(), val)?;
+        }
+        Ok(())
+    }
     fn append_null(b: &mut Self::Builder) {
         let _ = b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 // Provide ArrowBinding for value-nullable variant via Option<V>
@@ -132,12 +150,33 @@ where
         }
         let _ = b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        for (k, val_opt) in &v.0 {
+            <K as ArrowBinding>::validate_value(k)?;
+            if let Some(val) = val_opt {
+                <V as ArrowBinding>::validate_value(val)?;
+            }
+        }
+        Ok(())
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        for (k, val_opt) in &v.0 {
+            <K as ArrowBinding>::reserve_value(b.keys(), k)?;
+            if let Some(val) = val_opt {
+                <V as ArrowBinding>::reserve_value(b.values(), val)?;
+            }
+        }
+        Ok(())
+    }
     fn append_null(b: &mut Self::Builder) {
         let _ = b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 /// Sorted-keys `Map`: entries sourced from `BTreeMap<K, V>`, declaring `keys_sorted = true`.
@@ -205,34 +244,31 @@ where
         }
         let _ = b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        for (k, val) in &v.0 {
+            <K as ArrowBinding>::validate_value(k)?;
+            <V as ArrowBinding>::validate_value(val)?;
+        }
+        Ok(())
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        for (k, val) in &v.0 {
+            <K as ArrowBinding>::reserve_value(b.keys(), k)?;
+            <V as ArrowBinding>::reserve_value(b.values(), val)?;
+        }
+        Ok(())
+    }
     fn append_null(b: &mut Self::Builder) {
         let _ = b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
-        use arrow_array::Array;
-        use arrow_data::ArrayData;
-
-        let map_array = b.finish();
-
-        // MapBuilder always creates maps with sorted=false, so we need to manually update it
-        // Get the existing field and create a new DataType with sorted=true
-        let data = map_array.into_data();
-        let (field, _sorted) = match data.data_type() {
-            DataType::Map(f, _) => (f.clone(), true),
-            _ => unreachable!(),
-        };
-
-        // Reconstruct the MapArray with sorted=true flag
-        // All data is copied from the valid MapArray produced by MapBuilder
-        let new_data = ArrayData::builder(DataType::Map(field, true))
-            .len(data.len())
-            .buffers(data.buffers().to_vec())
-            .child_data(data.child_data().to_vec())
-            .nulls(data.nulls().cloned())
-            .build()
-            .expect("MapArray reconstruction should succeed - all data copied from valid array");
-
-        MapArray::from(new_data)
+        into_sorted(b.finish())
+    }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        into_sorted(b.finish())
+    }
+    fn finish_cloned(b: &Self::Builder) -> Self::Array {
+        into_sorted(b.finish_cloned())
     }
 }
 
@@ -267,35 +303,62 @@ where
         }
         let _ = b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        for (k, val_opt) in &v.0 {
+            <K as ArrowBinding>::validate_value(k)?;
+            if let Some(val) = val_opt {
+                <V as ArrowBinding>::validate_value(val)?;
+            }
+        }
+        Ok(())
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        for (k, val_opt) in &v.0 {
+            <K as ArrowBinding>::reserve_value(b.keys(), k)?;
+            if let Some(val) = val_opt {
+                <V as ArrowBinding>::reserve_value(b.values(), val)?;
+            }
+        }
+        Ok(())
+    }
     fn append_null(b: &mut Self::Builder) {
         let _ = b.append(false);
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
-        use arrow_array::Array;
-        use arrow_data::ArrayData;
-
-        let map_array = b.finish();
-
-        // MapBuilder always creates maps with sorted=false, so we need to manually update it
-        // Get the existing field and create a new DataType with sorted=true
-        let data = map_array.into_data();
-        let (field, _sorted) = match data.data_type() {
-            DataType::Map(f, _) => (f.clone(), true),
-            _ => unreachable!(),
-        };
-
-        // Reconstruct the MapArray with sorted=true flag
-        // All data is copied from the valid MapArray produced by MapBuilder
-        let new_data = ArrayData::builder(DataType::Map(field, true))
-            .len(data.len())
-            .buffers(data.buffers().to_vec())
-            .child_data(data.child_data().to_vec())
-            .nulls(data.nulls().cloned())
-            .build()
-            .expect("MapArray reconstruction should succeed - all data copied from valid array");
-
-        MapArray::from(new_data)
+        into_sorted(b.finish())
+    }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        into_sorted(b.finish())
     }
+    fn finish_cloned(b: &Self::Builder) -> Self::Array {
+        into_sorted(b.finish_cloned())
+    }
+}
+
+/// Mark a `MapArray` produced by `MapBuilder` as sorted.
+///
+/// `MapBuilder` always creates maps with `sorted=false`, so the array is rebuilt
+/// around the same buffers with `sorted=true`.
+fn into_sorted(map_array: MapArray) -> MapArray {
+    use arrow_array::Array;
+    use arrow_data::ArrayData;
+
+    let data = map_array.into_data();
+    let field = match data.data_type() {
+        DataType::Map(f, _) => f.clone(),
+        _ => unreachable!(),
+    };
+
+    // All data is copied from the valid MapArray produced by MapBuilder
+    let new_data = ArrayData::builder(DataType::Map(field, true))
+        .len(data.len())
+        .buffers(data.buffers().to_vec())
+        .child_data(data.child_data().to_vec())
+        .nulls(data.nulls().cloned())
+        .build()
+        .expect("MapArray reconstruction should succeed - all data copied from valid array");
+
+    MapArray::from(new_data)
 }
 
 /// Iterator over views of map entries (key-value pairs).
@@ -812,3 +875,63 @@ where
         Ok(MapViewNullable::new(keys_array, values_array, start, end))
     }
 }
+
+#[cfg(feature = "views")]
+macro_rules! impl_map_append_view {
+    ([$($g:tt)*] $map:ty, [$($kb:tt)*], non_null) => {
+        impl<K, V, $($g)*> super::AppendView for $map
+        where
+            K: super::AppendView + $($kb)* 'static,
+            V: super::AppendView + 'static,
+            <K as ArrowBinding>::Builder: arrow_array::builder::ArrayBuilder,
+            <V as ArrowBinding>::Builder: arrow_array::builder::ArrayBuilder,
+        {
+            fn append_view(
+                b: &mut Self::Builder,
+                v: Self::View<'_>,
+            ) -> Result<(), crate::schema::ViewAccessError> {
+                for entry in v {
+                    let (k, val) = entry?;
+                    <K as super::AppendView>::append_view(b.keys(), k)?;
+                    <V as super::AppendView>::append_view(b.values(), val)?;
+                }
+                let _ = b.append(true);
+                Ok(())
+            }
+        }
+    };
+    ([$($g:tt)*] $map:ty, [$($kb:tt)*], nullable) => {
+        impl<K, V, $($g)*> super::AppendView for $map
+        where
+            K: super::AppendView + $($kb)* 'static,
+            V: super::AppendView + 'static,
+            <K as ArrowBinding>::Builder: arrow_array::builder::ArrayBuilder,
+            <V as ArrowBinding>::Builder: arrow_array::builder::ArrayBuilder,
+        {
+            fn append_view(
+                b: &mut Self::Builder,
+                v: Self::View<'_>,
+            ) -> Result<(), crate::schema::ViewAccessError> {
+                for entry in v {
+                    let (k, val) = entry?;
+                    <K as super::AppendView>::append_view(b.keys(), k)?;
+                    match val {
+                        Some(val) => <V as super::AppendView>::append_view(b.values(), val)?,
+                        None => <V as ArrowBinding>::append_null(b.values()),
+                    }
+                }
+                let _ = b.append(true);
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "views")]
+impl_map_append_view!([const SORTED: bool] Map<K, V, SORTED>, [], non_null);
+#[cfg(feature = "views")]
+impl_map_append_view!([const SORTED: bool] Map<K, Option<V>, SORTED>, [], nullable);
+#[cfg(feature = "views")]
+impl_map_append_view!([] OrderedMap<K, V>, [Ord +], non_null);
+#[cfg(feature = "views")]
+impl_map_append_view!([] OrderedMap<K, Option<V>>, [Ord +], nullable);
@@ -16,9 +16,11 @@
 //!
 //! See tests for end-to-end examples and usage patterns.
 
-use arrow_array::Array;
+use arrow_array::{Array, builder::ArrayBuilder};
 use arrow_schema::DataType;
 
+use crate::error::AppendError;
+
 /// Binding from a Rust type to Arrow typed builders/arrays and `DataType`.
 ///
 /// Implementations of this trait provide a zero-cost, monomorphized mapping
@@ -50,13 +52,153 @@ pub trait ArrowBinding {
     }
 
     /// Append a non-null value to the builder.
+    ///
+    /// Dictionary bindings drop the value when the key type cannot index another
+    /// distinct value; use [`try_append_value`](Self::try_append_value) to handle that
+    /// case.
     fn append_value(b: &mut Self::Builder, v: &Self);
 
+    /// Check that `v` satisfies the declared Arrow type (e.g. decimal precision)
+    /// without touching any builder. Containers check their children.
+    ///
+    /// # Errors
+    /// Returns an `AppendError` describing why the value cannot be appended.
+    #[inline]
+    fn validate_value(_v: &Self) -> Result<(), AppendError> {
+        Ok(())
+    }
+
+    /// Make sure `b` can take `v` without appending it, so that a following
+    /// [`append_value`](Self::append_value) cannot fail.
+    ///
+    /// Dictionary bindings intern the value, reserving its key; containers reserve
+    /// their children. Reserved values that are never appended only leave unused
+    /// dictionary entries behind.
+    ///
+    /// # Errors
+    /// Returns an `AppendError` if the builder cannot accept the value (e.g. dictionary
+    /// key overflow).
+    #[inline]
+    fn reserve_value(_b: &mut Self::Builder, _v: &Self) -> Result<(), AppendError> {
+        Ok(())
+    }
+
+    /// Append a non-null value, reporting invalid values and builder failures
+    /// (e.g. dictionary key overflow) instead of dropping values or writing bad data.
+    ///
+    /// Nothing is appended when an error is returned.
+    ///
+    /// # Errors
+    /// Returns an `AppendError` if the value fails [`validate_value`](Self::validate_value)
+    /// or [`reserve_value`](Self::reserve_value).
+    #[inline]
+    fn try_append_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        Self::validate_value(v)?;
+        Self::reserve_value(b, v)?;
+        Self::append_value(b, v);
+        Ok(())
+    }
+
     /// Append a null to the builder.
     fn append_null(b: &mut Self::Builder);
 
+    /// Append a run of non-null values.
+    ///
+    /// Primitive builders copy the slice in one call; other types append each value.
+    #[inline]
+    fn append_slice(b: &mut Self::Builder, values: &[Self])
+    where
+        Self: Sized,
+    {
+        for v in values {
+            Self::append_value(b, v);
+        }
+    }
+
+    /// Append a run of optional values, with `None` as null.
+    #[inline]
+    fn append_option_slice(b: &mut Self::Builder, values: &[Option<Self>])
+    where
+        Self: Sized,
+    {
+        for v in values {
+            match v {
+                Some(v) => Self::append_value(b, v),
+                None => Self::append_null(b),
+            }
+        }
+    }
+
+    /// Bytes held by the values appended to `b` so far.
+    ///
+    /// Bindings whose builder exposes its buffers sum their lengths; the default
+    /// multiplies the builder's length by [`estimated_value_size`].
+    fn builder_memory_size(b: &Self::Builder) -> usize
+    where
+        Self: Sized,
+        Self::Builder: ArrayBuilder,
+    {
+        ArrayBuilder::len(b) * estimated_value_size::<Self>()
+    }
+
     /// Finish the builder and produce a typed Arrow array.
     fn finish(b: Self::Builder) -> Self::Array;
+
+    /// Finish the values appended so far and leave `b` empty for reuse.
+    ///
+    /// The default swaps in a new builder; the built-in bindings call the builder's
+    /// own `finish`, which resets it in place.
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        Self::finish(std::mem::replace(b, Self::new_builder(0)))
+    }
+
+    /// Build an array from the values appended so far, leaving `b` untouched.
+    ///
+    /// The default uses the builder's own `finish_cloned`; bindings whose
+    /// [`finish`](Self::finish) adjusts the array (e.g. `OrderedMap`) override it.
+    fn finish_cloned(b: &Self::Builder) -> Self::Array
+    where
+        Self::Builder: ArrayBuilder,
+        Self::Array: Clone + 'static,
+    {
+        ArrayBuilder::finish_cloned(b)
+            .as_any()
+            .downcast_ref::<Self::Array>()
+            .expect("builder finishes into its binding's array type")
+            .clone()
+    }
+
+    /// Build an array from owned values.
+    ///
+    /// Primitive types move the `Vec` into the array's value buffer without copying;
+    /// other types append each value to a fresh builder.
+    fn array_from_vec(values: Vec<Self>) -> Self::Array
+    where
+        Self: Sized,
+    {
+        let mut b = Self::new_builder(values.len());
+        for v in &values {
+            Self::append_value(&mut b, v);
+        }
+        Self::finish(b)
+    }
+
+    /// Build an array from optional values, with `None` as null.
+    ///
+    /// Primitive types fill the value buffer and validity bitmap in one pass.
+    fn array_from_options(values: Vec<Option<Self>>) -> Self::Array
+    where
+        Self: Sized,
+    {
+        let mut b = Self::new_builder(values.len());
+        for v in &values {
+            match v {
+                Some(v) => Self::append_value(&mut b, v),
+                None => Self::append_null(&mut b),
+            }
+        }
+        Self::finish(b)
+    }
 }
 
 /// View binding from an Arrow array to borrowed Rust reference types.
@@ -89,6 +231,48 @@ pub trait ArrowBindingView {
     ) -> Result<Self::View<'_>, crate::schema::ViewAccessError>;
 }
 
+/// Append a borrowed view straight into this type's builder.
+///
+/// This copies rows between batches (filtering, re-partitioning) without first
+/// converting each view into an owned value.
+#[cfg(feature = "views")]
+pub trait AppendView: ArrowBinding + ArrowBindingView {
+    /// Append a non-null value read from an array of this type.
+    ///
+    /// # Errors
+    /// Returns the `ViewAccessError` raised while reading nested values (list items,
+    /// map entries). This can only happen for batches that were not validated against
+    /// the record; the builder may then hold a partial value and should be discarded.
+    ///
+    /// # Panics
+    /// Dictionary bindings panic when the key type cannot index another distinct
+    /// value.
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError>;
+}
+
+/// Implement [`AppendView`] for types whose view is the value itself.
+#[cfg(feature = "views")]
+macro_rules! impl_append_view_by_value {
+    ([$($g:tt)*] $ty:ty $(where $($w:tt)*)?) => {
+        impl<$($g)*> super::AppendView for $ty $(where $($w)*)? {
+            #[inline]
+            fn append_view(
+                b: &mut Self::Builder,
+                v: Self::View<'_>,
+            ) -> Result<(), crate::schema::ViewAccessError> {
+                <Self as super::ArrowBinding>::append_value(b, &v);
+                Ok(())
+            }
+        }
+    };
+    ($($ty:ty),* $(,)?) => {
+        $( impl_append_view_by_value!([] $ty); )*
+    };
+}
+
 mod binary;
 mod column;
 mod decimals;
@@ -105,8 +289,10 @@ mod temporal;
 
 // Public re-exports for convenience
 pub use binary::LargeBinary;
-pub use column::{ColumnBuilder, data_type_of};
+pub use column::{ColumnBuilder, data_type_of, estimated_value_size};
 pub use decimals::{Decimal128, Decimal256};
+#[cfg(not(feature = "arrow-57"))]
+pub use dictionary::FixedSizeBinaryDictBuilder;
 pub use dictionary::{DictKey, Dictionary};
 pub use intervals::{IntervalDayTime, IntervalMonthDayNano, IntervalYearMonth};
 pub use lists::{FixedSizeList, FixedSizeListNullable, LargeList, List};
@@ -117,3 +303,5 @@ pub use temporal::{
     Date32, Date64, Duration, Microsecond, Millisecond, Nanosecond, Second, Time32, Time64,
     TimeZoneSpec, Timestamp, TimestampTz, Utc,
 };
+#[cfg(any(feature = "compute-55", feature = "compute-56", feature = "compute-57"))]
+pub(crate) use temporal::{DurationUnitSpec, Time32UnitSpec, Time64UnitSpec, TimeUnitSpec};
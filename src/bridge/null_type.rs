@@ -35,6 +35,9 @@ impl ArrowBinding for Null {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
 }
 
 #[cfg(feature = "views")]
@@ -57,3 +60,6 @@ impl ArrowBindingView for Null {
         Ok(Null)
     }
 }
+
+#[cfg(feature = "views")]
+impl_append_view_by_value!(Null);
@@ -3,13 +3,14 @@
 #[cfg(feature = "views")]
 use arrow_array::Array;
 use arrow_array::{
-    PrimitiveArray,
+    ArrowPrimitiveType, PrimitiveArray,
     builder::PrimitiveBuilder,
     types::{
         Float16Type, Float32Type, Float64Type, Int8Type, Int16Type, Int32Type, Int64Type,
         UInt8Type, UInt16Type, UInt32Type, UInt64Type,
     },
 };
+use arrow_buffer::NullBuffer;
 use arrow_schema::DataType;
 use half::f16;
 
@@ -17,6 +18,29 @@ use super::ArrowBinding;
 #[cfg(feature = "views")]
 use super::ArrowBindingView;
 
+// Value buffer plus a validity bitmap that is omitted when nothing is null.
+fn primitive_from_options<T: ArrowPrimitiveType>(
+    values: Vec<Option<T::Native>>,
+) -> PrimitiveArray<T> {
+    let nulls: NullBuffer = values.iter().map(Option::is_some).collect();
+    let values: Vec<T::Native> = values.into_iter().map(Option::unwrap_or_default).collect();
+    PrimitiveArray::new(values.into(), (nulls.null_count() > 0).then_some(nulls))
+}
+
+// Bulk append of optional values: one pass for the values, one for validity.
+fn primitive_append_options<T: ArrowPrimitiveType>(
+    b: &mut PrimitiveBuilder<T>,
+    values: &[Option<T::Native>],
+) {
+    let natives: Vec<T::Native> = values.iter().map(|v| v.unwrap_or_default()).collect();
+    if values.iter().all(Option::is_some) {
+        b.append_slice(&natives);
+    } else {
+        let valid: Vec<bool> = values.iter().map(Option::is_some).collect();
+        b.append_values(&natives, &valid);
+    }
+}
+
 // Primitive integers/floats
 macro_rules! impl_primitive_binding {
     ($rust:ty, $atype:ty, $dt:expr) => {
@@ -43,6 +67,30 @@ macro_rules! impl_primitive_binding {
             fn finish(mut b: Self::Builder) -> Self::Array {
                 b.finish()
             }
+            #[inline]
+            fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+                b.finish()
+            }
+            #[inline]
+            fn builder_memory_size(b: &Self::Builder) -> usize {
+                super::column::primitive_builder_size(b)
+            }
+            #[inline]
+            fn append_slice(b: &mut Self::Builder, values: &[Self]) {
+                b.append_slice(values);
+            }
+            #[inline]
+            fn append_option_slice(b: &mut Self::Builder, values: &[Option<Self>]) {
+                primitive_append_options(b, values);
+            }
+            #[inline]
+            fn array_from_vec(values: Vec<Self>) -> Self::Array {
+                PrimitiveArray::new(values.into(), None)
+            }
+            #[inline]
+            fn array_from_options(values: Vec<Option<Self>>) -> Self::Array {
+                primitive_from_options(values)
+            }
         }
 
         #[cfg(feature = "views")]
@@ -70,6 +118,9 @@ macro_rules! impl_primitive_binding {
                 Ok(array.value(index))
             }
         }
+
+        #[cfg(feature = "views")]
+        impl_append_view_by_value!($rust);
     };
 }
 
@@ -108,6 +159,30 @@ impl ArrowBinding for f16 {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    #[inline]
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    #[inline]
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
+    #[inline]
+    fn append_slice(b: &mut Self::Builder, values: &[Self]) {
+        b.append_slice(values);
+    }
+    #[inline]
+    fn append_option_slice(b: &mut Self::Builder, values: &[Option<Self>]) {
+        primitive_append_options(b, values);
+    }
+    #[inline]
+    fn array_from_vec(values: Vec<Self>) -> Self::Array {
+        PrimitiveArray::new(values.into(), None)
+    }
+    #[inline]
+    fn array_from_options(values: Vec<Option<Self>>) -> Self::Array {
+        primitive_from_options(values)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -157,9 +232,21 @@ impl ArrowBinding for bool {
         b.append_null();
     }
     #[inline]
+    fn append_slice(b: &mut Self::Builder, values: &[Self]) {
+        b.append_slice(values);
+    }
+    #[inline]
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    #[inline]
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    #[inline]
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        b.values_slice().len() + super::column::validity_size(b.validity_slice())
+    }
 }
 
 #[cfg(feature = "views")]
@@ -187,3 +274,6 @@ impl ArrowBindingView for bool {
         Ok(array.value(index))
     }
 }
+
+#[cfg(feature = "views")]
+impl_append_view_by_value!(f16, bool);
@@ -5,10 +5,13 @@ use arrow_schema::DataType;
 
 use super::ArrowBinding;
 #[cfg(feature = "views")]
-use super::ArrowBindingView;
+use super::{AppendView, ArrowBindingView};
 #[cfg(feature = "views")]
-use crate::schema::StructView;
-use crate::schema::{AppendStruct, AppendStructRef, Record, StructMeta};
+use crate::schema::{AppendStructView, StructView};
+use crate::{
+    error::AppendError,
+    schema::{AppendStruct, AppendStructRef, Record, StructMeta},
+};
 
 // Any `T` implementing `Record + StructMeta` automatically binds to a typed
 // Arrow `StructArray`, with a `StructBuilder` produced by `new_builder()`. The
@@ -35,6 +38,12 @@ where
         <T as AppendStructRef>::append_borrowed_into(v, b);
         b.append(true);
     }
+    fn validate_value(v: &Self) -> Result<(), AppendError> {
+        <T as AppendStructRef>::validate_fields(v)
+    }
+    fn reserve_value(b: &mut Self::Builder, v: &Self) -> Result<(), AppendError> {
+        <T as AppendStructRef>::reserve_fields(v, b)
+    }
     fn append_null(b: &mut Self::Builder) {
         // Append nulls to children to keep lengths aligned, then mark null
         <T as AppendStruct>::append_null_into(b);
@@ -43,6 +52,12 @@ where
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        <T as StructMeta>::struct_builder_memory_size(b)
+    }
 }
 
 // Blanket impl of ArrowBindingView for structs that implement StructView
@@ -79,3 +94,24 @@ where
         <T as StructView>::view_at(array, index)
     }
 }
+
+#[cfg(feature = "views")]
+impl<T> AppendView for T
+where
+    T: Record
+        + StructMeta
+        + AppendStruct
+        + AppendStructRef
+        + StructView
+        + AppendStructView
+        + 'static,
+{
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError> {
+        <T as AppendStructView>::append_view_into(v, b)?;
+        b.append(true);
+        Ok(())
+    }
+}
@@ -49,6 +49,14 @@ impl ArrowBinding for String {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    #[inline]
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    #[inline]
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::byte_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -151,6 +159,14 @@ impl ArrowBinding for LargeUtf8 {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    #[inline]
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    #[inline]
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::byte_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -178,3 +194,27 @@ impl ArrowBindingView for LargeUtf8 {
         Ok(array.value(index))
     }
 }
+
+#[cfg(feature = "views")]
+impl super::AppendView for String {
+    #[inline]
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError> {
+        b.append_value(v);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "views")]
+impl super::AppendView for LargeUtf8 {
+    #[inline]
+    fn append_view(
+        b: &mut Self::Builder,
+        v: Self::View<'_>,
+    ) -> Result<(), crate::schema::ViewAccessError> {
+        b.append_value(v);
+        Ok(())
+    }
+}
@@ -111,6 +111,12 @@ impl<U: TimeUnitSpec> ArrowBinding for Timestamp<U> {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -190,7 +196,16 @@ impl<U: TimeUnitSpec, Z: TimeZoneSpec> ArrowBinding for TimestampTz<U, Z> {
         b.append_null();
     }
     fn finish(mut b: Self::Builder) -> Self::Array {
-        b.finish()
+        b.finish().with_timezone_opt(Z::NAME)
+    }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish().with_timezone_opt(Z::NAME)
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
+    fn finish_cloned(b: &Self::Builder) -> Self::Array {
+        b.finish_cloned().with_timezone_opt(Z::NAME)
     }
 }
 
@@ -263,6 +278,12 @@ impl ArrowBinding for Date32 {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -332,6 +353,12 @@ impl ArrowBinding for Date64 {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -424,6 +451,12 @@ where
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -517,6 +550,12 @@ where
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -624,6 +663,12 @@ where
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(feature = "views")]
@@ -679,6 +724,12 @@ impl ArrowBinding for jiff::Timestamp {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(all(feature = "jiff", feature = "views"))]
@@ -739,6 +790,12 @@ impl ArrowBinding for jiff::civil::Date {
     fn finish(mut b: Self::Builder) -> Self::Array {
         b.finish()
     }
+    fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+        b.finish()
+    }
+    fn builder_memory_size(b: &Self::Builder) -> usize {
+        super::column::primitive_builder_size(b)
+    }
 }
 
 #[cfg(all(feature = "jiff", feature = "views"))]
@@ -768,3 +825,21 @@ impl ArrowBindingView for jiff::civil::Date {
             .map_err(|e| crate::schema::ViewAccessError::Custom(Box::new(e)))
     }
 }
+
+#[cfg(feature = "views")]
+impl_append_view_by_value!([U: TimeUnitSpec + 'static] Timestamp<U>);
+#[cfg(feature = "views")]
+impl_append_view_by_value!([U: TimeUnitSpec + 'static, Z: TimeZoneSpec + 'static] TimestampTz<U, Z>);
+#[cfg(feature = "views")]
+impl_append_view_by_value!(Date32, Date64);
+#[cfg(feature = "views")]
+impl_append_view_by_value!([U: Time32UnitSpec + 'static] Time32<U>
+    where U::Arrow: arrow_array::types::ArrowPrimitiveType<Native = i32>);
+#[cfg(feature = "views")]
+impl_append_view_by_value!([U: Time64UnitSpec + 'static] Time64<U>
+    where U::Arrow: arrow_array::types::ArrowPrimitiveType<Native = i64>);
+#[cfg(feature = "views")]
+impl_append_view_by_value!([U: DurationUnitSpec + 'static] Duration<U>
+    where U::Arrow: arrow_array::types::ArrowPrimitiveType<Native = i64>);
+#[cfg(all(feature = "jiff", feature = "views"))]
+impl_append_view_by_value!(jiff::Timestamp, jiff::civil::Date);
@@ -0,0 +1,438 @@
+//! Typed filter, take, sort and column aggregates over record batches (requires one
+//! of the `compute-55`/`compute-56`/`compute-57` features).
+//!
+//! The free functions check a plain [`RecordBatch`] against `R` once; the same
+//! operations are available as methods on [`TypedBatch<R>`], which skip the check.
+//! Every row operation returns a new `TypedBatch<R>` with the input's schema, built by
+//! the `arrow-select` and `arrow-ord` kernels.
+//!
+//! Column aggregates (`sum`, `min`, `max`, `count_nulls`, `distinct_count`) take the
+//! column index `I` of [`ColAt<I>`] and return the column's Rust type, so a summary
+//! of a `Decimal128<P, S>` or `Timestamp<U>` column keeps its precision, scale or unit.
+//!
+//! ```
+//! use typed_arrow::{
+//!     TypedBatch,
+//!     arrow_ord::sort::SortOptions,
+//!     compute::{filter_rows, sort_by},
+//!     prelude::*,
+//! };
+//!
+//! #[derive(Record)]
+//! struct Order {
+//!     id: i64,
+//!     customer: String,
+//!     total: Option<f64>,
+//! }
+//!
+//! let mut b = <Order as BuildRows>::new_builders(3);
+//! b.append_row(Order {
+//!     id: 1,
+//!     customer: "b".into(),
+//!     total: Some(9.5),
+//! });
+//! b.append_row(Order {
+//!     id: 2,
+//!     customer: "a".into(),
+//!     total: None,
+//! });
+//! b.append_row(Order {
+//!     id: 3,
+//!     customer: "a".into(),
+//!     total: Some(20.0),
+//! });
+//! let batch = b.finish().into_record_batch();
+//!
+//! let paid = filter_rows::<Order, _>(&batch, |o| o.total.is_some())?;
+//! assert_eq!(paid.columns().id.values(), &[1, 3]);
+//!
+//! let sorted = sort_by::<Order>(
+//!     &batch,
+//!     &[OrderColumn::Customer, OrderColumn::Id],
+//!     SortOptions {
+//!         descending: true,
+//!         nulls_first: false,
+//!     },
+//! )?;
+//! assert_eq!(sorted.columns().id.values(), &[1, 3, 2]);
+//!
+//! let typed = TypedBatch::<Order>::try_new(batch)?;
+//! assert_eq!(typed.sum::<2>()?, Some(29.5));
+//! assert_eq!(typed.max::<0>(), Some(3));
+//! assert_eq!(typed.count_nulls::<2>(), 1);
+//! assert_eq!(typed.distinct_count::<1>()?, 2);
+//! # Ok::<_, typed_arrow::error::ComputeError>(())
+//! ```
+
+use arrow_array::{
+    Array, ArrowPrimitiveType, PrimitiveArray, RecordBatch, UInt64Array,
+    builder::BooleanBuilder,
+    types::{
+        Date32Type, Date64Type, Decimal128Type, Decimal256Type, Float16Type, Float32Type,
+        Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type, UInt32Type,
+        UInt64Type,
+    },
+};
+use arrow_schema::ArrowError;
+
+use crate::{
+    Date32, Date64, Decimal128, Decimal256, Duration, Time32, Time64, Timestamp, TimestampTz,
+    TypedBatch,
+    arrow_arith::aggregate,
+    arrow_ord::{
+        partition::partition,
+        sort::{SortColumn, SortOptions, lexsort_to_indices, sort},
+    },
+    arrow_select::{filter::filter_record_batch, take::take_record_batch},
+    bridge::{
+        ArrowBinding, DurationUnitSpec, Time32UnitSpec, Time64UnitSpec, TimeUnitSpec, TimeZoneSpec,
+    },
+    error::ComputeError,
+    schema::{ColAt, ColumnRef, FromRecordBatch, Record, RecordColumns, SchemaMeta},
+};
+
+/// Keep the rows of `batch` whose view satisfies `predicate`.
+///
+/// # Errors
+/// Returns [`ComputeError::Schema`] if `batch` does not match `R`, or the first
+/// error raised while reading a row.
+pub fn filter_rows<R, F>(batch: &RecordBatch, predicate: F) -> Result<TypedBatch<R>, ComputeError>
+where
+    R: SchemaMeta + FromRecordBatch,
+    F: for<'a> FnMut(R::View<'a>) -> bool,
+{
+    TypedBatch::<R>::try_new(batch.clone())?.filter_rows(predicate)
+}
+
+/// Gather the rows of `batch` at `indices`, in that order. Indices may repeat.
+///
+/// # Errors
+/// Returns [`ComputeError::Schema`] if `batch` does not match `R`, or
+/// [`ComputeError::Arrow`] if an index is out of bounds.
+pub fn take_rows<R: SchemaMeta>(
+    batch: &RecordBatch,
+    indices: &[usize],
+) -> Result<TypedBatch<R>, ComputeError> {
+    TypedBatch::<R>::try_new(batch.clone())?.take_rows(indices)
+}
+
+/// Sort the rows of `batch` lexicographically by `keys`, applying `options` to each key.
+///
+/// An empty key list leaves the rows in order.
+///
+/// # Errors
+/// Returns [`ComputeError::Schema`] if `batch` does not match `R`, or
+/// [`ComputeError::Arrow`] if a key column has a type Arrow cannot sort.
+pub fn sort_by<R: SchemaMeta + RecordColumns>(
+    batch: &RecordBatch,
+    keys: &[R::Column],
+    options: SortOptions,
+) -> Result<TypedBatch<R>, ComputeError> {
+    TypedBatch::<R>::try_new(batch.clone())?.sort_by(keys, options)
+}
+
+impl<R: FromRecordBatch> TypedBatch<R> {
+    /// Keep the rows whose view satisfies `predicate`.
+    ///
+    /// # Errors
+    /// Returns the first error raised while reading a row.
+    pub fn filter_rows<F>(&self, mut predicate: F) -> Result<Self, ComputeError>
+    where
+        F: for<'a> FnMut(R::View<'a>) -> bool,
+    {
+        let mut mask = BooleanBuilder::with_capacity(self.len());
+        for view in self.iter_views() {
+            mask.append_value(predicate(view?));
+        }
+        let batch = filter_record_batch(self.as_record_batch(), &mask.finish())?;
+        Ok(Self::new_unchecked(batch))
+    }
+}
+
+impl<R: Record> TypedBatch<R> {
+    /// Gather the rows at `indices`, in that order. Indices may repeat.
+    ///
+    /// # Errors
+    /// Returns [`ComputeError::Arrow`] if an index is out of bounds.
+    pub fn take_rows(&self, indices: &[usize]) -> Result<Self, ComputeError> {
+        let len = self.len();
+        if let Some(&index) = indices.iter().find(|&&i| i >= len) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "row index {index} out of bounds (len {len})"
+            ))
+            .into());
+        }
+        let indices: UInt64Array = indices.iter().map(|&i| i as u64).collect();
+        Ok(Self::new_unchecked(take_record_batch(
+            self.as_record_batch(),
+            &indices,
+        )?))
+    }
+}
+
+impl<R: RecordColumns> TypedBatch<R> {
+    /// Sort the rows lexicographically by `keys`, applying `options` to each key.
+    ///
+    /// An empty key list leaves the rows in order.
+    ///
+    /// # Errors
+    /// Returns [`ComputeError::Arrow`] if a key column has a type Arrow cannot sort.
+    pub fn sort_by(&self, keys: &[R::Column], options: SortOptions) -> Result<Self, ComputeError> {
+        if keys.is_empty() {
+            return Ok(self.clone());
+        }
+        let batch = self.as_record_batch();
+        let columns: Vec<SortColumn> = keys
+            .iter()
+            .map(|key| SortColumn {
+                values: batch.column(key.index()).clone(),
+                options: Some(options),
+            })
+            .collect();
+        let indices = lexsort_to_indices(&columns, None)?;
+        Ok(Self::new_unchecked(take_record_batch(batch, &indices)?))
+    }
+}
+
+impl<R: Record> TypedBatch<R> {
+    /// Sum of the non-null values of column `I`, or `None` if there are none.
+    ///
+    /// A decimal sum keeps the column's `P` and `S` but may need more than `P` digits;
+    /// check it with `fits_precision`.
+    ///
+    /// # Errors
+    /// Returns [`ComputeError::Arrow`] if an integer or decimal sum overflows.
+    pub fn sum<const I: usize>(&self) -> Result<Option<R::Native>, ComputeError>
+    where
+        R: ColAt<I, Native: Summable>,
+    {
+        let sum = aggregate::sum_checked(self.primitive_column::<I>())?;
+        Ok(sum.map(PrimitiveValue::from_native))
+    }
+
+    /// Smallest non-null value of column `I`, or `None` if there are none.
+    #[must_use]
+    pub fn min<const I: usize>(&self) -> Option<R::Native>
+    where
+        R: ColAt<I, Native: PrimitiveValue>,
+    {
+        aggregate::min(self.primitive_column::<I>()).map(PrimitiveValue::from_native)
+    }
+
+    /// Largest non-null value of column `I`, or `None` if there are none.
+    #[must_use]
+    pub fn max<const I: usize>(&self) -> Option<R::Native>
+    where
+        R: ColAt<I, Native: PrimitiveValue>,
+    {
+        aggregate::max(self.primitive_column::<I>()).map(PrimitiveValue::from_native)
+    }
+
+    /// Number of null values in column `I`.
+    #[must_use]
+    pub fn count_nulls<const I: usize>(&self) -> usize
+    where
+        R: ColAt<I>,
+    {
+        self.as_record_batch().column(I).logical_null_count()
+    }
+
+    /// Number of distinct non-null values in column `I`.
+    ///
+    /// # Errors
+    /// Returns [`ComputeError::Arrow`] if the column has a type Arrow cannot sort.
+    pub fn distinct_count<const I: usize>(&self) -> Result<usize, ComputeError>
+    where
+        R: ColAt<I>,
+    {
+        let column = self.as_record_batch().column(I);
+        if column.is_empty() {
+            return Ok(0);
+        }
+        let options = SortOptions {
+            descending: false,
+            nulls_first: true,
+        };
+        let sorted = sort(column, Some(options))?;
+        let groups = partition(&[sorted])?.len();
+        Ok(groups - usize::from(column.logical_null_count() > 0))
+    }
+
+    fn primitive_column<const I: usize>(&self) -> &<R::Native as ArrowBinding>::Array
+    where
+        R: ColAt<I, Native: PrimitiveValue>,
+    {
+        self.as_record_batch()
+            .column(I)
+            .as_any()
+            .downcast_ref()
+            .expect("TypedBatch schema conforms to R")
+    }
+}
+
+/// Rust value types stored in a `PrimitiveArray`, which the column aggregates
+/// convert back from Arrow's native representation.
+pub trait PrimitiveValue:
+    ArrowBinding<Array = PrimitiveArray<Self::ArrowType>> + Sized + 'static
+{
+    /// Arrow primitive type of the column.
+    type ArrowType: ArrowPrimitiveType;
+
+    /// Wrap a native Arrow value.
+    fn from_native(value: <Self::ArrowType as ArrowPrimitiveType>::Native) -> Self;
+}
+
+/// Primitive value types whose sum is meaningful: numbers, decimals and durations.
+///
+/// Summing instants is rejected at compile time:
+///
+/// ```compile_fail
+/// use typed_arrow::{Millisecond, Timestamp, TypedBatch, prelude::*};
+///
+/// #[derive(Record)]
+/// struct Event {
+///     at: Timestamp<Millisecond>,
+/// }
+///
+/// fn total(batch: &TypedBatch<Event>) {
+///     let _ = batch.sum::<0>();
+/// }
+/// ```
+pub trait Summable: PrimitiveValue {}
+
+macro_rules! impl_primitive_value {
+    ($($rust:ty => $atype:ty),* $(,)?) => {$(
+        impl PrimitiveValue for $rust {
+            type ArrowType = $atype;
+
+            #[inline]
+            fn from_native(value: <$atype as ArrowPrimitiveType>::Native) -> Self {
+                value
+            }
+        }
+
+        impl Summable for $rust {}
+    )*};
+}
+
+impl_primitive_value!(
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    half::f16 => Float16Type,
+    f32 => Float32Type,
+    f64 => Float64Type,
+);
+
+impl<const P: u8, const S: i8> PrimitiveValue for Decimal128<P, S> {
+    type ArrowType = Decimal128Type;
+
+    #[inline]
+    fn from_native(value: i128) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u8, const S: i8> Summable for Decimal128<P, S> {}
+
+impl<const P: u8, const S: i8> PrimitiveValue for Decimal256<P, S> {
+    type ArrowType = Decimal256Type;
+
+    #[inline]
+    fn from_native(value: crate::arrow_buffer::i256) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u8, const S: i8> Summable for Decimal256<P, S> {}
+
+impl<U: TimeUnitSpec + 'static> PrimitiveValue for Timestamp<U> {
+    type ArrowType = U::Arrow;
+
+    #[inline]
+    fn from_native(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<U, Z> PrimitiveValue for TimestampTz<U, Z>
+where
+    U: TimeUnitSpec + 'static,
+    Z: TimeZoneSpec + 'static,
+{
+    type ArrowType = U::Arrow;
+
+    #[inline]
+    fn from_native(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl PrimitiveValue for Date32 {
+    type ArrowType = Date32Type;
+
+    #[inline]
+    fn from_native(value: i32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl PrimitiveValue for Date64 {
+    type ArrowType = Date64Type;
+
+    #[inline]
+    fn from_native(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<U> PrimitiveValue for Time32<U>
+where
+    U: Time32UnitSpec + 'static,
+    U::Arrow: ArrowPrimitiveType<Native = i32>,
+{
+    type ArrowType = U::Arrow;
+
+    #[inline]
+    fn from_native(value: i32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<U> PrimitiveValue for Time64<U>
+where
+    U: Time64UnitSpec + 'static,
+    U::Arrow: ArrowPrimitiveType<Native = i64>,
+{
+    type ArrowType = U::Arrow;
+
+    #[inline]
+    fn from_native(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<U> PrimitiveValue for Duration<U>
+where
+    U: DurationUnitSpec + 'static,
+    U::Arrow: ArrowPrimitiveType<Native = i64>,
+{
+    type ArrowType = U::Arrow;
+
+    #[inline]
+    fn from_native(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<U> Summable for Duration<U>
+where
+    U: DurationUnitSpec + 'static,
+    U::Arrow: ArrowPrimitiveType<Native = i64>,
+{
+}
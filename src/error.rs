@@ -26,6 +26,18 @@ pub enum SchemaError {
         /// Error message
         message: String,
     },
+    /// Null value where the schema declares a non-nullable field
+    #[error("nullability violation at column {col} ({path}) index {index}: {message}")]
+    Nullability {
+        /// Top-level column index where the violation occurred
+        col: usize,
+        /// Dot-annotated path to the offending field (e.g., `address.city`, `tags[]`)
+        path: String,
+        /// Row or value index where the violation was found
+        index: usize,
+        /// Message describing the violation
+        message: String,
+    },
 }
 
 impl SchemaError {
@@ -49,6 +61,83 @@ impl SchemaError {
     }
 }
 
+/// A decimal value has more digits than its declared precision allows.
+///
+/// Returned by the checked decimal constructors such as
+/// [`Decimal128::try_new`](crate::Decimal128::try_new) and
+/// [`Decimal128::checked`](crate::Decimal128::checked), and carried by
+/// [`AppendError::DecimalPrecision`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("decimal value {value} exceeds precision {precision}")]
+pub struct DecimalPrecisionError {
+    /// Scaled integer value, formatted
+    pub value: String,
+    /// Declared precision `P`
+    pub precision: u8,
+}
+
+/// Error type for fallible appends into typed builders.
+///
+/// Returned by `try_append_value` on [`ArrowBinding`](crate::bridge::ArrowBinding) and by the
+/// `try_append_row`/`try_append_rows` methods generated by `#[derive(Record)]`.
+#[derive(Debug, Clone, Error)]
+pub enum AppendError {
+    /// Decimal value has more digits than the declared precision allows
+    #[error(transparent)]
+    DecimalPrecision(#[from] DecimalPrecisionError),
+    /// Dictionary key type cannot index another distinct value
+    #[error("dictionary key overflow: {key_type} keys cannot index another distinct value")]
+    DictionaryKeyOverflow {
+        /// Arrow key `DataType` of the dictionary
+        key_type: DataType,
+    },
+    /// The underlying Arrow builder rejected the value
+    #[error("builder error: {message}")]
+    Builder {
+        /// Error message from the Arrow builder
+        message: String,
+    },
+    /// Error raised while appending a specific column
+    #[error("column {col} ('{field_name}'): {source}")]
+    Column {
+        /// Zero-based column index
+        col: usize,
+        /// Arrow field name of the column
+        field_name: &'static str,
+        /// Underlying error
+        source: Box<AppendError>,
+    },
+    /// Error raised while appending a specific row of a batch of rows
+    #[error("row {row}: {source}")]
+    Row {
+        /// Zero-based index of the row within the appended iterator
+        row: usize,
+        /// Underlying error
+        source: Box<AppendError>,
+    },
+}
+
+impl AppendError {
+    /// Add column context to this error.
+    #[must_use]
+    pub fn at_column(self, col: usize, field_name: &'static str) -> Self {
+        Self::Column {
+            col,
+            field_name,
+            source: Box::new(self),
+        }
+    }
+
+    /// Add row context to this error.
+    #[must_use]
+    pub fn at_row(self, row: usize) -> Self {
+        Self::Row {
+            row,
+            source: Box::new(self),
+        }
+    }
+}
+
 /// Error type for view access failures when reading from Arrow arrays.
 #[cfg(feature = "views")]
 #[derive(Debug, Error)]
@@ -81,6 +170,12 @@ pub enum ViewAccessError {
         /// Optional field name for context
         field_name: Option<&'static str>,
     },
+    /// Decimal value read from an array has more digits than its declared precision
+    ///
+    /// Views do not check precision themselves; this is returned when a read value
+    /// is passed through [`Decimal128::checked`](crate::Decimal128::checked).
+    #[error(transparent)]
+    DecimalPrecision(#[from] DecimalPrecisionError),
     /// Custom user-defined error from domain-specific validation
     ///
     /// This variant allows custom types (newtypes) to wrap their own error types
@@ -90,6 +185,124 @@ pub enum ViewAccessError {
     Custom(Box<dyn std::error::Error + Send + Sync + 'static>),
 }
 
+/// Error type for the typed batch operations in [`compute`](crate::compute).
+#[cfg(any(feature = "compute-55", feature = "compute-56", feature = "compute-57"))]
+#[derive(Debug, Error)]
+pub enum ComputeError {
+    /// The input batch does not match the record
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row view could not be read while evaluating a predicate
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+    /// The Arrow kernel failed (e.g. an index out of bounds or an unsortable column)
+    #[error("arrow compute error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+}
+
+/// Error type for the typed CSV reader and writer in [`io::csv`](crate::io::csv).
+#[cfg(any(feature = "csv-55", feature = "csv-56", feature = "csv-57"))]
+#[derive(Debug, Error)]
+pub enum CsvError {
+    /// The header or the record's columns cannot be used with CSV
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row could not be read from a batch
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+    /// A cell could not be converted to its column's type
+    #[error("csv row {row}, column `{column}`: cannot read {value:?}: {message}")]
+    Parse {
+        /// Zero-based index of the data row, not counting the header
+        row: usize,
+        /// Name of the column
+        column: String,
+        /// Raw text of the cell
+        value: String,
+        /// What went wrong
+        message: String,
+    },
+    /// Reading, writing or formatting failed
+    #[error("arrow csv error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+}
+
+/// Error type for the typed Arrow IPC readers and writers in [`io::ipc`](crate::io::ipc).
+#[cfg(any(feature = "ipc-55", feature = "ipc-56", feature = "ipc-57"))]
+#[derive(Debug, Error)]
+pub enum IpcError {
+    /// The file schema or a batch does not match the record
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row could not be read from a batch
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+    /// Encoding, decoding or the underlying I/O failed
+    #[error("arrow ipc error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+}
+
+/// Error type for the C Data Interface helpers in [`ffi`](crate::ffi).
+#[cfg(any(feature = "ffi-55", feature = "ffi-56", feature = "ffi-57"))]
+#[derive(Debug, Error)]
+pub enum FfiError {
+    /// An exported or imported batch, or a stream's schema, does not match the record
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row could not be read from a batch
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+    /// Exporting or importing through the C Data Interface failed
+    #[error("arrow ffi error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+}
+
+/// Error type for the typed NDJSON reader and writer in [`io::json`](crate::io::json).
+#[cfg(any(feature = "json-55", feature = "json-56", feature = "json-57"))]
+#[derive(Debug, Error)]
+pub enum JsonError {
+    /// A column of the record cannot be stored in JSON, or a batch does not match it
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row could not be read from a batch
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+    /// Decoding, encoding or the underlying I/O failed
+    #[error("arrow json error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+}
+
+/// Error type for the typed Parquet readers and writers in [`io::parquet`](crate::io::parquet).
+#[cfg(any(feature = "parquet-55", feature = "parquet-56", feature = "parquet-57"))]
+#[derive(Debug, Error)]
+pub enum ParquetError {
+    /// The file schema, a batch or a projection does not match the record
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row could not be read from a batch
+    #[cfg(feature = "views")]
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+    /// Encoding, decoding or the underlying I/O failed
+    #[error("parquet error: {0}")]
+    Parquet(#[from] crate::parquet::errors::ParquetError),
+    /// Decoding a batch failed
+    #[error("arrow error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+}
+
+/// Error type for the stream adapters in [`stream`](crate::stream).
+#[cfg(feature = "futures")]
+#[derive(Debug, Error)]
+pub enum StreamError {
+    /// An incoming batch does not match the record
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A row could not be read from its batch
+    #[error(transparent)]
+    View(#[from] ViewAccessError),
+}
+
 /// Allows generic code to uniformly handle both infallible and fallible view-to-owned conversions.
 ///
 /// When converting views to owned types, primitives and `String` never fail (`TryFrom<Primitive,
@@ -0,0 +1,272 @@
+//! Arrow C Data Interface export and import for typed batches (requires one of the
+//! `ffi-55`/`ffi-56`/`ffi-57` features).
+//!
+//! Exported batches are checked against `R` before they are handed out, and imported
+//! ones are validated as [`TypedBatch<R>`]es, names, types and nested nullability
+//! included, so data crossing to C or C++ code in the same process keeps the
+//! guarantees of `R`.
+//!
+//! - [`export_batch`] and [`export_typed_batch`] move a batch into an
+//!   [`FFI_ArrowArray`] and [`FFI_ArrowSchema`] pair; [`import_batch`] takes one back.
+//! - [`export_rows`] and [`export_batches`] expose rows or batches of `R` as an
+//!   [`FFI_ArrowArrayStream`], which the consumer pulls one batch at a time;
+//!   [`TypedArrowStreamReader`] reads a foreign stream.
+//!
+//! Buffers are shared, not copied: the consumer releases them through the structs'
+//! release callbacks.
+//!
+//! ```
+//! use typed_arrow::{
+//!     batch_writer::BatchWriter,
+//!     ffi::{TypedArrowStreamReader, export_rows, export_typed_batch, import_batch},
+//!     prelude::*,
+//! };
+//!
+//! #[derive(Record)]
+//! struct Tick {
+//!     symbol: String,
+//!     price: Option<f64>,
+//! }
+//!
+//! let ticks = || {
+//!     (0..5).map(|i| Tick {
+//!         symbol: format!("S{i}"),
+//!         price: (i % 2 == 0).then_some(i as f64),
+//!     })
+//! };
+//!
+//! let mut builders = <Tick as BuildRows>::new_builders(5);
+//! builders.append_rows(ticks());
+//! let batch = TypedBatch::<Tick>::try_new(builders.finish().into_record_batch())?;
+//! let (array, schema) = export_typed_batch(&batch)?;
+//! // Safety: both structs were just exported by arrow-rs.
+//! let back = unsafe { import_batch::<Tick>(array, &schema)? };
+//! assert_eq!(back.len(), 5);
+//!
+//! let stream = export_rows(ticks(), BatchWriter::<Tick>::new(2));
+//! let sizes: Vec<usize> = TypedArrowStreamReader::<Tick>::try_new(stream)?
+//!     .map(|batch| batch.map(|b| b.len()))
+//!     .collect::<Result<_, _>>()?;
+//! assert_eq!(sizes, vec![2, 2, 1]);
+//! # Ok::<_, typed_arrow::error::FfiError>(())
+//! ```
+
+use std::{iter::Fuse, marker::PhantomData, sync::Arc};
+
+use arrow_array::{
+    Array, RecordBatch, RecordBatchReader, StructArray,
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema, from_ffi},
+    ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream},
+};
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+
+use crate::{
+    batch_writer::BatchWriter,
+    error::{FfiError, SchemaError, ViewAccessError},
+    schema::{BuildRows, FromRecordBatch, Record, ReusableRowBuilder, SchemaMeta},
+    typed_batch::TypedBatch,
+    validate::validate_schema,
+};
+
+/// Validate `batch` against `R` and export it as a struct array with the batch's
+/// schema, metadata included.
+///
+/// # Errors
+/// Returns [`FfiError::Schema`] if the batch does not match `R`, or
+/// [`FfiError::Arrow`] if its schema cannot be exported.
+pub fn export_batch<R: SchemaMeta>(
+    batch: &RecordBatch,
+) -> Result<(FFI_ArrowArray, FFI_ArrowSchema), FfiError> {
+    export_typed_batch(&TypedBatch::<R>::try_new(batch.clone())?)
+}
+
+/// Export an already validated batch; see [`export_batch`].
+///
+/// # Errors
+/// Returns [`FfiError::Arrow`] if the schema cannot be exported.
+pub fn export_typed_batch<R: Record>(
+    batch: &TypedBatch<R>,
+) -> Result<(FFI_ArrowArray, FFI_ArrowSchema), FfiError> {
+    export_record_batch(batch.as_record_batch())
+}
+
+fn export_record_batch(batch: &RecordBatch) -> Result<(FFI_ArrowArray, FFI_ArrowSchema), FfiError> {
+    let schema = FFI_ArrowSchema::try_from(batch.schema().as_ref())?;
+    let array = FFI_ArrowArray::new(&StructArray::from(batch.clone()).into_data());
+    Ok((array, schema))
+}
+
+/// Take ownership of a foreign struct array and validate it as a batch of `R`.
+///
+/// # Errors
+/// Returns [`FfiError::Arrow`] if the array cannot be imported, or
+/// [`FfiError::Schema`] if it is not a struct array without top-level nulls or does
+/// not match `R`.
+///
+/// # Safety
+/// `array` and `schema` must follow the C Data Interface and describe the same data;
+/// see [`from_ffi`].
+pub unsafe fn import_batch<R: SchemaMeta>(
+    array: FFI_ArrowArray,
+    schema: &FFI_ArrowSchema,
+) -> Result<TypedBatch<R>, FfiError> {
+    let data = unsafe { from_ffi(array, schema) }?;
+    if !matches!(data.data_type(), DataType::Struct(_)) {
+        return Err(SchemaError::invalid(format!(
+            "imported array of type {} is not a struct array",
+            data.data_type()
+        ))
+        .into());
+    }
+    let array = StructArray::from(data);
+    if array.null_count() > 0 {
+        return Err(SchemaError::invalid(format!(
+            "imported struct array has {} null rows",
+            array.null_count()
+        ))
+        .into());
+    }
+    // The schema keeps the metadata the struct type drops.
+    let schema = Arc::new(Schema::try_from(schema)?);
+    let (_, columns, _) = array.into_parts();
+    let batch = RecordBatch::try_new(schema, columns)?;
+    Ok(TypedBatch::try_new(batch)?)
+}
+
+/// Stream `rows` to a C Data Interface consumer, batched by `writer`.
+///
+/// Rows are pulled from the iterator only when the consumer asks for the next batch.
+pub fn export_rows<R, I>(rows: I, writer: BatchWriter<R>) -> FFI_ArrowArrayStream
+where
+    R: BuildRows + SchemaMeta + 'static,
+    R::Builders: ReusableRowBuilder<R> + Send,
+    I: IntoIterator<Item = R>,
+    I::IntoIter: Send + 'static,
+{
+    FFI_ArrowArrayStream::new(Box::new(RowBatchReader {
+        rows: rows.into_iter().fuse(),
+        writer,
+        schema: R::schema(),
+    }))
+}
+
+/// Stream validated batches to a C Data Interface consumer.
+pub fn export_batches<R, I>(batches: I) -> FFI_ArrowArrayStream
+where
+    R: SchemaMeta + 'static,
+    I: IntoIterator<Item = TypedBatch<R>>,
+    I::IntoIter: Send + 'static,
+{
+    FFI_ArrowArrayStream::new(Box::new(TypedBatchReader {
+        batches: batches.into_iter(),
+        schema: R::schema(),
+    }))
+}
+
+struct RowBatchReader<R: BuildRows, I> {
+    rows: Fuse<I>,
+    writer: BatchWriter<R>,
+    schema: SchemaRef,
+}
+
+impl<R: BuildRows, I: Iterator<Item = R>> Iterator for RowBatchReader<R, I>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for row in self.rows.by_ref() {
+            if let Some(batch) = self.writer.write(row) {
+                return Some(Ok(batch));
+            }
+        }
+        self.writer.flush().map(Ok)
+    }
+}
+
+impl<R: BuildRows, I: Iterator<Item = R>> RecordBatchReader for RowBatchReader<R, I>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+struct TypedBatchReader<I> {
+    batches: I,
+    schema: SchemaRef,
+}
+
+impl<R: Record, I: Iterator<Item = TypedBatch<R>>> Iterator for TypedBatchReader<I> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches.next().map(|batch| Ok(batch.into_inner()))
+    }
+}
+
+impl<R: Record, I: Iterator<Item = TypedBatch<R>>> RecordBatchReader for TypedBatchReader<I> {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+/// Reads a foreign [`FFI_ArrowArrayStream`] as validated [`TypedBatch<R>`]es.
+///
+/// The stream's schema is checked against `R` when the reader is created and every
+/// batch is validated as it is read.
+pub struct TypedArrowStreamReader<R> {
+    batches: ArrowArrayStreamReader,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: SchemaMeta> TypedArrowStreamReader<R> {
+    /// Take ownership of `stream` and check its schema against `R`.
+    ///
+    /// # Errors
+    /// Returns [`FfiError::Arrow`] if the schema cannot be imported, or
+    /// [`FfiError::Schema`] if the column names or types do not match `R`.
+    pub fn try_new(stream: FFI_ArrowArrayStream) -> Result<Self, FfiError> {
+        let batches = ArrowArrayStreamReader::try_new(stream)?;
+        validate_schema::<R>(&batches.schema())?;
+        Ok(Self {
+            batches,
+            _record: PhantomData,
+        })
+    }
+
+    /// Schema declared by the stream.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        self.batches.schema()
+    }
+
+    /// Read every row as an owned `R`, converting one batch at a time.
+    pub fn into_records(self) -> impl Iterator<Item = Result<R, FfiError>>
+    where
+        R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+    {
+        self.flat_map(|batch| match batch {
+            Ok(batch) => batch
+                .iter_views()
+                .map(|view| Ok(view.and_then(R::try_from)?))
+                .collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+}
+
+impl<R: SchemaMeta> Iterator for TypedArrowStreamReader<R> {
+    type Item = Result<TypedBatch<R>, FfiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.batches.next()?;
+        Some(
+            batch
+                .map_err(FfiError::from)
+                .and_then(|batch| Ok(TypedBatch::try_new(batch)?)),
+        )
+    }
+}
@@ -0,0 +1,554 @@
+//! Typed CSV reading and writing for flat records (requires one of the
+//! `csv-55`/`csv-56`/`csv-57` features).
+//!
+//! [`TypedCsvReader<R, Rd>`] reads every column as text with arrow-csv, using the
+//! schema from [`text_schema::<R>()`](text_schema), then converts each column to its
+//! type in `R`. A cell that cannot be converted is reported as [`CsvError::Parse`]
+//! with its row, column name and raw text. The header, when present, must name the
+//! columns of `R` in order.
+//!
+//! [`TypedCsvWriter<R, W>`] writes a header and the rows or validated batches of `R`
+//! with the fixed formats of [`writer_builder`], which the reader parses back.
+//! Dates, times and timestamps use ISO 8601, timestamps with a time zone are written
+//! in UTC with a `+00:00` offset (any offset is accepted when reading), decimals keep every digit
+//! of their scale and dictionary columns are written as their values.
+//!
+//! Records with struct, list, map, union or binary columns are rejected. Nulls are
+//! written as empty cells, so an empty string in an `Option<String>` column reads back
+//! as `None`; in a non-nullable string column an empty cell reads as `""`.
+//!
+//! ```
+//! use typed_arrow::{
+//!     Date32, Decimal128,
+//!     io::csv::{TypedCsvReader, TypedCsvWriter},
+//!     prelude::*,
+//! };
+//!
+//! #[derive(Record)]
+//! struct Trade {
+//!     day: Date32,
+//!     symbol: String,
+//!     price: Decimal128<10, 2>,
+//!     note: Option<String>,
+//! }
+//!
+//! let mut writer = TypedCsvWriter::<Trade, _>::try_new(Vec::new())?;
+//! writer.write_row(Trade {
+//!     day: Date32::new(19_000),
+//!     symbol: "ABC".into(),
+//!     price: Decimal128::new(12_345),
+//!     note: None,
+//! })?;
+//! let text = String::from_utf8(writer.finish()?).unwrap();
+//! assert_eq!(text, "day,symbol,price,note\n2022-01-08,ABC,123.45,\n");
+//!
+//! let bad = "day,symbol,price,note\n2022-01-08,ABC,12x,\n";
+//! let err = TypedCsvReader::<Trade, _>::try_new(bad.as_bytes())?
+//!     .next()
+//!     .unwrap()
+//!     .err()
+//!     .unwrap();
+//! assert!(matches!(
+//!     err,
+//!     typed_arrow::error::CsvError::Parse { row: 0, ref column, ref value, .. }
+//!         if column == "price" && value == "12x"
+//! ));
+//! # Ok::<_, typed_arrow::error::CsvError>(())
+//! ```
+
+use std::{
+    io::{BufRead, Write},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use arrow_array::{Array, ArrayRef, RecordBatch, StringArray, cast::AsArray, make_array};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+use crate::{
+    arrow_cast::{CastOptions, can_cast_types, cast_with_options},
+    arrow_csv::{
+        ReaderBuilder, Writer, WriterBuilder,
+        reader::{BufReader as CsvBufReader, Format},
+    },
+    batch_writer::BatchWriter,
+    error::{CsvError, SchemaError, ViewAccessError},
+    schema::{BuildRows, FromRecordBatch, ReusableRowBuilder, SchemaMeta},
+    typed_batch::TypedBatch,
+};
+
+/// Rows per batch for [`TypedCsvReader`] and [`TypedCsvWriter`] unless configured
+/// otherwise.
+pub const DEFAULT_BATCH_ROWS: usize = 8192;
+
+/// The schema arrow-csv reads `R` with: the columns of `R` in order, each as nullable
+/// `Utf8`.
+///
+/// # Errors
+/// Returns [`SchemaError::InvalidSchema`] if a column of `R` is nested or binary, or
+/// has a type that cannot be parsed from text.
+pub fn text_schema<R: SchemaMeta>() -> Result<SchemaRef, SchemaError> {
+    let fields = R::fields()
+        .iter()
+        .map(|field| {
+            check_column(field)?;
+            Ok(Field::new(field.name(), DataType::Utf8, true))
+        })
+        .collect::<Result<Vec<_>, SchemaError>>()?;
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+fn check_column(field: &Field) -> Result<(), SchemaError> {
+    let data_type = field.data_type();
+    if is_text(data_type) && can_cast_types(&DataType::Utf8, data_type) {
+        Ok(())
+    } else {
+        Err(SchemaError::invalid(format!(
+            "column `{}` of type {data_type} cannot be stored in CSV",
+            field.name()
+        )))
+    }
+}
+
+/// Whether values of `data_type` are written as text that reads back as the same
+/// values: not nested and not binary, which arrow-csv writes as hex.
+fn is_text(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Dictionary(_, values) => is_text(values),
+        DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_) => false,
+        other => !other.is_nested() && !matches!(other, DataType::Union(..)),
+    }
+}
+
+/// The arrow-csv writer settings used by [`TypedCsvWriter::try_new`]: a header row,
+/// empty cells for nulls and ISO 8601 dates, times and timestamps with fractional
+/// seconds only when they are non-zero.
+#[must_use]
+pub fn writer_builder() -> WriterBuilder {
+    WriterBuilder::new()
+        .with_header(true)
+        .with_date_format("%Y-%m-%d".into())
+        .with_datetime_format("%Y-%m-%dT%H:%M:%S%.f".into())
+        .with_time_format("%H:%M:%S%.f".into())
+        .with_timestamp_format("%Y-%m-%dT%H:%M:%S%.f".into())
+        .with_timestamp_tz_format("%Y-%m-%dT%H:%M:%S%.f%:z".into())
+}
+
+/// Writes rows and batches of `R` as CSV.
+///
+/// Rows are buffered into batches by a [`BatchWriter`]. Call
+/// [`finish`](Self::finish) to write the remaining rows; a file with no rows still
+/// gets its header.
+pub struct TypedCsvWriter<R: BuildRows, W: Write> {
+    writer: Writer<W>,
+    rows: BatchWriter<R>,
+    started: bool,
+}
+
+impl<R: BuildRows + SchemaMeta, W: Write> TypedCsvWriter<R, W>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    /// Start writing CSV to `writer` with the settings of [`writer_builder`].
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Schema`] if a column of `R` cannot be stored in CSV.
+    pub fn try_new(writer: W) -> Result<Self, CsvError> {
+        Self::try_new_with_builder(writer, writer_builder())
+    }
+
+    /// Start writing CSV to `writer` with custom arrow-csv settings, e.g. another
+    /// delimiter.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Schema`] if a column of `R` cannot be stored in CSV.
+    pub fn try_new_with_builder(writer: W, builder: WriterBuilder) -> Result<Self, CsvError> {
+        text_schema::<R>()?;
+        Ok(Self {
+            writer: builder.build(writer),
+            rows: BatchWriter::new(DEFAULT_BATCH_ROWS),
+            started: false,
+        })
+    }
+
+    /// Buffer rows with `rows` instead of the default [`DEFAULT_BATCH_ROWS`] row cap.
+    ///
+    /// # Panics
+    /// Panics if rows are already pending.
+    #[must_use]
+    pub fn with_batch_writer(mut self, rows: BatchWriter<R>) -> Self {
+        assert_eq!(
+            self.rows.pending_rows(),
+            0,
+            "with_batch_writer must be called before writing rows"
+        );
+        self.rows = rows;
+        self
+    }
+
+    /// Rows buffered but not yet written.
+    #[must_use]
+    pub fn pending_rows(&self) -> usize {
+        self.rows.pending_rows()
+    }
+
+    fn write_record_batch(&mut self, batch: &RecordBatch) -> Result<(), CsvError> {
+        self.started = true;
+        let schema = batch.schema();
+        if !schema.fields().iter().any(|f| utc(f.data_type()).is_some()) {
+            return Ok(self.writer.write(batch)?);
+        }
+        let (fields, columns): (Vec<Field>, Vec<ArrayRef>) = schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, column)| match utc(field.data_type()) {
+                Some(data_type) => Ok((
+                    field.as_ref().clone().with_data_type(data_type.clone()),
+                    with_data_type(column, data_type)?,
+                )),
+                None => Ok((field.as_ref().clone(), Arc::clone(column))),
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?
+            .into_iter()
+            .unzip();
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+        Ok(self.writer.write(&batch)?)
+    }
+
+    /// Buffer a row, writing a batch if it fills one.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Arrow`] if a full batch cannot be written.
+    pub fn write_row(&mut self, row: R) -> Result<(), CsvError> {
+        match self.rows.write(row) {
+            Some(batch) => self.write_record_batch(&batch),
+            None => Ok(()),
+        }
+    }
+
+    /// Buffer every row, writing batches as they fill.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Arrow`] if a full batch cannot be written.
+    pub fn write_rows<I: IntoIterator<Item = R>>(&mut self, rows: I) -> Result<(), CsvError> {
+        rows.into_iter().try_for_each(|row| self.write_row(row))
+    }
+
+    /// Validate `batch` against `R` and write it after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Schema`] if the batch does not match `R`, or
+    /// [`CsvError::Arrow`] if it cannot be written.
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<(), CsvError> {
+        self.write_typed_batch(&TypedBatch::try_new(batch.clone())?)
+    }
+
+    /// Write an already validated batch after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Arrow`] if the batch cannot be written.
+    pub fn write_typed_batch(&mut self, batch: &TypedBatch<R>) -> Result<(), CsvError> {
+        self.flush()?;
+        self.write_record_batch(batch.as_record_batch())
+    }
+
+    /// Write the buffered rows, if there are any.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Arrow`] if the rows cannot be written.
+    pub fn flush(&mut self) -> Result<(), CsvError> {
+        match self.rows.flush() {
+            Some(batch) => self.write_record_batch(&batch),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the buffered rows, and the header if nothing was written yet, returning
+    /// the inner writer.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Arrow`] if writing fails.
+    pub fn finish(mut self) -> Result<W, CsvError> {
+        self.flush()?;
+        if !self.started {
+            self.write_record_batch(&RecordBatch::new_empty(R::schema()))?;
+        }
+        Ok(self.writer.into_inner())
+    }
+}
+
+/// Configures a [`TypedCsvReader`]: header, delimiter, quoting and batch size.
+pub struct TypedCsvReaderBuilder<R> {
+    header: bool,
+    format: Format,
+    batch_size: usize,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R> Default for TypedCsvReaderBuilder<R> {
+    fn default() -> Self {
+        Self {
+            header: true,
+            format: Format::default(),
+            batch_size: DEFAULT_BATCH_ROWS,
+            _record: PhantomData,
+        }
+    }
+}
+
+impl<R: SchemaMeta> TypedCsvReaderBuilder<R> {
+    /// Comma separated, double quoted, with a header row.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the first row is a header naming the columns of `R` in order.
+    #[must_use]
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Field delimiter.
+    #[must_use]
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.format = self.format.with_delimiter(delimiter);
+        self
+    }
+
+    /// Quote character.
+    #[must_use]
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.format = self.format.with_quote(quote);
+        self
+    }
+
+    /// Escape character.
+    #[must_use]
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.format = self.format.with_escape(escape);
+        self
+    }
+
+    /// Maximum number of rows per batch.
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Check the header, if any, and start reading `reader`.
+    ///
+    /// # Errors
+    /// Returns [`CsvError::Schema`] if a column of `R` cannot be read from CSV or the
+    /// header does not name the columns of `R`, or [`CsvError::Arrow`] if the header
+    /// cannot be read.
+    pub fn build<Rd: BufRead>(self, mut reader: Rd) -> Result<TypedCsvReader<R, Rd>, CsvError> {
+        let text = text_schema::<R>()?;
+        let builder = || {
+            ReaderBuilder::new(Arc::clone(&text))
+                .with_format(self.format.clone())
+                .with_header(false)
+        };
+        if self.header {
+            check_header::<R, _>(&mut reader, builder())?;
+        }
+        Ok(TypedCsvReader {
+            text: builder()
+                .with_batch_size(self.batch_size)
+                .build_buffered(reader)?,
+            schema: R::schema(),
+            row: 0,
+            _record: PhantomData,
+        })
+    }
+}
+
+/// Read the first row of `reader` with arrow-csv's decoder and compare it with the
+/// column names of `R`.
+fn check_header<R: SchemaMeta, Rd: BufRead>(
+    reader: &mut Rd,
+    builder: ReaderBuilder,
+) -> Result<(), CsvError> {
+    let mut decoder = builder.with_batch_size(1).build_decoder();
+    loop {
+        let buf = reader.fill_buf().map_err(ArrowError::from)?;
+        let eof = buf.is_empty();
+        let read = decoder.decode(buf)?;
+        reader.consume(read);
+        if eof || decoder.capacity() == 0 {
+            break;
+        }
+    }
+    let Some(header) = decoder.flush()? else {
+        return Ok(());
+    };
+    for (index, field) in R::fields().iter().enumerate() {
+        let name = header.column(index).as_string::<i32>();
+        let name = if name.is_null(0) { "" } else { name.value(0) };
+        if name != field.name() {
+            return Err(SchemaError::invalid(format!(
+                "csv header column {index} is `{name}`, expected `{}`",
+                field.name()
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Reads CSV as validated [`TypedBatch<R>`]es; see the [module docs](self).
+pub struct TypedCsvReader<R, Rd> {
+    text: CsvBufReader<Rd>,
+    schema: SchemaRef,
+    // Data rows read so far, for error positions.
+    row: usize,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: SchemaMeta, Rd: BufRead> TypedCsvReader<R, Rd> {
+    /// Read comma separated values with a header row.
+    ///
+    /// # Errors
+    /// See [`TypedCsvReaderBuilder::build`].
+    pub fn try_new(reader: Rd) -> Result<Self, CsvError> {
+        TypedCsvReaderBuilder::new().build(reader)
+    }
+
+    /// Schema of the batches: `R::schema()`.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Read every row as an owned `R`, converting one batch at a time.
+    pub fn into_records(self) -> impl Iterator<Item = Result<R, CsvError>>
+    where
+        R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+    {
+        self.flat_map(|batch| match batch {
+            Ok(batch) => batch
+                .iter_views()
+                .map(|view| Ok(view.and_then(R::try_from)?))
+                .collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+
+    /// Convert a batch of text columns to the types of `R`.
+    fn typed(&self, text: &RecordBatch) -> Result<TypedBatch<R>, CsvError> {
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(text.columns())
+            .map(|(field, column)| self.convert(field, column.as_string::<i32>()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let batch = RecordBatch::try_new(Arc::clone(&self.schema), columns)?;
+        Ok(TypedBatch::try_new(batch)?)
+    }
+
+    fn convert(&self, field: &Field, text: &StringArray) -> Result<ArrayRef, CsvError> {
+        let parse_error = |index: usize, message: String| CsvError::Parse {
+            row: self.row + index,
+            column: field.name().clone(),
+            value: if text.is_null(index) {
+                String::new()
+            } else {
+                text.value(index).into()
+            },
+            message,
+        };
+        let filled;
+        let text = if field.is_nullable() || text.null_count() == 0 {
+            text
+        } else if is_string(field.data_type()) {
+            filled = text.iter().map(|v| Some(v.unwrap_or_default())).collect();
+            &filled
+        } else {
+            let index = (0..text.len())
+                .find(|&i| text.is_null(i))
+                .unwrap_or_default();
+            return Err(parse_error(
+                index,
+                "empty cell in a non-nullable column".into(),
+            ));
+        };
+        let strict = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        // Offsets in the text give the instant; the zone itself is only metadata.
+        let target = match field.data_type() {
+            DataType::Timestamp(unit, Some(_)) => DataType::Timestamp(*unit, None),
+            other => other.clone(),
+        };
+        let array = cast_with_options(text, &target, &strict).map_err(|e| {
+            // The strict cast only reports the first failure; a lenient one turns every
+            // failure into a null, which locates the cell.
+            let lenient = cast_with_options(text, &target, &CastOptions::default());
+            let index = lenient
+                .ok()
+                .and_then(|out| (0..text.len()).find(|&i| text.is_valid(i) && out.is_null(i)));
+            match index {
+                Some(index) => parse_error(index, e.to_string()),
+                None => e.into(),
+            }
+        })?;
+        if &target == field.data_type() {
+            Ok(array)
+        } else {
+            Ok(with_data_type(&array, field.data_type().clone())?)
+        }
+    }
+}
+
+/// The type a timestamp column with a time zone is written as: the same instants
+/// labelled `+00:00`. Formatting in a named zone would need arrow's `chrono-tz`
+/// feature.
+fn utc(data_type: &DataType) -> Option<DataType> {
+    match data_type {
+        DataType::Timestamp(unit, Some(_)) => {
+            Some(DataType::Timestamp(*unit, Some("+00:00".into())))
+        }
+        _ => None,
+    }
+}
+
+/// `array` with its type replaced by `data_type`, which has the same layout.
+fn with_data_type(array: &ArrayRef, data_type: DataType) -> Result<ArrayRef, ArrowError> {
+    Ok(make_array(
+        array
+            .to_data()
+            .into_builder()
+            .data_type(data_type)
+            .build()?,
+    ))
+}
+
+fn is_string(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => true,
+        DataType::Dictionary(_, values) => is_string(values),
+        _ => false,
+    }
+}
+
+impl<R: SchemaMeta, Rd: BufRead> Iterator for TypedCsvReader<R, Rd> {
+    type Item = Result<TypedBatch<R>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = match self.text.next()? {
+            Ok(text) => text,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let batch = self.typed(&text);
+        self.row += text.num_rows();
+        Some(batch)
+    }
+}
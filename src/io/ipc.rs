@@ -0,0 +1,426 @@
+//! Typed Arrow IPC files and streams (requires one of the `ipc-55`/`ipc-56`/`ipc-57` features).
+//!
+//! [`TypedIpcWriter<R, W>`] writes `R::schema()` once, then accepts rows (buffered into
+//! batches by a [`BatchWriter`]) or whole batches, which are validated against `R`
+//! before they are encoded. [`TypedIpcReader<R, B>`] checks the file schema against
+//! `R` when it is opened, validates every batch it yields as a [`TypedBatch<R>`], and
+//! can hand back owned records instead.
+//!
+//! [`MappedIpcFile<R>`] decodes an IPC file that is already in memory without copying
+//! its buffers: with the `mmap` feature, [`MappedIpcFile::open`] memory-maps the file
+//! and every view borrows straight from the mapping.
+//!
+//! ```
+//! use typed_arrow::{
+//!     io::ipc::{TypedIpcReader, TypedIpcWriter},
+//!     prelude::*,
+//! };
+//!
+//! #[derive(Record, Debug, PartialEq)]
+//! struct Spill {
+//!     id: i64,
+//!     payload: Option<String>,
+//! }
+//!
+//! let mut writer = TypedIpcWriter::<Spill, _>::try_new_file(Vec::new())?;
+//! writer.write_rows((0..3).map(|id| Spill {
+//!     id,
+//!     payload: (id > 0).then(|| format!("p{id}")),
+//! }))?;
+//! let bytes = writer.finish()?;
+//!
+//! let reader = TypedIpcReader::<Spill, _>::try_new_file(std::io::Cursor::new(bytes))?;
+//! for batch in reader {
+//!     for row in batch?.iter_views() {
+//!         let row = row?;
+//!         assert_eq!(row.payload.is_some(), row.id > 0);
+//!     }
+//! }
+//! # Ok::<_, typed_arrow::error::IpcError>(())
+//! ```
+
+use std::{
+    io::{Read, Seek, Write},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, SchemaRef};
+
+use crate::{
+    arrow_ipc::{
+        Block,
+        convert::fb_to_schema,
+        reader::{FileDecoder, FileReader, StreamReader, read_footer_length},
+        root_as_footer,
+        writer::{FileWriter, StreamWriter},
+    },
+    batch_writer::BatchWriter,
+    error::{IpcError, ViewAccessError},
+    schema::{BuildRows, FromRecordBatch, Record, ReusableRowBuilder, SchemaMeta},
+    table::{BatchesViews, TypedTable},
+    typed_batch::TypedBatch,
+    validate::validate_schema,
+};
+
+/// Rows buffered per batch by [`TypedIpcWriter`] unless configured otherwise.
+pub const DEFAULT_BATCH_ROWS: usize = 8192;
+
+enum Encoder<W: Write> {
+    File(FileWriter<W>),
+    Stream(StreamWriter<W>),
+}
+
+impl<W: Write> Encoder<W> {
+    fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        match self {
+            Self::File(w) => w.write(batch),
+            Self::Stream(w) => w.write(batch),
+        }
+    }
+
+    fn into_inner(self) -> Result<W, ArrowError> {
+        match self {
+            Self::File(mut w) => {
+                w.finish()?;
+                w.into_inner()
+            }
+            Self::Stream(mut w) => {
+                w.finish()?;
+                w.into_inner()
+            }
+        }
+    }
+}
+
+/// Writes rows and batches of `R` in the Arrow IPC file or stream format.
+///
+/// Rows are buffered until a batch is full; call [`finish`](Self::finish) to write the
+/// remaining rows and the format's footer. Dropping the writer without finishing
+/// leaves an incomplete file.
+///
+/// The file format allows a single dictionary per field, and each batch built from
+/// rows carries its own; for records with `Dictionary` columns, write one batch per
+/// file or use the stream format.
+pub struct TypedIpcWriter<R: BuildRows, W: Write> {
+    encoder: Encoder<W>,
+    rows: BatchWriter<R>,
+}
+
+impl<R: BuildRows + SchemaMeta, W: Write> TypedIpcWriter<R, W>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    /// Start an IPC file (random-access format with a footer) on `writer`.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the schema cannot be written.
+    pub fn try_new_file(writer: W) -> Result<Self, IpcError> {
+        let encoder = Encoder::File(FileWriter::try_new(writer, &R::schema())?);
+        Ok(Self::with_encoder(encoder))
+    }
+
+    /// Start an IPC stream (sequential format) on `writer`.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the schema cannot be written.
+    pub fn try_new_stream(writer: W) -> Result<Self, IpcError> {
+        let encoder = Encoder::Stream(StreamWriter::try_new(writer, &R::schema())?);
+        Ok(Self::with_encoder(encoder))
+    }
+
+    fn with_encoder(encoder: Encoder<W>) -> Self {
+        Self {
+            encoder,
+            rows: BatchWriter::new(DEFAULT_BATCH_ROWS),
+        }
+    }
+
+    /// Buffer rows with `rows` instead of the default [`DEFAULT_BATCH_ROWS`] row cap,
+    /// e.g. to bound batches by estimated bytes.
+    ///
+    /// # Panics
+    /// Panics if rows are already pending.
+    #[must_use]
+    pub fn with_batch_writer(mut self, rows: BatchWriter<R>) -> Self {
+        assert_eq!(
+            self.rows.pending_rows(),
+            0,
+            "with_batch_writer must be called before writing rows"
+        );
+        self.rows = rows;
+        self
+    }
+
+    /// Rows buffered but not yet written.
+    #[must_use]
+    pub fn pending_rows(&self) -> usize {
+        self.rows.pending_rows()
+    }
+
+    /// Buffer a row, writing a batch if it fills one.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if a full batch cannot be written.
+    pub fn write_row(&mut self, row: R) -> Result<(), IpcError> {
+        match self.rows.write(row) {
+            Some(batch) => Ok(self.encoder.write(&batch)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Buffer every row, writing batches as they fill.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if a full batch cannot be written.
+    pub fn write_rows<I: IntoIterator<Item = R>>(&mut self, rows: I) -> Result<(), IpcError> {
+        rows.into_iter().try_for_each(|row| self.write_row(row))
+    }
+
+    /// Validate `batch` against `R` and write it after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Schema`] if the batch does not match `R`, including nested
+    /// nullability, or [`IpcError::Arrow`] if it cannot be written.
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<(), IpcError> {
+        self.write_typed_batch(&TypedBatch::try_new(batch.clone())?)
+    }
+
+    /// Write an already validated batch after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the batch cannot be written.
+    pub fn write_typed_batch(&mut self, batch: &TypedBatch<R>) -> Result<(), IpcError> {
+        self.flush()?;
+        Ok(self.encoder.write(batch.as_record_batch())?)
+    }
+
+    /// Write the buffered rows as a batch, if there are any.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the batch cannot be written.
+    pub fn flush(&mut self) -> Result<(), IpcError> {
+        match self.rows.flush() {
+            Some(batch) => Ok(self.encoder.write(&batch)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the buffered rows and the format's end marker, returning the inner writer.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if writing or flushing fails.
+    pub fn finish(mut self) -> Result<W, IpcError> {
+        self.flush()?;
+        Ok(self.encoder.into_inner()?)
+    }
+}
+
+/// Reads batches of `R` from an Arrow IPC file or stream, validating each one.
+///
+/// The iterator yields one [`TypedBatch<R>`] per IPC batch; use
+/// [`iter_views`](TypedBatch::iter_views) on it for zero-copy rows, or
+/// [`into_records`](Self::into_records) for owned ones.
+pub struct TypedIpcReader<R, B> {
+    batches: B,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: SchemaMeta, B: RecordBatchReader> TypedIpcReader<R, B> {
+    /// Wrap any `RecordBatchReader` after checking its schema against `R`.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Schema`] if the column names or types do not match `R`.
+    pub fn try_new(batches: B) -> Result<Self, IpcError> {
+        validate_schema::<R>(&batches.schema())?;
+        Ok(Self {
+            batches,
+            _record: PhantomData,
+        })
+    }
+
+    /// Schema declared by the file or stream.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        self.batches.schema()
+    }
+
+    /// Read every row as an owned `R`, converting one batch at a time.
+    pub fn into_records(self) -> impl Iterator<Item = Result<R, IpcError>>
+    where
+        R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+    {
+        self.flat_map(|batch| match batch {
+            Ok(batch) => batch
+                .iter_views()
+                .map(|view| Ok(view.and_then(R::try_from)?))
+                .collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+}
+
+impl<R: SchemaMeta, Rd: Read + Seek> TypedIpcReader<R, FileReader<Rd>> {
+    /// Open an IPC file and check its schema against `R`.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the footer cannot be read, or
+    /// [`IpcError::Schema`] if the schema does not match `R`.
+    pub fn try_new_file(reader: Rd) -> Result<Self, IpcError> {
+        Self::try_new(FileReader::try_new(reader, None)?)
+    }
+}
+
+impl<R: SchemaMeta, Rd: Read> TypedIpcReader<R, StreamReader<Rd>> {
+    /// Open an IPC stream and check its schema against `R`.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the schema message cannot be read, or
+    /// [`IpcError::Schema`] if the schema does not match `R`.
+    pub fn try_new_stream(reader: Rd) -> Result<Self, IpcError> {
+        Self::try_new(StreamReader::try_new(reader, None)?)
+    }
+}
+
+impl<R: SchemaMeta, B: RecordBatchReader> Iterator for TypedIpcReader<R, B> {
+    type Item = Result<TypedBatch<R>, IpcError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.batches.next()?;
+        Some(
+            batch
+                .map_err(IpcError::from)
+                .and_then(|batch| Ok(TypedBatch::try_new(batch)?)),
+        )
+    }
+}
+
+/// An IPC file decoded in place: every batch references the file's bytes instead of
+/// copying them.
+///
+/// The schema is checked against `R` once, each batch is validated as it is decoded,
+/// and the batches are kept in a [`TypedTable<R>`], so views borrow from this value
+/// and cannot outlive the underlying buffer. Buffers that are not aligned for their
+/// type are copied by the decoder; files written by arrow-rs are always aligned.
+pub struct MappedIpcFile<R> {
+    schema: SchemaRef,
+    table: TypedTable<R>,
+}
+
+impl<R: SchemaMeta> MappedIpcFile<R> {
+    /// Decode the IPC file held in `buffer`.
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the footer or a block cannot be decoded, or
+    /// [`IpcError::Schema`] if the file schema or a batch does not match `R`.
+    pub fn try_from_buffer(buffer: Buffer) -> Result<Self, IpcError> {
+        let invalid = |message: &str| ArrowError::IpcError(format!("invalid IPC file: {message}"));
+        let trailer_start = buffer
+            .len()
+            .checked_sub(10)
+            .ok_or_else(|| invalid("shorter than its trailer"))?;
+        let trailer: [u8; 10] = buffer[trailer_start..].try_into().expect("10 byte trailer");
+        let footer_start = trailer_start
+            .checked_sub(read_footer_length(trailer)?)
+            .ok_or_else(|| invalid("footer length exceeds the file"))?;
+        let footer = root_as_footer(&buffer[footer_start..trailer_start])
+            .map_err(|e| invalid(&e.to_string()))?;
+        let fb_schema = footer.schema().ok_or_else(|| invalid("missing schema"))?;
+        let schema = Arc::new(fb_to_schema(fb_schema));
+        validate_schema::<R>(&schema)?;
+
+        let block_data = |block: &Block| {
+            let offset = usize::try_from(block.offset()).ok();
+            let len = usize::try_from(block.metaDataLength())
+                .ok()
+                .and_then(|meta| {
+                    usize::try_from(block.bodyLength())
+                        .ok()
+                        .and_then(|body| meta.checked_add(body))
+                });
+            match (offset, len) {
+                (Some(offset), Some(len)) if offset.checked_add(len) <= Some(footer_start) => {
+                    Ok(buffer.slice_with_length(offset, len))
+                }
+                _ => Err(invalid("block out of bounds")),
+            }
+        };
+
+        let mut decoder = FileDecoder::new(Arc::clone(&schema), footer.version());
+        for block in footer.dictionaries().iter().flatten() {
+            decoder.read_dictionary(block, &block_data(block)?)?;
+        }
+        let mut table = TypedTable::new();
+        for block in footer.recordBatches().iter().flatten() {
+            if let Some(batch) = decoder.read_record_batch(block, &block_data(block)?)? {
+                table.push(TypedBatch::try_new(batch)?);
+            }
+        }
+        Ok(Self { schema, table })
+    }
+
+    /// Memory-map the IPC file at `path` and decode it in place.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while this value, or any batch or
+    /// array obtained from it, is alive; see [`memmap2::Mmap`].
+    ///
+    /// # Errors
+    /// Returns [`IpcError::Arrow`] if the file cannot be opened, mapped or decoded, or
+    /// [`IpcError::Schema`] if its schema or a batch does not match `R`.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn open(path: impl AsRef<std::path::Path>) -> Result<Self, IpcError> {
+        let file = std::fs::File::open(path).map_err(ArrowError::from)?;
+        // SAFETY: the caller guarantees the file is not modified while mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(ArrowError::from)?;
+        let ptr = std::ptr::NonNull::new(mmap.as_ptr().cast_mut())
+            .expect("a mapping is never at address zero");
+        let len = mmap.len();
+        // SAFETY: `ptr` and `len` describe the mapping, which the buffer keeps alive
+        // through the `Arc` owner and never writes to.
+        let buffer = unsafe { Buffer::from_custom_allocation(ptr, len, Arc::new(mmap)) };
+        Self::try_from_buffer(buffer)
+    }
+}
+
+impl<R: Record> MappedIpcFile<R> {
+    /// Schema stored in the file.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Total number of rows across all batches.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Whether the file has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// The decoded batches with global row indexing.
+    #[must_use]
+    pub fn table(&self) -> &TypedTable<R> {
+        &self.table
+    }
+
+    /// Unwrap into the decoded batches; they keep the underlying buffer alive.
+    #[must_use]
+    pub fn into_table(self) -> TypedTable<R> {
+        self.table
+    }
+}
+
+impl<R: Record + FromRecordBatch> MappedIpcFile<R> {
+    /// Iterate over views of all rows, borrowing from the file's buffer.
+    #[must_use]
+    pub fn iter_views(&self) -> BatchesViews<'_, R> {
+        self.table.iter_views()
+    }
+}
@@ -0,0 +1,679 @@
+//! Typed NDJSON reading and writing (requires one of the `json-55`/`json-56`/`json-57`
+//! features).
+//!
+//! Every line holds one row as a JSON object keyed by the column names of `R`, so
+//! `#[record(name = "...")]` renames apply. [`TypedJsonReader<R, Rd>`] decodes lines
+//! with arrow-json, using the schema from [`json_schema::<R>()`](json_schema), and
+//! converts the columns to their types in `R`. [`TypedJsonDecoder<R>`] does the same
+//! for bytes pushed in chunks, e.g. a request body. [`TypedJsonWriter<R, W>`] writes
+//! rows, views and validated batches in the layout the reader expects.
+//!
+//! Values follow arrow-json, which reads structs as objects, lists as arrays and maps
+//! as objects, with these changes:
+//!
+//! - A union is an object with a single key, the field name of its variant, e.g.
+//!   `{"Click": 3}`. A null union is written as a null; it reads back as the first
+//!   variant holding null, which is the default null-carrying variant.
+//! - Timestamps with a time zone are written in UTC, e.g. `2023-11-14T22:13:20Z`; any
+//!   offset is accepted when reading.
+//! - Map keys that are not strings are written as their text.
+//! - Dictionary columns are written as their values, and fixed-size lists as arrays.
+//!
+//! Null values are left out of objects unless the writer is built with explicit nulls.
+//! Keys that are not columns of `R` are skipped unless the reader is strict. Records
+//! with a column arrow-json cannot decode are rejected when the reader or writer is
+//! created.
+//!
+//! ```
+//! use typed_arrow::{
+//!     List,
+//!     io::json::{TypedJsonReader, TypedJsonWriter},
+//!     prelude::*,
+//! };
+//!
+//! #[derive(Union)]
+//! enum Payload {
+//!     Click(i32),
+//!     Text(String),
+//! }
+//!
+//! #[derive(Record)]
+//! struct Event {
+//!     #[record(name = "type")]
+//!     kind: String,
+//!     payload: Payload,
+//!     tags: List<String>,
+//! }
+//!
+//! let mut writer = TypedJsonWriter::<Event, _>::try_new(Vec::new())?;
+//! writer.write_row(Event {
+//!     kind: "click".into(),
+//!     payload: Payload::Click(3),
+//!     tags: List::new(vec!["home".into()]),
+//! })?;
+//! let text = String::from_utf8(writer.finish()?).unwrap();
+//! assert_eq!(
+//!     text,
+//!     "{\"type\":\"click\",\"payload\":{\"Click\":3},\"tags\":[\"home\"]}\n"
+//! );
+//!
+//! let events = TypedJsonReader::<Event, _>::try_new(text.as_bytes())?
+//!     .into_records()
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! assert!(matches!(events[0].payload, Payload::Click(3)));
+//! # Ok::<_, typed_arrow::error::JsonError>(())
+//! ```
+
+use std::{
+    io::{BufRead, Write},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use arrow_array::{
+    Array, ArrayRef, LargeListArray, ListArray, MapArray, RecordBatch, StructArray, UInt32Array,
+    UnionArray, cast::AsArray, make_array,
+};
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::{
+    ArrowError, DataType, Field, Fields, Schema, SchemaRef, UnionFields, UnionMode,
+};
+
+use crate::{
+    arrow_cast::{CastOptions, cast_with_options},
+    arrow_json::{
+        ReaderBuilder, WriterBuilder,
+        reader::Decoder,
+        writer::{LineDelimited, Writer},
+    },
+    arrow_select::take::take,
+    batch_writer::BatchWriter,
+    error::{JsonError, SchemaError, ViewAccessError},
+    schema::{
+        AppendViewRow, BuildRows, FromRecordBatch, ReusableRowBuilder, SchemaMeta, StructView,
+    },
+    typed_batch::TypedBatch,
+};
+
+/// Rows per batch for [`TypedJsonReader`] and [`TypedJsonWriter`] unless configured
+/// otherwise.
+pub const DEFAULT_BATCH_ROWS: usize = 8192;
+
+/// The schema arrow-json decodes and encodes `R` with: the columns of `R` in order,
+/// with unions as structs of their variants and the other changes listed in the
+/// [module docs](self).
+///
+/// # Errors
+/// Returns [`SchemaError::InvalidSchema`] if arrow-json cannot decode a column of `R`.
+pub fn json_schema<R: SchemaMeta>() -> Result<SchemaRef, SchemaError> {
+    let fields = R::fields()
+        .iter()
+        .map(|field| {
+            let json = json_field(field);
+            match ReaderBuilder::new_with_field(json.clone()).build_decoder() {
+                Ok(_) => Ok(json),
+                Err(e) => Err(SchemaError::invalid(format!(
+                    "column `{}` of type {} cannot be stored in JSON: {e}",
+                    field.name(),
+                    field.data_type()
+                ))),
+            }
+        })
+        .collect::<Result<Vec<_>, SchemaError>>()?;
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+fn json_field(field: &Field) -> Field {
+    // A union holding null is written as a missing value, whatever its nullability.
+    let nullable = field.is_nullable() || matches!(field.data_type(), DataType::Union(..));
+    field
+        .clone()
+        .with_data_type(json_type(field.data_type()))
+        .with_nullable(nullable)
+}
+
+fn json_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Union(variants, _) => DataType::Struct(
+            variants
+                .iter()
+                .map(|(_, field)| json_field(field).with_nullable(true))
+                .collect(),
+        ),
+        DataType::Struct(fields) => {
+            DataType::Struct(fields.iter().map(|field| json_field(field)).collect())
+        }
+        DataType::List(item) => DataType::List(Arc::new(json_field(item))),
+        DataType::LargeList(item) => DataType::LargeList(Arc::new(json_field(item))),
+        // Null slots hold values too, which a variable-size list cannot skip.
+        DataType::FixedSizeList(item, _) => {
+            DataType::List(Arc::new(json_field(item).with_nullable(true)))
+        }
+        DataType::Map(entries, sorted) => {
+            let DataType::Struct(pair) = entries.data_type() else {
+                return data_type.clone();
+            };
+            let key = match pair[0].data_type() {
+                DataType::Utf8 | DataType::LargeUtf8 => pair[0].as_ref().clone(),
+                _ => pair[0].as_ref().clone().with_data_type(DataType::Utf8),
+            };
+            let pair = Fields::from(vec![key, json_field(&pair[1])]);
+            DataType::Map(
+                Arc::new(
+                    entries
+                        .as_ref()
+                        .clone()
+                        .with_data_type(DataType::Struct(pair)),
+                ),
+                *sorted,
+            )
+        }
+        DataType::Dictionary(_, values) => json_type(values),
+        // Formatting in a named zone would need arrow's `chrono-tz` feature.
+        DataType::Timestamp(unit, Some(_)) => DataType::Timestamp(*unit, Some("+00:00".into())),
+        other => other.clone(),
+    }
+}
+
+/// Convert `array` to `to`, either the JSON form of its type or the type it was
+/// written from.
+fn convert(array: &ArrayRef, to: &DataType) -> Result<ArrayRef, ArrowError> {
+    match (array.data_type(), to) {
+        (from, to) if from == to => Ok(Arc::clone(array)),
+        (DataType::Union(..), DataType::Struct(fields)) => {
+            union_to_struct(array.as_union(), fields)
+        }
+        (DataType::Struct(_), DataType::Union(variants, mode)) => {
+            struct_to_union(array.as_struct(), variants, *mode)
+        }
+        (DataType::Struct(_), DataType::Struct(fields)) => {
+            let array = array.as_struct();
+            let columns = array
+                .columns()
+                .iter()
+                .zip(fields.iter())
+                .map(|(column, field)| convert(column, field.data_type()))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Arc::new(StructArray::try_new(
+                fields.clone(),
+                columns,
+                array.nulls().cloned(),
+            )?))
+        }
+        (DataType::List(_), DataType::List(item)) => {
+            let array = array.as_list::<i32>();
+            Ok(Arc::new(ListArray::try_new(
+                Arc::clone(item),
+                array.offsets().clone(),
+                convert(array.values(), item.data_type())?,
+                array.nulls().cloned(),
+            )?))
+        }
+        (DataType::LargeList(_), DataType::LargeList(item)) => {
+            let array = array.as_list::<i64>();
+            Ok(Arc::new(LargeListArray::try_new(
+                Arc::clone(item),
+                array.offsets().clone(),
+                convert(array.values(), item.data_type())?,
+                array.nulls().cloned(),
+            )?))
+        }
+        (DataType::FixedSizeList(item, _), DataType::List(_)) => {
+            let list = DataType::List(Arc::new(item.as_ref().clone().with_nullable(true)));
+            convert(&cast_with_options(array, &list, &strict())?, to)
+        }
+        (DataType::List(_), DataType::FixedSizeList(item, _)) => {
+            let list = DataType::List(Arc::new(item.as_ref().clone().with_nullable(true)));
+            cast_with_options(&convert(array, &list)?, to, &strict())
+        }
+        (DataType::Map(..), DataType::Map(entries, sorted)) => {
+            let array = array.as_map();
+            let pairs: ArrayRef = Arc::new(array.entries().clone());
+            Ok(Arc::new(MapArray::try_new(
+                Arc::clone(entries),
+                array.offsets().clone(),
+                convert(&pairs, entries.data_type())?.as_struct().clone(),
+                array.nulls().cloned(),
+                *sorted,
+            )?))
+        }
+        (DataType::Timestamp(from, _), DataType::Timestamp(unit, _)) if from == unit => {
+            with_data_type(array, to.clone())
+        }
+        // Dictionaries and map keys.
+        _ => cast_with_options(array, to, &strict()),
+    }
+}
+
+fn strict() -> CastOptions<'static> {
+    CastOptions {
+        safe: false,
+        ..Default::default()
+    }
+}
+
+/// A struct with one column per variant, each null except in the rows holding that
+/// variant. Rows where the variant holds null are null.
+fn union_to_struct(array: &UnionArray, fields: &Fields) -> Result<ArrayRef, ArrowError> {
+    let DataType::Union(variants, _) = array.data_type() else {
+        unreachable!("union_to_struct called on a non-union array")
+    };
+    let columns = variants
+        .iter()
+        .zip(fields.iter())
+        .map(|((type_id, _), field)| {
+            let rows: UInt32Array = (0..array.len())
+                .map(|i| (array.type_id(i) == type_id).then(|| array.value_offset(i) as u32))
+                .collect();
+            convert(&take(array.child(type_id), &rows, None)?, field.data_type())
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+    let nulls: Vec<_> = columns
+        .iter()
+        .map(|column| column.logical_nulls())
+        .collect();
+    let valid: NullBuffer = (0..array.len())
+        .map(|i| {
+            nulls
+                .iter()
+                .any(|n| n.as_ref().is_none_or(|n| n.is_valid(i)))
+        })
+        .collect();
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        columns,
+        Some(valid),
+    )?))
+}
+
+/// The union whose variant in each row is the one non-null column of `array`. Rows
+/// with no such column hold null in the first variant.
+fn struct_to_union(
+    array: &StructArray,
+    variants: &UnionFields,
+    mode: UnionMode,
+) -> Result<ArrayRef, ArrowError> {
+    let children = variants
+        .iter()
+        .zip(array.columns())
+        .map(|((_, field), column)| convert(column, field.data_type()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let nulls: Vec<_> = children.iter().map(|child| child.logical_nulls()).collect();
+    let mut chosen = Vec::with_capacity(array.len());
+    for row in 0..array.len() {
+        let set: Vec<usize> = (0..children.len())
+            .filter(|&j| array.is_valid(row) && nulls[j].as_ref().is_none_or(|n| n.is_valid(row)))
+            .collect();
+        match set[..] {
+            [] => chosen.push(0),
+            [j] => chosen.push(j),
+            [a, b, ..] => {
+                let name = |j: usize| variants.iter().nth(j).map(|(_, f)| f.name().clone());
+                return Err(ArrowError::JsonError(format!(
+                    "union object at index {row} sets both `{}` and `{}`",
+                    name(a).unwrap_or_default(),
+                    name(b).unwrap_or_default()
+                )));
+            }
+        }
+    }
+    let tags: Vec<i8> = variants.iter().map(|(type_id, _)| type_id).collect();
+    let type_ids: ScalarBuffer<i8> = chosen.iter().map(|&j| tags[j]).collect();
+    let union = match mode {
+        UnionMode::Sparse => UnionArray::try_new(variants.clone(), type_ids, None, children)?,
+        UnionMode::Dense => {
+            let mut rows = vec![Vec::<u32>::new(); children.len()];
+            let offsets: ScalarBuffer<i32> = chosen
+                .iter()
+                .enumerate()
+                .map(|(row, &j)| {
+                    rows[j].push(row as u32);
+                    (rows[j].len() - 1) as i32
+                })
+                .collect();
+            let children = children
+                .iter()
+                .zip(rows)
+                .map(|(child, rows)| take(child, &UInt32Array::from(rows), None))
+                .collect::<Result<Vec<_>, _>>()?;
+            UnionArray::try_new(variants.clone(), type_ids, Some(offsets), children)?
+        }
+    };
+    Ok(Arc::new(union))
+}
+
+/// `array` with its type replaced by `data_type`, which has the same layout.
+fn with_data_type(array: &ArrayRef, data_type: DataType) -> Result<ArrayRef, ArrowError> {
+    Ok(make_array(
+        array
+            .to_data()
+            .into_builder()
+            .data_type(data_type)
+            .build()?,
+    ))
+}
+
+/// Writes rows, views and batches of `R` as NDJSON.
+///
+/// Rows are buffered into batches by a [`BatchWriter`]. Call
+/// [`finish`](Self::finish) to write the remaining rows.
+pub struct TypedJsonWriter<R: BuildRows, W: Write> {
+    writer: Writer<W, LineDelimited>,
+    schema: SchemaRef,
+    rows: BatchWriter<R>,
+}
+
+impl<R: BuildRows + SchemaMeta, W: Write> TypedJsonWriter<R, W>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    /// Start writing NDJSON to `writer`, leaving nulls out of objects.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Schema`] if a column of `R` cannot be stored in JSON.
+    pub fn try_new(writer: W) -> Result<Self, JsonError> {
+        Self::try_new_with_builder(writer, WriterBuilder::new())
+    }
+
+    /// Start writing NDJSON to `writer` with custom arrow-json settings, e.g. explicit
+    /// nulls.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Schema`] if a column of `R` cannot be stored in JSON.
+    pub fn try_new_with_builder(writer: W, builder: WriterBuilder) -> Result<Self, JsonError> {
+        Ok(Self {
+            writer: builder.build::<_, LineDelimited>(writer),
+            schema: json_schema::<R>()?,
+            rows: BatchWriter::new(DEFAULT_BATCH_ROWS),
+        })
+    }
+
+    /// Buffer rows with `rows` instead of the default [`DEFAULT_BATCH_ROWS`] row cap.
+    ///
+    /// # Panics
+    /// Panics if rows are already pending.
+    #[must_use]
+    pub fn with_batch_writer(mut self, rows: BatchWriter<R>) -> Self {
+        assert_eq!(
+            self.rows.pending_rows(),
+            0,
+            "with_batch_writer must be called before writing rows"
+        );
+        self.rows = rows;
+        self
+    }
+
+    /// Rows buffered but not yet written.
+    #[must_use]
+    pub fn pending_rows(&self) -> usize {
+        self.rows.pending_rows()
+    }
+
+    fn write_record_batch(&mut self, batch: &RecordBatch) -> Result<(), JsonError> {
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, column)| convert(column, field.data_type()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let batch = RecordBatch::try_new(Arc::clone(&self.schema), columns)?;
+        Ok(self.writer.write(&batch)?)
+    }
+
+    /// Buffer a row, writing a batch if it fills one.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if a full batch cannot be written.
+    pub fn write_row(&mut self, row: R) -> Result<(), JsonError> {
+        match self.rows.write(row) {
+            Some(batch) => self.write_record_batch(&batch),
+            None => Ok(()),
+        }
+    }
+
+    /// Buffer every row, writing batches as they fill.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if a full batch cannot be written.
+    pub fn write_rows<I: IntoIterator<Item = R>>(&mut self, rows: I) -> Result<(), JsonError> {
+        rows.into_iter().try_for_each(|row| self.write_row(row))
+    }
+
+    /// Buffer a row view, e.g. from a batch read earlier, without converting it to an
+    /// owned row.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::View`] if a nested value of the view cannot be read, or
+    /// [`JsonError::Arrow`] if a full batch cannot be written.
+    pub fn write_view(&mut self, view: <R as StructView>::View<'_>) -> Result<(), JsonError>
+    where
+        R: StructView,
+        R::Builders: AppendViewRow<R>,
+    {
+        match self.rows.write_view(view)? {
+            Some(batch) => self.write_record_batch(&batch),
+            None => Ok(()),
+        }
+    }
+
+    /// Validate `batch` against `R` and write it after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Schema`] if the batch does not match `R`, or
+    /// [`JsonError::Arrow`] if it cannot be written.
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<(), JsonError> {
+        self.write_typed_batch(&TypedBatch::try_new(batch.clone())?)
+    }
+
+    /// Write an already validated batch after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if the batch cannot be written.
+    pub fn write_typed_batch(&mut self, batch: &TypedBatch<R>) -> Result<(), JsonError> {
+        self.flush()?;
+        self.write_record_batch(batch.as_record_batch())
+    }
+
+    /// Write the buffered rows, if there are any.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if the rows cannot be written.
+    pub fn flush(&mut self) -> Result<(), JsonError> {
+        match self.rows.flush() {
+            Some(batch) => self.write_record_batch(&batch),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the buffered rows and return the inner writer.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if writing fails.
+    pub fn finish(mut self) -> Result<W, JsonError> {
+        self.flush()?;
+        self.writer.finish()?;
+        Ok(self.writer.into_inner())
+    }
+}
+
+/// Configures a [`TypedJsonReader`] or [`TypedJsonDecoder`]: batch size and whether
+/// unknown keys are an error.
+pub struct TypedJsonReaderBuilder<R> {
+    batch_size: usize,
+    strict: bool,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R> Default for TypedJsonReaderBuilder<R> {
+    fn default() -> Self {
+        Self {
+            batch_size: DEFAULT_BATCH_ROWS,
+            strict: false,
+            _record: PhantomData,
+        }
+    }
+}
+
+impl<R: SchemaMeta> TypedJsonReaderBuilder<R> {
+    /// Batches of [`DEFAULT_BATCH_ROWS`] rows, skipping unknown keys.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum number of rows per batch.
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Whether a key that is not a column of `R`, or a field of a nested struct, is an
+    /// error.
+    #[must_use]
+    pub fn with_strict_mode(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Create a decoder for bytes pushed in chunks.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Schema`] if a column of `R` cannot be read from JSON.
+    pub fn build_decoder(self) -> Result<TypedJsonDecoder<R>, JsonError> {
+        let decoder = ReaderBuilder::new(json_schema::<R>()?)
+            .with_batch_size(self.batch_size)
+            .with_strict_mode(self.strict)
+            .build_decoder()?;
+        Ok(TypedJsonDecoder {
+            decoder,
+            schema: R::schema(),
+            _record: PhantomData,
+        })
+    }
+
+    /// Start reading `reader`.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Schema`] if a column of `R` cannot be read from JSON.
+    pub fn build<Rd: BufRead>(self, reader: Rd) -> Result<TypedJsonReader<R, Rd>, JsonError> {
+        Ok(TypedJsonReader {
+            reader,
+            decoder: self.build_decoder()?,
+        })
+    }
+}
+
+/// Decodes NDJSON pushed in chunks into validated [`TypedBatch<R>`]es.
+///
+/// Feed bytes to [`decode`](Self::decode) until it stops consuming them or the input
+/// ends, then call [`flush`](Self::flush) for the decoded rows. A row may span chunks.
+pub struct TypedJsonDecoder<R> {
+    decoder: Decoder,
+    schema: SchemaRef,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: SchemaMeta> TypedJsonDecoder<R> {
+    /// Decode rows from `buf`, returning how many bytes were consumed. Fewer than
+    /// `buf.len()` means a batch is full and must be flushed.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if `buf` is not valid JSON.
+    pub fn decode(&mut self, buf: &[u8]) -> Result<usize, JsonError> {
+        Ok(self.decoder.decode(buf)?)
+    }
+
+    /// Rows decoded but not yet flushed.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.decoder.len()
+    }
+
+    /// Whether no rows are waiting to be flushed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.decoder.is_empty()
+    }
+
+    /// Take the decoded rows as a batch, or `None` if there are none.
+    ///
+    /// # Errors
+    /// Returns [`JsonError::Arrow`] if a value does not fit its column or a row is
+    /// incomplete, or [`JsonError::Schema`] if a nested non-nullable value is null.
+    pub fn flush(&mut self) -> Result<Option<TypedBatch<R>>, JsonError> {
+        let Some(json) = self.decoder.flush()? else {
+            return Ok(None);
+        };
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(json.columns())
+            .map(|(field, column)| convert(column, field.data_type()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let batch = RecordBatch::try_new(Arc::clone(&self.schema), columns)?;
+        Ok(Some(TypedBatch::try_new(batch)?))
+    }
+}
+
+/// Reads NDJSON as validated [`TypedBatch<R>`]es; see the [module docs](self).
+pub struct TypedJsonReader<R, Rd> {
+    reader: Rd,
+    decoder: TypedJsonDecoder<R>,
+}
+
+impl<R: SchemaMeta, Rd: BufRead> TypedJsonReader<R, Rd> {
+    /// Read with the default settings of [`TypedJsonReaderBuilder`].
+    ///
+    /// # Errors
+    /// See [`TypedJsonReaderBuilder::build`].
+    pub fn try_new(reader: Rd) -> Result<Self, JsonError> {
+        TypedJsonReaderBuilder::new().build(reader)
+    }
+
+    /// Schema of the batches: `R::schema()`.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.decoder.schema)
+    }
+
+    /// Read every row as an owned `R`, converting one batch at a time.
+    pub fn into_records(self) -> impl Iterator<Item = Result<R, JsonError>>
+    where
+        R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+    {
+        self.flat_map(|batch| match batch {
+            Ok(batch) => batch
+                .iter_views()
+                .map(|view| Ok(view.and_then(R::try_from)?))
+                .collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+
+    fn read(&mut self) -> Result<Option<TypedBatch<R>>, JsonError> {
+        loop {
+            let buf = self.reader.fill_buf().map_err(ArrowError::from)?;
+            if buf.is_empty() {
+                break;
+            }
+            let available = buf.len();
+            let read = self.decoder.decode(buf)?;
+            self.reader.consume(read);
+            if read != available {
+                break;
+            }
+        }
+        self.decoder.flush()
+    }
+}
+
+impl<R: SchemaMeta, Rd: BufRead> Iterator for TypedJsonReader<R, Rd> {
+    type Item = Result<TypedBatch<R>, JsonError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
@@ -0,0 +1,12 @@
+//! Typed readers and writers for Arrow file formats.
+//!
+//! Each format lives in its own submodule behind the matching cargo feature.
+
+#[cfg(any(feature = "csv-55", feature = "csv-56", feature = "csv-57"))]
+pub mod csv;
+#[cfg(any(feature = "ipc-55", feature = "ipc-56", feature = "ipc-57"))]
+pub mod ipc;
+#[cfg(any(feature = "json-55", feature = "json-56", feature = "json-57"))]
+pub mod json;
+#[cfg(any(feature = "parquet-55", feature = "parquet-56", feature = "parquet-57"))]
+pub mod parquet;
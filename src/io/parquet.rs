@@ -0,0 +1,830 @@
+//! Parquet helpers (requires one of the `parquet-55`/`parquet-56`/`parquet-57` features).
+//!
+//! [`TypedParquetWriter<R, W>`] writes rows or validated batches of `R`, applying the
+//! per-column `#[record(parquet(...))]` hints through [`writer_properties`].
+//! [`TypedParquetReaderBuilder<R, T>`] opens a file, optionally narrows it to a
+//! projection record, and builds a [`TypedParquetReader<R>`] that yields validated
+//! [`TypedBatch<R>`]es.
+//!
+//! Predicates built from the generated `{Name}Column` constants with [`ColumnPredicateExt`]
+//! are checked against row-group statistics and, when the file has one, the page
+//! index, so row groups and pages that cannot match are never decoded. Pruning is
+//! coarse: the surviving batches may still hold rows outside the predicate.
+//!
+//! ```
+//! use typed_arrow::{
+//!     io::parquet::{TypedParquetReaderBuilder, TypedParquetWriter},
+//!     prelude::*,
+//! };
+//!
+//! #[derive(Record)]
+//! struct Reading {
+//!     #[record(parquet(encoding = "delta_binary_packed", statistics = "page"))]
+//!     ts: i64,
+//!     #[record(parquet(dictionary = true))]
+//!     sensor: String,
+//!     value: Option<f64>,
+//! }
+//!
+//! #[derive(Record)]
+//! #[record(project_of = Reading)]
+//! struct Value {
+//!     value: Option<f64>,
+//!     ts: i64,
+//! }
+//!
+//! let path = std::env::temp_dir().join(format!("typed_arrow_doc_{}.parquet", std::process::id()));
+//! let mut writer = TypedParquetWriter::<Reading, _>::try_new(std::fs::File::create(&path)?)?;
+//! writer.write_rows((0..100).map(|ts| Reading {
+//!     ts,
+//!     sensor: format!("s{}", ts % 4),
+//!     value: (ts % 10 != 0).then_some(ts as f64 / 10.0),
+//! }))?;
+//! writer.finish()?;
+//!
+//! let reader = TypedParquetReaderBuilder::<Reading, _>::try_new(std::fs::File::open(&path)?)?
+//!     .project::<Value>()?
+//!     .build()?;
+//! let mut nulls = 0;
+//! for batch in reader {
+//!     for row in batch?.iter_views() {
+//!         nulls += usize::from(row?.value.is_none());
+//!     }
+//! }
+//! assert_eq!(nulls, 10);
+//! # std::fs::remove_file(&path)?;
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{io::Write, marker::PhantomData, str::FromStr, sync::Arc};
+
+use arrow_array::{
+    Array, ArrayRef, BooleanArray, RecordBatch, RecordBatchReader, Scalar, UInt64Array, make_array,
+};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+
+use crate::{
+    arrow_ord::cmp,
+    batch_writer::BatchWriter,
+    bridge::ArrowBinding,
+    error::{ParquetError, SchemaError},
+    parquet::{
+        arrow::{
+            ArrowSchemaConverter, ArrowWriter, ProjectionMask,
+            arrow_reader::{
+                ArrowReaderOptions, ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder,
+                RowSelection, RowSelector, statistics::StatisticsConverter,
+            },
+        },
+        basic::{Compression, Encoding},
+        file::{
+            metadata::ParquetMetaData,
+            properties::{EnabledStatistics, WriterProperties, WriterPropertiesBuilder},
+            reader::ChunkReader,
+        },
+        schema::types::SchemaDescriptor,
+    },
+    schema::{
+        BuildRows, ColAt, ColumnRef, Projection, RecordColumns, ReusableRowBuilder, SchemaMeta,
+        TypedColumn,
+    },
+    typed_batch::TypedBatch,
+    validate::validate_schema,
+};
+#[cfg(feature = "views")]
+use crate::{error::ViewAccessError, schema::FromRecordBatch};
+
+/// Build a Parquet [`ProjectionMask`] selecting the source columns of projection `P`.
+///
+/// Columns are matched by name against the top-level fields of the file schema, so the
+/// file only needs to contain the projected columns, in any order.
+///
+/// # Errors
+/// Returns [`SchemaError::MissingField`] if a projected column is not in the file.
+///
+/// # Example
+/// ```no_run
+/// use typed_arrow::{
+///     io::parquet::projection_mask,
+///     parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder, prelude::*,
+/// };
+///
+/// #[derive(Record)]
+/// struct Wide {
+///     id: i64,
+///     name: String,
+///     ts: i64,
+/// }
+///
+/// #[derive(Record)]
+/// #[record(project_of = Wide)]
+/// struct IdTs {
+///     id: i64,
+///     ts: i64,
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = std::fs::File::open("wide.parquet")?;
+/// let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
+/// let mask = projection_mask::<IdTs>(builder.parquet_schema())?;
+/// for batch in builder.with_projection(mask).build()? {
+///     for row in batch?.iter_views::<IdTs>()?.try_flatten()? {
+///         println!("{} @ {}", row.id, row.ts);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn projection_mask<P: Projection>(
+    schema: &SchemaDescriptor,
+) -> Result<ProjectionMask, SchemaError> {
+    let roots = schema.root_schema().get_fields();
+    let indices = P::source_names()
+        .into_iter()
+        .map(|name| {
+            roots
+                .iter()
+                .position(|f| f.name() == name)
+                .ok_or_else(|| SchemaError::missing_field(name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ProjectionMask::roots(schema, indices))
+}
+
+/// Apply the `#[record(parquet(...))]` hints of `R` on top of `builder`.
+///
+/// A hint on a nested, list or map field applies to every leaf column under it. An
+/// `encoding` hint turns dictionary encoding off for its columns unless the field also
+/// sets `dictionary = true`, in which case the encoding is only the fallback.
+///
+/// `encoding = "rle"` is rejected at compile time on anything but `bool` fields:
+///
+/// ```compile_fail
+/// use typed_arrow::prelude::*;
+///
+/// #[derive(Record)]
+/// struct Flags {
+///     #[record(parquet(encoding = "rle"))]
+///     id: i64,
+/// }
+/// ```
+///
+/// # Errors
+/// Returns [`ParquetError::Parquet`] if a hint names an unknown or unsupported
+/// encoding, codec or level.
+pub fn writer_properties<R: SchemaMeta>(
+    mut builder: WriterPropertiesBuilder,
+) -> Result<WriterPropertiesBuilder, ParquetError> {
+    let hints = R::parquet_hints();
+    if hints.is_empty() {
+        return Ok(builder);
+    }
+    let general = |message: String| crate::parquet::errors::ParquetError::General(message);
+    let descr = ArrowSchemaConverter::new().convert(&R::schema())?;
+    for (name, hint) in hints {
+        let encoding = hint.encoding.map(Encoding::from_str).transpose()?;
+        if let Some(e @ (Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY)) = encoding {
+            return Err(general(format!(
+                "column `{name}`: {e} is requested with `dictionary = true`"
+            ))
+            .into());
+        }
+        let compression = hint.compression.map(Compression::from_str).transpose()?;
+        let statistics = hint
+            .statistics
+            .map(EnabledStatistics::from_str)
+            .transpose()
+            .map_err(general)?;
+        for column in descr.columns() {
+            if column.path().parts().first().map(String::as_str) != Some(name) {
+                continue;
+            }
+            let path = column.path().clone();
+            if let Some(encoding) = encoding {
+                builder = builder.set_column_encoding(path.clone(), encoding);
+            }
+            if let Some(compression) = compression {
+                builder = builder.set_column_compression(path.clone(), compression);
+            }
+            if let Some(statistics) = statistics {
+                builder = builder.set_column_statistics_enabled(path.clone(), statistics);
+            }
+            if let Some(dictionary) = hint.dictionary.or(encoding.map(|_| false)) {
+                builder = builder.set_column_dictionary_enabled(path.clone(), dictionary);
+            }
+            if let Some(bloom_filter) = hint.bloom_filter {
+                builder = builder.set_column_bloom_filter_enabled(path, bloom_filter);
+            }
+        }
+    }
+    Ok(builder)
+}
+
+/// Writes rows and batches of `R` to a Parquet file.
+///
+/// Rows are buffered into batches by a [`BatchWriter`]; the underlying `ArrowWriter`
+/// groups batches into row groups. Call [`finish`](Self::finish) to write the footer.
+pub struct TypedParquetWriter<R: BuildRows, W: Write + Send> {
+    writer: ArrowWriter<W>,
+    rows: BatchWriter<R>,
+}
+
+impl<R: BuildRows + SchemaMeta, W: Write + Send> TypedParquetWriter<R, W>
+where
+    R::Builders: ReusableRowBuilder<R>,
+{
+    /// Start a file on `writer` with default properties plus `R`'s column hints.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if a hint is invalid or the writer cannot start.
+    pub fn try_new(writer: W) -> Result<Self, ParquetError> {
+        Self::try_new_with_properties(writer, WriterProperties::builder())
+    }
+
+    /// Start a file on `writer` with `properties`, then `R`'s column hints on top.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if a hint is invalid or the writer cannot start.
+    pub fn try_new_with_properties(
+        writer: W,
+        properties: WriterPropertiesBuilder,
+    ) -> Result<Self, ParquetError> {
+        let properties = writer_properties::<R>(properties)?.build();
+        Ok(Self {
+            writer: ArrowWriter::try_new(writer, R::schema(), Some(properties))?,
+            rows: BatchWriter::new(DEFAULT_BATCH_ROWS),
+        })
+    }
+
+    /// Buffer rows with `rows` instead of the default [`DEFAULT_BATCH_ROWS`] row cap.
+    ///
+    /// # Panics
+    /// Panics if rows are already pending.
+    #[must_use]
+    pub fn with_batch_writer(mut self, rows: BatchWriter<R>) -> Self {
+        assert_eq!(
+            self.rows.pending_rows(),
+            0,
+            "with_batch_writer must be called before writing rows"
+        );
+        self.rows = rows;
+        self
+    }
+
+    /// Rows buffered but not yet handed to the Parquet writer.
+    #[must_use]
+    pub fn pending_rows(&self) -> usize {
+        self.rows.pending_rows()
+    }
+
+    /// Buffer a row, encoding a batch if it fills one.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if a full batch cannot be written.
+    pub fn write_row(&mut self, row: R) -> Result<(), ParquetError> {
+        match self.rows.write(row) {
+            Some(batch) => Ok(self.writer.write(&batch)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Buffer every row, encoding batches as they fill.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if a full batch cannot be written.
+    pub fn write_rows<I: IntoIterator<Item = R>>(&mut self, rows: I) -> Result<(), ParquetError> {
+        rows.into_iter().try_for_each(|row| self.write_row(row))
+    }
+
+    /// Validate `batch` against `R` and write it after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Schema`] if the batch does not match `R`, including
+    /// nested nullability, or [`ParquetError::Parquet`] if it cannot be written.
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<(), ParquetError> {
+        self.write_typed_batch(&TypedBatch::try_new(batch.clone())?)
+    }
+
+    /// Write an already validated batch after any buffered rows.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if the batch cannot be written.
+    pub fn write_typed_batch(&mut self, batch: &TypedBatch<R>) -> Result<(), ParquetError> {
+        self.flush()?;
+        Ok(self.writer.write(batch.as_record_batch())?)
+    }
+
+    /// Hand the buffered rows to the Parquet writer as a batch, if there are any.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if the batch cannot be written.
+    pub fn flush(&mut self) -> Result<(), ParquetError> {
+        match self.rows.flush() {
+            Some(batch) => Ok(self.writer.write(&batch)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Close the current row group, so the following rows start a new one.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if the row group cannot be written.
+    pub fn flush_row_group(&mut self) -> Result<(), ParquetError> {
+        self.flush()?;
+        Ok(self.writer.flush()?)
+    }
+
+    /// Write the buffered rows and the footer, returning the inner writer.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if writing fails.
+    pub fn finish(mut self) -> Result<W, ParquetError> {
+        self.flush()?;
+        Ok(self.writer.into_inner()?)
+    }
+}
+
+/// Rows buffered per batch by [`TypedParquetWriter`] unless configured otherwise.
+pub const DEFAULT_BATCH_ROWS: usize = 8192;
+
+/// Opens a Parquet file for reading as `R`; see [`TypedParquetReader`].
+pub struct TypedParquetReaderBuilder<R, T: ChunkReader> {
+    inner: ParquetRecordBatchReaderBuilder<T>,
+    row_groups: Option<Vec<usize>>,
+    predicates: Vec<StatisticsFilter>,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: SchemaMeta, T: ChunkReader + 'static> TypedParquetReaderBuilder<R, T> {
+    /// Read the footer of `reader`, along with the page index if the file has one.
+    ///
+    /// The schema is checked against `R` by [`build`](Self::build), after any
+    /// projection has been applied.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Parquet`] if the footer cannot be read.
+    pub fn try_new(reader: T) -> Result<Self, ParquetError> {
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        Ok(Self::from_builder(
+            ParquetRecordBatchReaderBuilder::try_new_with_options(reader, options)?,
+        ))
+    }
+
+    /// Wrap a builder configured directly through the `parquet` API.
+    ///
+    /// Predicates only prune pages if the builder was opened with the page index.
+    #[must_use]
+    pub fn from_builder(inner: ParquetRecordBatchReaderBuilder<T>) -> Self {
+        Self {
+            inner,
+            row_groups: None,
+            predicates: Vec::new(),
+            _record: PhantomData,
+        }
+    }
+
+    /// The file's Parquet schema.
+    #[must_use]
+    pub fn parquet_schema(&self) -> &SchemaDescriptor {
+        self.inner.parquet_schema()
+    }
+
+    /// The file's metadata: row groups, statistics and key/value metadata.
+    #[must_use]
+    pub fn metadata(&self) -> &std::sync::Arc<ParquetMetaData> {
+        self.inner.metadata()
+    }
+
+    /// Maximum number of rows per batch.
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.inner = self.inner.with_batch_size(batch_size);
+        self
+    }
+
+    /// Only read the row groups at `row_groups`, in that order.
+    ///
+    /// Predicates prune within this list.
+    #[must_use]
+    pub fn with_row_groups(mut self, row_groups: Vec<usize>) -> Self {
+        self.row_groups = Some(row_groups);
+        self
+    }
+
+    /// Skip row groups and pages whose statistics show that no row can satisfy
+    /// `predicate`.
+    ///
+    /// Predicates combine with AND and are checked by [`build`](Self::build). They
+    /// survive [`project`](Self::project), so they may name columns the projection
+    /// leaves out.
+    #[must_use]
+    pub fn with_predicate(mut self, predicate: ColumnPredicate<R::Column>) -> Self
+    where
+        R: RecordColumns,
+    {
+        self.predicates.push(predicate.filter);
+        self
+    }
+
+    /// Decode only the columns of projection `P`, reading the file as `P`.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Schema`] if a projected column is not in the file.
+    pub fn project<P>(self) -> Result<TypedParquetReaderBuilder<P, T>, ParquetError>
+    where
+        P: Projection<Source = R> + SchemaMeta,
+    {
+        let mask = projection_mask::<P>(self.inner.parquet_schema())?;
+        Ok(TypedParquetReaderBuilder {
+            inner: self.inner.with_projection(mask),
+            row_groups: self.row_groups,
+            predicates: self.predicates,
+            _record: PhantomData,
+        })
+    }
+
+    /// The row groups and row selection that remain after applying the predicates.
+    fn prune(self) -> Result<ParquetRecordBatchReaderBuilder<T>, ParquetError> {
+        let Self {
+            inner,
+            row_groups,
+            predicates,
+            ..
+        } = self;
+        if predicates.is_empty() {
+            return Ok(match row_groups {
+                Some(row_groups) => inner.with_row_groups(row_groups),
+                None => inner,
+            });
+        }
+        let metadata = Arc::clone(inner.metadata());
+        let schema = Arc::clone(inner.schema());
+        let mut row_groups = row_groups.unwrap_or_else(|| (0..metadata.num_row_groups()).collect());
+        let converters = predicates
+            .iter()
+            .map(|p| p.converter(&schema, &metadata))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (predicate, converter) in predicates.iter().zip(&converters) {
+            let keep = predicate.row_groups(converter, &metadata, &row_groups)?;
+            row_groups = row_groups
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(row_group, keep)| keep.then_some(row_group))
+                .collect();
+        }
+
+        let mut selection: Option<RowSelection> = None;
+        for (predicate, converter) in predicates.iter().zip(&converters) {
+            if let Some(pages) = predicate.pages(converter, &metadata, &row_groups)? {
+                selection = Some(match selection {
+                    Some(selection) => selection.intersection(&pages),
+                    None => pages,
+                });
+            }
+        }
+
+        let inner = inner.with_row_groups(row_groups);
+        Ok(match selection.filter(|s| s.skipped_row_count() > 0) {
+            Some(selection) => inner.with_row_selection(selection),
+            None => inner,
+        })
+    }
+
+    /// Check the (projected) schema against `R` and start reading.
+    ///
+    /// Columns are matched by name, so a projection may list its columns in a
+    /// different order than the file.
+    ///
+    /// # Errors
+    /// Returns [`ParquetError::Schema`] if the columns do not match `R` or a predicate's
+    /// values do not match its column, or [`ParquetError::Parquet`] if the reader
+    /// cannot be built.
+    pub fn build(self) -> Result<TypedParquetReader<R>, ParquetError> {
+        let batches = self.prune()?.build()?;
+        let schema = batches.schema();
+        let order = R::fields()
+            .iter()
+            .map(|f| {
+                schema
+                    .index_of(f.name())
+                    .map_err(|_| SchemaError::missing_field(f.name().as_str()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let reorder = order.iter().copied().ne(0..schema.fields().len());
+        let order = if reorder {
+            let projected = schema.project(&order)?;
+            validate_schema::<R>(&projected)?;
+            Some(order)
+        } else {
+            validate_schema::<R>(&schema)?;
+            None
+        };
+        Ok(TypedParquetReader {
+            batches,
+            order,
+            _record: PhantomData,
+        })
+    }
+}
+
+/// Iterator over the batches of a Parquet file as validated [`TypedBatch<R>`]es.
+pub struct TypedParquetReader<R> {
+    batches: ParquetRecordBatchReader,
+    // Column order of `R` within the decoded batches, when it differs.
+    order: Option<Vec<usize>>,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: SchemaMeta> TypedParquetReader<R> {
+    /// Schema of the decoded batches, before columns are put in `R`'s order.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        self.batches.schema()
+    }
+
+    fn typed(&self, batch: RecordBatch) -> Result<TypedBatch<R>, ParquetError> {
+        let batch = match &self.order {
+            Some(order) => batch.project(order)?,
+            None => batch,
+        };
+        Ok(TypedBatch::try_new(batch)?)
+    }
+
+    /// Read every row as an owned `R`, converting one batch at a time.
+    #[cfg(feature = "views")]
+    pub fn into_records(self) -> impl Iterator<Item = Result<R, ParquetError>>
+    where
+        R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+    {
+        self.flat_map(|batch| match batch {
+            Ok(batch) => batch
+                .iter_views()
+                .map(|view| Ok(view.and_then(R::try_from)?))
+                .collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+}
+
+impl<R: SchemaMeta> Iterator for TypedParquetReader<R> {
+    type Item = Result<TypedBatch<R>, ParquetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.batches.next()?;
+        Some(
+            batch
+                .map_err(ParquetError::from)
+                .and_then(|batch| self.typed(batch)),
+        )
+    }
+}
+
+/// A predicate on one column of a record, used to skip row groups and pages by their
+/// statistics; see [`TypedParquetReaderBuilder::with_predicate`].
+///
+/// Built with [`ColumnPredicateExt`]. Null values never satisfy a predicate.
+pub struct ColumnPredicate<C> {
+    filter: StatisticsFilter,
+    _column: PhantomData<fn() -> C>,
+}
+
+impl<C: ColumnRef> ColumnPredicate<C> {
+    fn new<V: ArrowBinding>(column: C, values: Vec<V>, ranges: Vec<(usize, usize)>) -> Self {
+        Self {
+            filter: StatisticsFilter {
+                column: column.name(),
+                values: make_array(V::array_from_vec(values).into_data()),
+                ranges,
+            },
+            _column: PhantomData,
+        }
+    }
+}
+
+/// Predicate constructors on the [`TypedColumn`] constants of the `{Name}Column` enums
+/// generated by `#[derive(Record)]`.
+///
+/// The values must have the column's Rust type, e.g. `Timestamp<Millisecond>` for a
+/// millisecond timestamp column, so a value of another type does not compile.
+///
+/// ```
+/// use typed_arrow::{
+///     Millisecond, Timestamp,
+///     io::parquet::{ColumnPredicateExt, TypedParquetReaderBuilder, TypedParquetWriter},
+///     prelude::*,
+/// };
+///
+/// #[derive(Record)]
+/// struct Event {
+///     ts: Timestamp<Millisecond>,
+///     kind: String,
+/// }
+///
+/// let path = std::env::temp_dir().join(format!(
+///     "typed_arrow_prune_doc_{}.parquet",
+///     std::process::id()
+/// ));
+/// let mut writer = TypedParquetWriter::<Event, _>::try_new(std::fs::File::create(&path)?)?;
+/// for day in 0..4 {
+///     writer.write_rows((0..100).map(|i| Event {
+///         ts: Timestamp::new(day * 86_400_000 + i),
+///         kind: format!("k{}", i % 3),
+///     }))?;
+///     writer.flush_row_group()?;
+/// }
+/// writer.finish()?;
+///
+/// let rows: usize = TypedParquetReaderBuilder::<Event, _>::try_new(std::fs::File::open(&path)?)?
+///     .with_predicate(EventColumn::TS.between(
+///         Timestamp::<Millisecond>::new(86_400_000),
+///         Timestamp::new(86_400_000 + 99),
+///     ))
+///     .build()?
+///     .map(|batch| batch.map(|b| b.len()))
+///     .sum::<Result<_, _>>()?;
+/// assert_eq!(rows, 100); // only the second row group is decoded
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// A value of another type is rejected:
+///
+/// ```compile_fail
+/// use typed_arrow::{Millisecond, Timestamp, io::parquet::ColumnPredicateExt, prelude::*};
+///
+/// #[derive(Record)]
+/// struct Event {
+///     ts: Timestamp<Millisecond>,
+/// }
+///
+/// let _ = EventColumn::TS.between(1_i64, 2_i64);
+/// ```
+pub trait ColumnPredicateExt: Sized {
+    /// Record the column belongs to.
+    type Record: RecordColumns;
+
+    /// Rust value type of the column, without nullability.
+    type Value: ArrowBinding;
+
+    /// Values in `lo..=hi`.
+    fn between(
+        self,
+        lo: Self::Value,
+        hi: Self::Value,
+    ) -> ColumnPredicate<<Self::Record as RecordColumns>::Column>;
+
+    /// Values equal to `value`.
+    fn eq(self, value: Self::Value) -> ColumnPredicate<<Self::Record as RecordColumns>::Column>;
+
+    /// Values equal to any of `values`; an empty set matches nothing.
+    fn in_set<I>(self, values: I) -> ColumnPredicate<<Self::Record as RecordColumns>::Column>
+    where
+        I: IntoIterator<Item = Self::Value>;
+}
+
+impl<R, const I: usize> ColumnPredicateExt for TypedColumn<R, I>
+where
+    R: RecordColumns + ColAt<I, Native: ArrowBinding>,
+{
+    type Record = R;
+    type Value = R::Native;
+
+    fn between(self, lo: R::Native, hi: R::Native) -> ColumnPredicate<R::Column> {
+        ColumnPredicate::new(self.column(), vec![lo, hi], vec![(0, 1)])
+    }
+
+    fn eq(self, value: R::Native) -> ColumnPredicate<R::Column> {
+        ColumnPredicate::new(self.column(), vec![value], vec![(0, 0)])
+    }
+
+    fn in_set<V>(self, values: V) -> ColumnPredicate<R::Column>
+    where
+        V: IntoIterator<Item = R::Native>,
+    {
+        let values: Vec<R::Native> = values.into_iter().collect();
+        let ranges = (0..values.len()).map(|i| (i, i)).collect();
+        ColumnPredicate::new(self.column(), values, ranges)
+    }
+}
+
+/// A column predicate with its record type erased: the column may hold a value in
+/// any of the inclusive ranges `values[lo]..=values[hi]`.
+struct StatisticsFilter {
+    column: &'static str,
+    values: ArrayRef,
+    ranges: Vec<(usize, usize)>,
+}
+
+impl StatisticsFilter {
+    fn converter<'a>(
+        &self,
+        schema: &'a Schema,
+        metadata: &'a ParquetMetaData,
+    ) -> Result<StatisticsConverter<'a>, ParquetError> {
+        let field = schema
+            .field_with_name(self.column)
+            .map_err(|_| SchemaError::missing_field(self.column))?;
+        if field.data_type() != self.values.data_type() {
+            return Err(SchemaError::type_mismatch(
+                field.data_type().clone(),
+                self.values.data_type().clone(),
+            )
+            .into());
+        }
+        Ok(StatisticsConverter::try_new(
+            self.column,
+            schema,
+            metadata.file_metadata().schema_descr(),
+        )?)
+    }
+
+    /// Whether each of `row_groups` may hold a matching row.
+    fn row_groups(
+        &self,
+        converter: &StatisticsConverter<'_>,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+    ) -> Result<Vec<bool>, ParquetError> {
+        let groups = || row_groups.iter().map(|&i| metadata.row_group(i));
+        let rows: Vec<u64> = groups().map(|g| g.num_rows().unsigned_abs()).collect();
+        Ok(self.may_match(
+            &converter.row_group_mins(groups())?,
+            &converter.row_group_maxes(groups())?,
+            &converter.row_group_null_counts(groups())?,
+            &rows,
+        )?)
+    }
+
+    /// Rows of `row_groups` on pages that may hold a matching row, or `None` if the
+    /// file has no page index for the column.
+    fn pages(
+        &self,
+        converter: &StatisticsConverter<'_>,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+    ) -> Result<Option<RowSelection>, ParquetError> {
+        let (Some(column_index), Some(offset_index)) =
+            (metadata.column_index(), metadata.offset_index())
+        else {
+            return Ok(None);
+        };
+        let mut selectors = Vec::new();
+        for row_group in row_groups {
+            let group = std::slice::from_ref(row_group);
+            let Some(rows) =
+                converter.data_page_row_counts(offset_index, metadata.row_groups(), group)?
+            else {
+                return Ok(None);
+            };
+            let rows: Vec<u64> = rows.iter().map(Option::unwrap_or_default).collect();
+            let keep = self.may_match(
+                &converter.data_page_mins(column_index, offset_index, group)?,
+                &converter.data_page_maxes(column_index, offset_index, group)?,
+                &converter.data_page_null_counts(column_index, offset_index, group)?,
+                &rows,
+            )?;
+            for (rows, keep) in rows.into_iter().zip(keep) {
+                let rows = usize::try_from(rows).unwrap_or(usize::MAX);
+                selectors.push(if keep {
+                    RowSelector::select(rows)
+                } else {
+                    RowSelector::skip(rows)
+                });
+            }
+        }
+        Ok(Some(RowSelection::from(selectors)))
+    }
+
+    /// For each statistics entry, whether its `[min, max]` range overlaps one of the
+    /// predicate's ranges and it holds at least one non-null value. Unknown
+    /// statistics always may match.
+    fn may_match(
+        &self,
+        mins: &ArrayRef,
+        maxes: &ArrayRef,
+        null_counts: &UInt64Array,
+        rows: &[u64],
+    ) -> Result<Vec<bool>, ArrowError> {
+        let all_null =
+            |i: usize| null_counts.is_valid(i) && rows.get(i) == Some(&null_counts.value(i));
+        if mins.data_type() != self.values.data_type()
+            || maxes.data_type() != self.values.data_type()
+        {
+            return Ok((0..rows.len()).map(|i| !all_null(i)).collect());
+        }
+        let known = |b: &BooleanArray, i: usize| b.is_valid(i) && b.value(i);
+        let mut keep = vec![false; rows.len()];
+        for &(lo, hi) in &self.ranges {
+            let above = cmp::gt(mins, &Scalar::new(self.values.slice(hi, 1)))?;
+            let below = cmp::lt(maxes, &Scalar::new(self.values.slice(lo, 1)))?;
+            for (i, keep) in keep.iter_mut().enumerate() {
+                *keep |= !known(&above, i) && !known(&below, i);
+            }
+        }
+        for (i, keep) in keep.iter_mut().enumerate() {
+            *keep &= !all_null(i);
+        }
+        Ok(keep)
+    }
+}
@@ -115,16 +115,40 @@
 //! | [`ForEachCol`](schema::ForEachCol) | Compile-time column iteration via [`ColumnVisitor`](schema::ColumnVisitor) |
 //! | [`SchemaMeta`](schema::SchemaMeta) | Runtime schema access: `fields()`, `schema()`, `metadata()` |
 //! | [`StructMeta`](schema::StructMeta) | Nested struct support: `child_fields()`, `new_struct_builder()` |
+//! | [`RecordColumns`](schema::RecordColumns) | Generated `{Name}Column` enum: `name()`, `index()`, `data_type()`, nested [`ColumnPath`](schema::ColumnPath), typed [`TypedColumn`](schema::TypedColumn) constants |
+//! | [`FromRecordBatchColumns`](schema::FromRecordBatchColumns) | Typed `{Name}Columns<'a>` column references via [`AsTypedColumns::columns_of`] |
 //!
 //! ## Row Building Traits (in [`schema`] module)
 //!
 //! | Trait | Description |
 //! |-------|-------------|
 //! | [`BuildRows`](schema::BuildRows) | Entry point: `new_builders(capacity)` → `Builders` |
-//! | [`RowBuilder<T>`](schema::RowBuilder) | `append_row()`, `append_rows()`, `append_option_row()`, `finish()` |
+//! | [`RowBuilder<T>`](schema::RowBuilder) | `append_row()`, `append_rows()`, `append_option_row()`, `try_append_row()`, `finish()` |
+//! | [`ReusableRowBuilder<T>`](schema::ReusableRowBuilder) | `finish_and_reset()`, `len()`, `memory_size()` for emitting batches |
 //! | [`IntoRecordBatch`](schema::IntoRecordBatch) | Convert finished arrays to [`RecordBatch`](arrow_array::RecordBatch) |
+//! | [`BatchWriter<R>`](batch_writer::BatchWriter) | Emit a `RecordBatch` every N rows or buffered bytes |
+//! | `stream::RowStreamExt` | With feature `futures`: `Stream<Item = R>` → `Stream<Item = RecordBatch>`, and back via `RecordBatchStreamExt` |
 //! | [`AppendStruct`](schema::AppendStruct) | Append struct fields into a `StructBuilder` |
 //!
+//! ## Typed Batches (in [`typed_batch`] module)
+//!
+//! | Type | Description |
+//! |------|-------------|
+//! | [`TypedBatch<R>`](TypedBatch) | `RecordBatch` checked against `R` once: `columns()`, `iter_views()`, `row(i)`, `slice()`, `into_inner()` |
+//! | [`TypedTable<R>`](table::TypedTable) | Validated `Vec<RecordBatch>` with global row indexing and multi-batch views |
+//!
+//! With a `compute-5x` feature, the [`compute`] module adds `filter_rows`, `take_rows` and
+//! `sort_by`, which return a new `TypedBatch<R>`, and per-column `sum`, `min`, `max`,
+//! `count_nulls` and `distinct_count`, which return the column's Rust type.
+//!
+//! ## Validation (in [`validate`] module)
+//!
+//! | Function | Description |
+//! |----------|-------------|
+//! | [`validate_batch`](validate::validate_batch) | Check a `RecordBatch` against a Record's types and nested nullability |
+//! | [`validate_batch_all`](validate::validate_batch_all) | Same, collecting every violation with its path |
+//! | [`validate_schema`](validate::validate_schema) | Check a file or stream schema's column names and types before reading |
+//!
 //! ## Type Binding Trait (in [`bridge`] module)
 //!
 //! | Trait | Description |
@@ -264,9 +288,98 @@ pub extern crate arrow_schema_56 as arrow_schema;
 #[cfg(feature = "arrow-57")]
 pub extern crate arrow_schema_57 as arrow_schema;
 
+#[cfg(feature = "parquet-55")]
+pub extern crate parquet_55 as parquet;
+#[cfg(feature = "parquet-56")]
+pub extern crate parquet_56 as parquet;
+#[cfg(feature = "parquet-57")]
+pub extern crate parquet_57 as parquet;
+
+#[cfg(feature = "csv-55")]
+pub extern crate arrow_csv_55 as arrow_csv;
+#[cfg(feature = "csv-56")]
+pub extern crate arrow_csv_56 as arrow_csv;
+#[cfg(feature = "csv-57")]
+pub extern crate arrow_csv_57 as arrow_csv;
+
+#[cfg(any(feature = "csv-55", feature = "json-55"))]
+pub extern crate arrow_cast_55 as arrow_cast;
+#[cfg(any(feature = "csv-56", feature = "json-56"))]
+pub extern crate arrow_cast_56 as arrow_cast;
+#[cfg(any(feature = "csv-57", feature = "json-57"))]
+pub extern crate arrow_cast_57 as arrow_cast;
+
+#[cfg(feature = "json-55")]
+pub extern crate arrow_json_55 as arrow_json;
+#[cfg(feature = "json-56")]
+pub extern crate arrow_json_56 as arrow_json;
+#[cfg(feature = "json-57")]
+pub extern crate arrow_json_57 as arrow_json;
+
+#[cfg(feature = "ipc-55")]
+pub extern crate arrow_ipc_55 as arrow_ipc;
+#[cfg(feature = "ipc-56")]
+pub extern crate arrow_ipc_56 as arrow_ipc;
+#[cfg(feature = "ipc-57")]
+pub extern crate arrow_ipc_57 as arrow_ipc;
+
+#[cfg(feature = "rayon")]
+pub extern crate rayon;
+
+#[cfg(feature = "futures")]
+pub extern crate futures_core;
+
+#[cfg(feature = "compute-55")]
+pub extern crate arrow_arith_55 as arrow_arith;
+#[cfg(feature = "compute-56")]
+pub extern crate arrow_arith_56 as arrow_arith;
+#[cfg(feature = "compute-57")]
+pub extern crate arrow_arith_57 as arrow_arith;
+
+#[cfg(any(feature = "compute-55", feature = "parquet-55"))]
+pub extern crate arrow_ord_55 as arrow_ord;
+#[cfg(any(feature = "compute-56", feature = "parquet-56"))]
+pub extern crate arrow_ord_56 as arrow_ord;
+#[cfg(any(feature = "compute-57", feature = "parquet-57"))]
+pub extern crate arrow_ord_57 as arrow_ord;
+
+#[cfg(any(feature = "compute-55", feature = "json-55"))]
+pub extern crate arrow_select_55 as arrow_select;
+#[cfg(any(feature = "compute-56", feature = "json-56"))]
+pub extern crate arrow_select_56 as arrow_select;
+#[cfg(any(feature = "compute-57", feature = "json-57"))]
+pub extern crate arrow_select_57 as arrow_select;
+
+pub mod batch_writer;
 pub mod bridge;
+#[cfg(any(feature = "compute-55", feature = "compute-56", feature = "compute-57"))]
+pub mod compute;
 pub mod error;
+#[cfg(any(feature = "ffi-55", feature = "ffi-56", feature = "ffi-57"))]
+pub mod ffi;
+#[cfg(any(
+    feature = "csv-55",
+    feature = "csv-56",
+    feature = "csv-57",
+    feature = "ipc-55",
+    feature = "ipc-56",
+    feature = "ipc-57",
+    feature = "json-55",
+    feature = "json-56",
+    feature = "json-57",
+    feature = "parquet-55",
+    feature = "parquet-56",
+    feature = "parquet-57"
+))]
+pub mod io;
+#[cfg(feature = "rayon")]
+pub mod par;
 pub mod schema;
+#[cfg(feature = "futures")]
+pub mod stream;
+pub mod table;
+pub mod typed_batch;
+pub mod validate;
 
 /// Prelude exporting the most common traits and markers.
 pub mod prelude {
@@ -280,12 +393,15 @@ pub mod prelude {
     pub use crate::error::ViewAccessError;
     #[cfg(feature = "views")]
     pub use crate::schema::{FromRecordBatch, ViewResultIteratorExt};
-    #[cfg(any(feature = "arrow-55", feature = "arrow-56", feature = "arrow-57"))]
-    pub use crate::{arrow_array, arrow_buffer, arrow_data, arrow_schema};
     pub use crate::{
-        error::SchemaError,
-        schema::{BuildRows, ColAt, ColumnVisitor, FieldMeta, ForEachCol, Record},
+        AsTypedColumns, TypedBatch,
+        error::{AppendError, SchemaError},
+        schema::{
+            BuildRows, ColAt, ColumnVisitor, FieldMeta, ForEachCol, FromRecordBatchColumns, Record,
+        },
     };
+    #[cfg(any(feature = "arrow-55", feature = "arrow-56", feature = "arrow-57"))]
+    pub use crate::{arrow_array, arrow_buffer, arrow_data, arrow_schema};
 }
 
 // Re-export the derive macro when enabled
@@ -301,6 +417,7 @@ pub use crate::bridge::{
     LargeList, LargeUtf8, List, Map, Microsecond, Millisecond, Nanosecond, Null, OrderedMap,
     Second, Time32, Time64, TimeZoneSpec, Timestamp, TimestampTz, Utc,
 };
+pub use crate::typed_batch::TypedBatch;
 
 /// Extension trait for creating typed view iterators from `RecordBatch`.
 #[cfg(feature = "views")]
@@ -354,3 +471,46 @@ impl AsViewsIterator for arrow_array::RecordBatch {
         T::from_record_batch(self)
     }
 }
+
+/// Extension trait for borrowing typed column arrays from a `RecordBatch`.
+pub trait AsTypedColumns {
+    /// Borrow every column as its typed Arrow array after a single schema check.
+    ///
+    /// # Errors
+    /// Returns `SchemaError` if the RecordBatch schema doesn't match the expected Record type.
+    ///
+    /// # Example
+    /// ```
+    /// use typed_arrow::{arrow_array::Array, prelude::*};
+    ///
+    /// #[derive(Record)]
+    /// struct Row {
+    ///     id: i32,
+    ///     name: Option<String>,
+    /// }
+    ///
+    /// let mut b = <Row as BuildRows>::new_builders(2);
+    /// b.append_row(Row {
+    ///     id: 1,
+    ///     name: Some("a".into()),
+    /// });
+    /// b.append_row(Row { id: 2, name: None });
+    /// let batch = b.finish().into_record_batch();
+    ///
+    /// let cols = batch.columns_of::<Row>()?;
+    /// assert_eq!(cols.id.values().iter().sum::<i32>(), 3); // &Int32Array
+    /// assert_eq!(cols.name.null_count(), 1); // &StringArray
+    /// # Ok::<_, typed_arrow::error::SchemaError>(())
+    /// ```
+    fn columns_of<T: schema::FromRecordBatchColumns>(
+        &self,
+    ) -> Result<T::Columns<'_>, error::SchemaError>;
+}
+
+impl AsTypedColumns for arrow_array::RecordBatch {
+    fn columns_of<T: schema::FromRecordBatchColumns>(
+        &self,
+    ) -> Result<T::Columns<'_>, error::SchemaError> {
+        T::columns_from_record_batch(self)
+    }
+}
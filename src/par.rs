@@ -0,0 +1,197 @@
+//! Parallel row views with [rayon](https://docs.rs/rayon) (feature `rayon`).
+//!
+//! [`AsParViewsIterator::par_iter_views`] checks the schema once and returns an
+//! [`IndexedParallelIterator`] over the same views `iter_views` yields. Work is
+//! split into contiguous row ranges, each served by a clone of the sequential
+//! views iterator positioned in O(1), so no row is read twice.
+//!
+//! ```
+//! use rayon::prelude::*;
+//! use typed_arrow::{par::AsParViewsIterator, prelude::*};
+//!
+//! #[derive(Record)]
+//! struct Row {
+//!     id: i64,
+//!     name: String,
+//! }
+//!
+//! let mut b = <Row as BuildRows>::new_builders(1000);
+//! b.append_rows((0..1000).map(|id| Row {
+//!     id,
+//!     name: format!("r{id}"),
+//! }));
+//! let batch = b.finish().into_record_batch();
+//!
+//! let total: i64 = batch
+//!     .par_iter_views::<Row>()?
+//!     .map(|r| r.map(|v| v.id))
+//!     .sum::<Result<i64, _>>()?;
+//! assert_eq!(total, 499_500);
+//!
+//! let rows: Vec<Row> = batch.par_iter_views::<Row>()?.try_into_records()?;
+//! assert_eq!(rows[999].name, "r999");
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::iter::{Skip, Take};
+
+use arrow_array::RecordBatch;
+use rayon::iter::{
+    IndexedParallelIterator, ParallelIterator,
+    plumbing::{Consumer, Producer, ProducerCallback, UnindexedConsumer, bridge},
+};
+
+use crate::{
+    error::{SchemaError, ViewAccessError},
+    schema::FromRecordBatch,
+    typed_batch::TypedBatch,
+};
+
+/// Extension trait for creating parallel typed view iterators from `RecordBatch`.
+pub trait AsParViewsIterator {
+    /// Iterate over typed views of rows in parallel.
+    ///
+    /// Items are the same `Result<View, ViewAccessError>` values `iter_views` yields,
+    /// and ordered collection preserves row order.
+    ///
+    /// # Errors
+    /// Returns `SchemaError` if the RecordBatch schema doesn't match the expected Record type.
+    fn par_iter_views<T>(&self) -> Result<ParViews<T::Views<'_>>, SchemaError>
+    where
+        T: FromRecordBatch,
+        for<'a> T::Views<'a>: SplittableViews;
+}
+
+impl AsParViewsIterator for RecordBatch {
+    fn par_iter_views<T>(&self) -> Result<ParViews<T::Views<'_>>, SchemaError>
+    where
+        T: FromRecordBatch,
+        for<'a> T::Views<'a>: SplittableViews,
+    {
+        T::from_record_batch(self).map(ParViews::new)
+    }
+}
+
+impl<R> TypedBatch<R>
+where
+    R: FromRecordBatch,
+    for<'a> R::Views<'a>: SplittableViews,
+{
+    /// Iterate over row views in parallel without rechecking the schema.
+    #[must_use]
+    pub fn par_iter_views(&self) -> ParViews<R::Views<'_>> {
+        ParViews::new(self.iter_views())
+    }
+}
+
+/// Views iterators that can be split into independent row ranges.
+///
+/// Implemented for every `Clone + DoubleEndedIterator + ExactSizeIterator + Send`
+/// iterator with `Send` items, which includes the generated `{Name}Views` whenever
+/// the record's view types are `Send`.
+pub trait SplittableViews:
+    Clone + DoubleEndedIterator<Item: Send> + ExactSizeIterator + Send
+{
+}
+
+impl<I> SplittableViews for I where
+    I: Clone + DoubleEndedIterator<Item: Send> + ExactSizeIterator + Send
+{
+}
+
+/// Parallel iterator returned by [`AsParViewsIterator::par_iter_views`].
+#[derive(Clone)]
+pub struct ParViews<V> {
+    views: V,
+}
+
+impl<V: SplittableViews> ParViews<V> {
+    /// Wrap a views iterator, such as one narrowed with the generated `slice`.
+    pub fn new(views: V) -> Self {
+        Self { views }
+    }
+}
+
+impl<V, T> ParViews<V>
+where
+    V: SplittableViews + Iterator<Item = Result<T, ViewAccessError>>,
+    T: Send,
+{
+    /// Convert every view to an owned record in parallel, preserving row order.
+    ///
+    /// # Errors
+    /// Returns the first `ViewAccessError` raised while reading or converting a row.
+    pub fn try_into_records<R, E>(self) -> Result<Vec<R>, ViewAccessError>
+    where
+        R: TryFrom<T, Error = E> + Send,
+        E: Into<ViewAccessError>,
+    {
+        self.map(|view| view.and_then(|v| R::try_from(v).map_err(Into::into)))
+            .collect()
+    }
+}
+
+impl<V: SplittableViews> ParallelIterator for ParViews<V> {
+    type Item = V::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.views.len())
+    }
+}
+
+impl<V: SplittableViews> IndexedParallelIterator for ParViews<V> {
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let len = self.views.len();
+        callback.callback(ViewsProducer {
+            views: self.views,
+            offset: 0,
+            len,
+        })
+    }
+}
+
+// A row range over a clone of the views iterator; `skip`/`take` position it
+// through `nth`/`nth_back`, which the generated views implement in O(1).
+struct ViewsProducer<V> {
+    views: V,
+    offset: usize,
+    len: usize,
+}
+
+impl<V: SplittableViews> Producer for ViewsProducer<V> {
+    type Item = V::Item;
+    type IntoIter = Take<Skip<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.views.skip(self.offset).take(self.len)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let left = Self {
+            views: self.views.clone(),
+            offset: self.offset,
+            len: index,
+        };
+        let right = Self {
+            views: self.views,
+            offset: self.offset + index,
+            len: self.len - index,
+        };
+        (left, right)
+    }
+}
@@ -9,6 +9,7 @@
 //! | [`Record`] | Marker trait with `const LEN: usize` for column count |
 //! | [`ColAt<I>`] | Per-column metadata: type, name, nullability, builder, array |
 //! | [`ForEachCol`] | Compile-time iteration via [`ColumnVisitor`] |
+//! | [`RecordColumns`] | Generated `{Name}Column` enum and its [`TypedColumn`] constants |
 //! | [`SchemaMeta`] | Runtime schema: `fields()`, `schema()`, `metadata()` |
 //! | [`StructMeta`] | Nested struct support: `child_fields()`, `new_struct_builder()` |
 //!
@@ -18,6 +19,7 @@
 //! |-------|-------------|
 //! | [`BuildRows`] | Entry point: `new_builders(capacity)` |
 //! | [`RowBuilder`] | Append methods: `append_row()`, `append_rows()`, `finish()` |
+//! | [`ReusableRowBuilder`] | `finish_and_reset()`, `len()`, `memory_size()` for batching |
 //! | [`IntoRecordBatch`] | Convert arrays to [`RecordBatch`] |
 //! | [`AppendStruct`] | Append struct fields into a `StructBuilder` |
 //!
@@ -99,9 +101,9 @@ use arrow_array::{
 };
 use arrow_schema::{DataType, Field, Schema};
 
-pub use crate::error::SchemaError;
 #[cfg(feature = "views")]
 pub use crate::error::ViewAccessError;
+pub use crate::error::{AppendError, SchemaError};
 
 #[cfg(feature = "views")]
 impl From<ViewAccessError> for SchemaError {
@@ -177,6 +179,230 @@ pub trait ForEachCol: Record {
     fn for_each_col<V: ColumnVisitor>();
 }
 
+/// A column of a `Record`, identified without strings.
+///
+/// Implemented by the `{Name}Column` enum generated by `#[derive(Record)]`.
+pub trait ColumnRef: Copy {
+    /// Zero-based column index within the record.
+    fn index(self) -> usize;
+
+    /// Arrow field name of the column.
+    fn name(self) -> &'static str;
+
+    /// Path to this column, to be extended with [`ColumnPath::child`] for nested fields.
+    fn path(self) -> ColumnPath {
+        ColumnPath::new(self)
+    }
+}
+
+/// Associates a `Record` with its generated `{Name}Column` enum.
+///
+/// The enum of a generic record has no `data_type()`, `is_nullable()` or typed column
+/// constants, since those depend on the type parameters; use [`ColAt`] on the
+/// instantiated record instead.
+pub trait RecordColumns: Record {
+    /// The column enum for this record.
+    type Column: ColumnRef + 'static;
+
+    /// All columns in schema order.
+    const COLUMNS: &'static [Self::Column];
+}
+
+/// Column `I` of record `R`, carrying the column's value type [`ColAt<I>::Native`].
+///
+/// `#[derive(Record)]` generates one constant per column on the `{Name}Column` enum,
+/// named after the field in upper case (`EventColumn::TS` for a field `ts`; a field
+/// named `all` gets none, as `ALL` lists the columns). APIs that
+/// take values of a column's type, such as the Parquet predicates, accept these
+/// instead of the untyped enum variants.
+///
+/// ```
+/// use typed_arrow::{prelude::*, schema::ColumnRef};
+///
+/// #[derive(Record)]
+/// struct Event {
+///     id: i64,
+///     kind: Option<String>,
+/// }
+///
+/// assert_eq!(EventColumn::KIND.name(), "kind");
+/// assert_eq!(EventColumn::KIND.column(), EventColumn::Kind);
+/// ```
+pub struct TypedColumn<R, const I: usize>(PhantomData<fn() -> R>);
+
+impl<R, const I: usize> TypedColumn<R, I> {
+    /// Refer to column `I` of `R`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// The untyped `{Name}Column` variant for this column.
+    #[must_use]
+    pub fn column(self) -> R::Column
+    where
+        R: RecordColumns,
+    {
+        R::COLUMNS[I]
+    }
+}
+
+impl<R, const I: usize> Default for TypedColumn<R, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, const I: usize> Clone for TypedColumn<R, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R, const I: usize> Copy for TypedColumn<R, I> {}
+
+impl<R: ColAt<I>, const I: usize> std::fmt::Debug for TypedColumn<R, I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TypedColumn").field(&R::NAME).finish()
+    }
+}
+
+impl<R: ColAt<I>, const I: usize> ColumnRef for TypedColumn<R, I> {
+    fn index(self) -> usize {
+        I
+    }
+
+    fn name(self) -> &'static str {
+        R::NAME
+    }
+}
+
+/// A record whose columns are a named subset of a wider `Source` record.
+///
+/// Implemented by `#[derive(Record)]` with `#[record(project_of = Wide)]`. Each field
+/// must share its Rust name with a field of `Wide` and have the same value type; a
+/// field projected from a nullable source column must be `Option<_>`. Both are checked
+/// at compile time. The projection's `FromRecordBatch` and `FromRecordBatchColumns`
+/// select columns by name, so they accept batches of the source record directly.
+///
+/// ```
+/// use typed_arrow::{prelude::*, schema::Projection};
+///
+/// #[derive(Record)]
+/// struct Wide {
+///     id: i64,
+///     name: String,
+///     ts: i64,
+/// }
+///
+/// #[derive(Record)]
+/// #[record(project_of = Wide)]
+/// struct IdTs {
+///     id: i64,
+///     ts: i64,
+/// }
+///
+/// assert_eq!(IdTs::source_indices(), vec![0, 2]);
+/// ```
+///
+/// A type that differs from the source column is rejected:
+///
+/// ```compile_fail
+/// use typed_arrow::prelude::*;
+///
+/// #[derive(Record)]
+/// struct Wide {
+///     id: i64,
+/// }
+///
+/// #[derive(Record)]
+/// #[record(project_of = Wide)]
+/// struct Id {
+///     id: i32,
+/// }
+/// ```
+pub trait Projection: Record {
+    /// The wider record this one is projected from.
+    type Source: RecordColumns;
+
+    /// Source column for each of this record's columns, in this record's order.
+    const SOURCE_COLUMNS: &'static [<Self::Source as RecordColumns>::Column];
+
+    /// Indices of the projected columns within the source record.
+    #[must_use]
+    fn source_indices() -> Vec<usize> {
+        Self::SOURCE_COLUMNS.iter().map(|c| c.index()).collect()
+    }
+
+    /// Arrow field names of the projected columns within the source record.
+    #[must_use]
+    fn source_names() -> Vec<&'static str> {
+        Self::SOURCE_COLUMNS.iter().map(|c| c.name()).collect()
+    }
+}
+
+/// Path from a top-level column down to a nested struct field.
+///
+/// ```
+/// use typed_arrow::prelude::*;
+///
+/// #[derive(Record)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// #[derive(Record)]
+/// struct Person {
+///     id: i64,
+///     address: Address,
+/// }
+///
+/// let path = PersonColumn::Address.path().child(AddressColumn::City);
+/// assert_eq!(path.to_string(), "address.city");
+/// assert_eq!(path.indices(), &[1, 0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnPath {
+    names: Vec<&'static str>,
+    indices: Vec<usize>,
+}
+
+impl ColumnPath {
+    /// Start a path at a top-level column.
+    pub fn new(col: impl ColumnRef) -> Self {
+        Self {
+            names: vec![col.name()],
+            indices: vec![col.index()],
+        }
+    }
+
+    /// Descend into a child field of the struct column at the end of this path.
+    #[must_use]
+    pub fn child(mut self, col: impl ColumnRef) -> Self {
+        self.names.push(col.name());
+        self.indices.push(col.index());
+        self
+    }
+
+    /// Field names from the top-level column down.
+    #[must_use]
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+
+    /// Child indices from the top-level column down.
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+impl std::fmt::Display for ColumnPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.names.join("."))
+    }
+}
+
 // No Arrow markers: ColAt exposes DATA_TYPE/ColumnBuilder/ColumnArray
 
 /// Metadata and builder utilities for nested Struct fields.
@@ -186,6 +412,15 @@ pub trait StructMeta: Record {
 
     /// Construct a `StructBuilder` with appropriate child builders for this struct.
     fn new_struct_builder(capacity: usize) -> StructBuilder;
+
+    /// Bytes held by the values appended to a builder from
+    /// [`new_struct_builder`](Self::new_struct_builder).
+    ///
+    /// The default only counts the struct's validity bitmap; the derive adds every
+    /// child builder's [`ArrowBinding::builder_memory_size`](crate::bridge::ArrowBinding::builder_memory_size).
+    fn struct_builder_memory_size(b: &StructBuilder) -> usize {
+        b.validity_slice().map_or(0, <[u8]>::len)
+    }
 }
 
 /// Arrow runtime schema metadata for a top-level Record.
@@ -193,6 +428,10 @@ pub trait SchemaMeta: Record {
     /// Top-level fields: (name, `data_type`, nullable) represented as `Field`s.
     fn fields() -> Vec<Field>;
 
+    /// Whether batches may hold other columns too, with this record's columns found by
+    /// name. True for [`Projection`]s, which read batches of their source record.
+    const SELECTS_COLUMNS_BY_NAME: bool = false;
+
     /// Optional top-level schema key/value metadata.
     #[must_use]
     fn metadata() -> HashMap<String, String> {
@@ -204,6 +443,33 @@ pub trait SchemaMeta: Record {
         let fields: Vec<Arc<Field>> = Self::fields().into_iter().map(Arc::new).collect();
         Arc::new(Schema::new_with_metadata(fields, Self::metadata()))
     }
+
+    /// Parquet storage hints declared with `#[record(parquet(...))]`, keyed by the
+    /// Arrow field name of each annotated top-level column.
+    #[must_use]
+    fn parquet_hints() -> Vec<(&'static str, ParquetColumnHints)> {
+        Vec::new()
+    }
+}
+
+/// Parquet storage hints for one column, from `#[record(parquet(...))]`.
+///
+/// Values are the names Parquet uses (`"delta_binary_packed"`, `"zstd(3)"`,
+/// `"page"`); `None` leaves the writer's default in place. A Parquet feature
+/// turns them into writer properties, see `io::parquet::writer_properties`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParquetColumnHints {
+    /// Value encoding, e.g. `"plain"` or `"delta_binary_packed"`; used in place of
+    /// dictionary encoding unless `dictionary` is `Some(true)`.
+    pub encoding: Option<&'static str>,
+    /// Compression codec with optional level, e.g. `"snappy"` or `"zstd(3)"`.
+    pub compression: Option<&'static str>,
+    /// Statistics level: `"none"`, `"chunk"` or `"page"`.
+    pub statistics: Option<&'static str>,
+    /// Whether to try dictionary encoding.
+    pub dictionary: Option<bool>,
+    /// Whether to write a bloom filter.
+    pub bloom_filter: Option<bool>,
 }
 
 /// Row-based building interface: construct typed column builders, append owned rows,
@@ -235,10 +501,53 @@ pub trait RowBuilder<Row> {
     fn append_rows<I: IntoIterator<Item = Row>>(&mut self, rows: I);
     /// Append an iterator of optional rows.
     fn append_option_rows<I: IntoIterator<Item = Option<Row>>>(&mut self, rows: I);
+    /// Append a non-null row, returning an error instead of panicking when a value
+    /// cannot be represented (e.g. decimal precision, dictionary key overflow).
+    ///
+    /// Every field is validated, and every dictionary key reserved, before any
+    /// column is appended to, so a rejected row leaves every builder at its previous
+    /// length.
+    ///
+    /// The default appends with [`append_row`](Self::append_row) and never fails;
+    /// derive-generated builders override it with the checks above.
+    ///
+    /// # Errors
+    /// Returns [`AppendError::Column`] identifying the offending column.
+    fn try_append_row(&mut self, row: Row) -> Result<(), AppendError> {
+        self.append_row(row);
+        Ok(())
+    }
+    /// Append an iterator of non-null rows, stopping at the first failure.
+    ///
+    /// Rows before the failing one stay appended.
+    ///
+    /// # Errors
+    /// Returns [`AppendError::Row`] wrapping the failing row's error.
+    fn try_append_rows<I: IntoIterator<Item = Row>>(&mut self, rows: I) -> Result<(), AppendError> {
+        for (i, row) in rows.into_iter().enumerate() {
+            self.try_append_row(row).map_err(|e| e.at_row(i))?;
+        }
+        Ok(())
+    }
     /// Finish and produce arrays.
     fn finish(self) -> Self::Arrays;
 }
 
+/// Trait implemented by derive-generated builders that can emit a batch and keep
+/// building, as used by [`BatchWriter`](crate::batch_writer::BatchWriter).
+pub trait ReusableRowBuilder<Row>: RowBuilder<Row> {
+    /// Finish the appended rows and leave the builders empty for reuse.
+    fn finish_and_reset(&mut self) -> Self::Arrays;
+    /// Number of rows appended since the builders were created or last finished.
+    fn len(&self) -> usize;
+    /// Whether no rows have been appended.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Bytes held in the builders' buffers by the appended rows.
+    fn memory_size(&self) -> usize;
+}
+
 /// Trait implemented by derive-generated arrays to assemble a `RecordBatch`.
 pub trait IntoRecordBatch {
     /// Assemble and return an `arrow_array::RecordBatch`.
@@ -271,6 +580,43 @@ pub trait AppendStructRef {
     /// Append this struct's child values into the provided `StructBuilder` using borrows.
     /// Caller is responsible for setting the parent validity via `append(true)`.
     fn append_borrowed_into(&self, b: &mut StructBuilder);
+
+    /// Check every field value without touching a builder, so a failing struct can
+    /// be rejected before any child is appended.
+    ///
+    /// # Errors
+    /// Returns the first failing field's error, wrapped with its column context.
+    fn validate_fields(&self) -> Result<(), AppendError> {
+        Ok(())
+    }
+
+    /// Reserve every field value in the child builders of `b` (see
+    /// [`ArrowBinding::reserve_value`](crate::bridge::ArrowBinding::reserve_value)) without
+    /// appending it.
+    ///
+    /// # Errors
+    /// Returns the first failing field's error, wrapped with its column context.
+    fn reserve_fields(&self, _b: &mut StructBuilder) -> Result<(), AppendError> {
+        Ok(())
+    }
+}
+
+/// Trait for borrowing a RecordBatch's columns as their typed Arrow arrays.
+///
+/// Implemented automatically by `#[derive(Record)]` to generate a `{Name}Columns<'a>`
+/// struct with one public field per column, typed as `&'a <ColAt<I>>::ColumnArray`.
+/// Columnar code can then call Arrow kernels directly without per-column downcasts.
+pub trait FromRecordBatchColumns: Record + Sized {
+    /// The struct of typed column references.
+    type Columns<'a>
+    where
+        Self: 'a;
+
+    /// Downcast every column of `batch` to its typed array.
+    ///
+    /// # Errors
+    /// Returns `SchemaError` if the column count differs or a column has the wrong type.
+    fn columns_from_record_batch(batch: &RecordBatch) -> Result<Self::Columns<'_>, SchemaError>;
 }
 
 /// Trait for creating zero-copy views over a RecordBatch.
@@ -375,3 +721,29 @@ pub trait StructView: Record + Sized {
     /// Check if the struct value at the given index is null.
     fn is_null_at(array: &arrow_array::StructArray, index: usize) -> bool;
 }
+
+/// Trait implemented by `#[derive(Record)]` structs to append a struct view's fields
+/// into a `StructBuilder`, the view counterpart of [`AppendStructRef`].
+#[cfg(feature = "views")]
+pub trait AppendStructView: StructView {
+    /// Append the view's child values into `b`.
+    /// Caller is responsible for setting the parent validity via `append(true)`.
+    ///
+    /// # Errors
+    /// Returns the `ViewAccessError` raised while reading a nested field.
+    fn append_view_into(view: Self::View<'_>, b: &mut StructBuilder)
+    -> Result<(), ViewAccessError>;
+}
+
+/// Row builders that append row views of `Row` directly, without converting them to
+/// owned rows. Implemented by the `{Name}Builders` generated by `#[derive(Record)]`.
+#[cfg(feature = "views")]
+pub trait AppendViewRow<Row: StructView> {
+    /// Append a row view, e.g. from another batch.
+    ///
+    /// # Errors
+    /// Returns the `ViewAccessError` raised while reading a nested value. This only
+    /// happens for batches not validated against `Row`; the builders may then hold a
+    /// partial row and should be discarded.
+    fn append_view(&mut self, view: Row::View<'_>) -> Result<(), ViewAccessError>;
+}
@@ -0,0 +1,268 @@
+//! Async [`Stream`] adapters between typed rows and `RecordBatch`es (feature `futures`).
+//!
+//! [`RowBatches`] drives a [`BatchWriter`] from a `Stream<Item = R>`, yielding a batch
+//! whenever the writer's row or byte cap is reached, when a configured delay has
+//! passed since the first pending row, and once more for the remainder when the input
+//! ends. [`BatchRows`] goes the other way: it checks each incoming batch against `R`,
+//! reads it through views and yields owned records.
+//!
+//! Both adapters are runtime agnostic; the flush delay uses
+//! [`futures-timer`](https://docs.rs/futures-timer), which works under tokio.
+//!
+//! ```
+//! use futures::{StreamExt, executor::block_on, stream};
+//! use typed_arrow::{
+//!     batch_writer::BatchWriter,
+//!     prelude::*,
+//!     stream::{RecordBatchStreamExt, RowStreamExt},
+//! };
+//!
+//! #[derive(Record, Debug, PartialEq)]
+//! struct Row {
+//!     id: i64,
+//!     name: String,
+//! }
+//!
+//! let rows = stream::iter((0..10).map(|id| Row {
+//!     id,
+//!     name: format!("r{id}"),
+//! }));
+//! let batches: Vec<_> = block_on(rows.record_batches(BatchWriter::new(4)).collect());
+//! let sizes: Vec<usize> = batches.iter().map(|b| b.num_rows()).collect();
+//! assert_eq!(sizes, vec![4, 4, 2]);
+//!
+//! let back: Vec<Row> = block_on(
+//!     stream::iter(batches)
+//!         .records::<Row>()
+//!         .map(Result::unwrap)
+//!         .collect(),
+//! );
+//! assert_eq!(back[9].name, "r9");
+//! ```
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use arrow_array::RecordBatch;
+use futures_core::{FusedStream, Stream};
+use futures_timer::Delay;
+
+use crate::{
+    batch_writer::BatchWriter,
+    error::{StreamError, ViewAccessError},
+    schema::{BuildRows, FromRecordBatch, ReusableRowBuilder},
+};
+
+/// Stream of `RecordBatch`es built from a stream of rows; see [`RowStreamExt::record_batches`].
+pub struct RowBatches<S, R: BuildRows> {
+    rows: Option<S>,
+    writer: BatchWriter<R>,
+    max_delay: Option<Duration>,
+    timer: Option<Delay>,
+}
+
+// Neither the builders nor the timer are ever pinned in place.
+impl<S: Unpin, R: BuildRows> Unpin for RowBatches<S, R> {}
+
+impl<S, R> RowBatches<S, R>
+where
+    S: Stream<Item = R> + Unpin,
+    R: BuildRows,
+    R::Builders: ReusableRowBuilder<R>,
+{
+    /// Batch `rows` with the caps configured on `writer`.
+    #[must_use]
+    pub fn new(rows: S, writer: BatchWriter<R>) -> Self {
+        Self {
+            rows: Some(rows),
+            writer,
+            max_delay: None,
+            timer: None,
+        }
+    }
+
+    /// Also emit the pending rows once `max_delay` has passed since the first of them
+    /// arrived, so a slow source still produces batches at a steady pace.
+    #[must_use]
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// The writer that accumulates the pending rows.
+    #[must_use]
+    pub fn writer(&self) -> &BatchWriter<R> {
+        &self.writer
+    }
+
+    fn flush(&mut self) -> Option<RecordBatch> {
+        self.timer = None;
+        self.writer.flush()
+    }
+}
+
+impl<S, R> Stream for RowBatches<S, R>
+where
+    S: Stream<Item = R> + Unpin,
+    R: BuildRows,
+    R::Builders: ReusableRowBuilder<R>,
+{
+    type Item = RecordBatch;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<RecordBatch>> {
+        let this = self.get_mut();
+        loop {
+            let Some(rows) = this.rows.as_mut() else {
+                return Poll::Ready(this.flush());
+            };
+            match Pin::new(rows).poll_next(cx) {
+                Poll::Ready(Some(row)) => {
+                    if let Some(batch) = this.writer.write(row) {
+                        this.timer = None;
+                        return Poll::Ready(Some(batch));
+                    }
+                    if this.timer.is_none()
+                        && let Some(delay) = this.max_delay
+                    {
+                        this.timer = Some(Delay::new(delay));
+                    }
+                }
+                Poll::Ready(None) => {
+                    this.rows = None;
+                    return Poll::Ready(this.flush());
+                }
+                Poll::Pending => {
+                    if let Some(timer) = this.timer.as_mut()
+                        && Pin::new(timer).poll(cx).is_ready()
+                    {
+                        return Poll::Ready(this.flush());
+                    }
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<S, R> FusedStream for RowBatches<S, R>
+where
+    S: Stream<Item = R> + Unpin,
+    R: BuildRows,
+    R::Builders: ReusableRowBuilder<R>,
+{
+    fn is_terminated(&self) -> bool {
+        self.rows.is_none() && self.writer.pending_rows() == 0
+    }
+}
+
+/// Stream of owned records read from a stream of batches; see [`RecordBatchStreamExt::records`].
+pub struct BatchRows<S, R> {
+    batches: Option<S>,
+    buffered: VecDeque<Result<R, ViewAccessError>>,
+}
+
+impl<S: Unpin, R> Unpin for BatchRows<S, R> {}
+
+impl<S, R> BatchRows<S, R>
+where
+    S: Stream<Item = RecordBatch> + Unpin,
+    R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+{
+    /// Read every batch of `batches` as rows of `R`.
+    #[must_use]
+    pub fn new(batches: S) -> Self {
+        Self {
+            batches: Some(batches),
+            buffered: VecDeque::new(),
+        }
+    }
+
+    /// Convert every row of `batch`; views borrow the batch, so the rows of one batch
+    /// are converted together before any is yielded.
+    fn read(&mut self, batch: &RecordBatch) -> Result<(), StreamError> {
+        let views = R::from_record_batch(batch)?;
+        self.buffered.reserve(batch.num_rows());
+        self.buffered
+            .extend(views.map(|view| view.and_then(R::try_from)));
+        Ok(())
+    }
+}
+
+impl<S, R> Stream for BatchRows<S, R>
+where
+    S: Stream<Item = RecordBatch> + Unpin,
+    R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+{
+    type Item = Result<R, StreamError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(row) = this.buffered.pop_front() {
+                return Poll::Ready(Some(row.map_err(StreamError::from)));
+            }
+            let Some(batches) = this.batches.as_mut() else {
+                return Poll::Ready(None);
+            };
+            match Pin::new(batches).poll_next(cx) {
+                Poll::Ready(Some(batch)) => {
+                    if let Err(e) = this.read(&batch) {
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    this.batches = None;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S, R> FusedStream for BatchRows<S, R>
+where
+    S: Stream<Item = RecordBatch> + Unpin,
+    R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+{
+    fn is_terminated(&self) -> bool {
+        self.batches.is_none() && self.buffered.is_empty()
+    }
+}
+
+/// Extension trait turning a stream of rows into a stream of `RecordBatch`es.
+pub trait RowStreamExt: Stream + Sized {
+    /// Batch the rows with the caps configured on `writer`; the last partial batch
+    /// is emitted when the input ends.
+    fn record_batches<R>(self, writer: BatchWriter<R>) -> RowBatches<Self, R>
+    where
+        Self: Stream<Item = R> + Unpin,
+        R: BuildRows,
+        R::Builders: ReusableRowBuilder<R>,
+    {
+        RowBatches::new(self, writer)
+    }
+}
+
+impl<S: Stream> RowStreamExt for S {}
+
+/// Extension trait turning a stream of `RecordBatch`es into a stream of owned records.
+pub trait RecordBatchStreamExt: Stream<Item = RecordBatch> + Sized {
+    /// Yield every row of every batch as an owned `R`.
+    ///
+    /// A batch whose schema does not match `R` yields one [`StreamError::Schema`] and
+    /// is skipped; a row that cannot be read yields [`StreamError::View`].
+    fn records<R>(self) -> BatchRows<Self, R>
+    where
+        Self: Unpin,
+        R: FromRecordBatch + for<'a> TryFrom<R::View<'a>, Error = ViewAccessError>,
+    {
+        BatchRows::new(self)
+    }
+}
+
+impl<S: Stream<Item = RecordBatch>> RecordBatchStreamExt for S {}
@@ -0,0 +1,391 @@
+//! Typed access across several batches: [`TypedTable<R>`] and multi-batch views.
+//!
+//! Readers for IPC or Parquet produce a sequence of batches rather than one. This
+//! module checks each batch against a Record once and then offers:
+//!
+//! - [`typed_batches`]: adapt any `Iterator<Item = RecordBatch>` into validated
+//!   [`IndexedBatch<R>`] items, whose views are tagged with their [`RowLocation`];
+//! - [`iter_batches_views`]: views over a `&[RecordBatch]`, each tagged with its [`RowLocation`];
+//! - [`TypedTable<R>`]: an owned `Vec<RecordBatch>` with global row indexing.
+//!
+//! ```
+//! use typed_arrow::{prelude::*, table::TypedTable};
+//!
+//! #[derive(Record)]
+//! struct Row {
+//!     id: i64,
+//! }
+//!
+//! let batch = |ids: std::ops::Range<i64>| {
+//!     let mut b = <Row as BuildRows>::new_builders(0);
+//!     b.append_rows(ids.map(|id| Row { id }));
+//!     b.finish().into_record_batch()
+//! };
+//! let table = TypedTable::<Row>::try_new(vec![batch(0..3), batch(3..5)])?;
+//!
+//! assert_eq!(table.len(), 5);
+//! assert_eq!(table.row(4)?.id, 4);
+//! let ids: Vec<i64> = table
+//!     .iter_views()
+//!     .map(|r| r.map(|(_, v)| v.id))
+//!     .collect::<Result<_, _>>()?;
+//! assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::marker::PhantomData;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+
+use crate::{
+    error::SchemaError,
+    schema::{Record, SchemaMeta},
+    typed_batch::TypedBatch,
+};
+#[cfg(feature = "views")]
+use crate::{
+    schema::{FromRecordBatch, ViewAccessError},
+    validate::validate_batch,
+};
+
+/// Position of a row within a sequence of batches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RowLocation {
+    /// Index of the batch.
+    pub batch: usize,
+    /// Row index within that batch.
+    pub row: usize,
+}
+
+/// A validated batch together with its position in a sequence of batches.
+pub struct IndexedBatch<R> {
+    /// Index of the batch within the sequence, counting batches that failed validation.
+    pub index: usize,
+    /// The batch.
+    pub batch: TypedBatch<R>,
+}
+
+#[cfg(feature = "views")]
+impl<R: FromRecordBatch> IndexedBatch<R> {
+    /// Iterate over row views, each with its [`RowLocation`].
+    #[must_use]
+    pub fn iter_views(&self) -> BatchesViews<'_, R> {
+        BatchesViews::new(vec![(self.index, self.batch.iter_views())])
+    }
+}
+
+/// Adapt an iterator of batches into validated [`IndexedBatch<R>`] items.
+///
+/// Each batch is checked with [`TypedBatch::try_new`] as it is pulled. A reader's
+/// batches cannot be borrowed past the next pull, so views are taken per batch:
+///
+/// ```
+/// use typed_arrow::{prelude::*, table::typed_batches};
+///
+/// #[derive(Record)]
+/// struct Row {
+///     id: i64,
+/// }
+///
+/// let batch = |ids: std::ops::Range<i64>| {
+///     let mut b = <Row as BuildRows>::new_builders(0);
+///     b.append_rows(ids.map(|id| Row { id }));
+///     b.finish().into_record_batch()
+/// };
+/// let mut last = None;
+/// for batch in typed_batches::<Row, _>(vec![batch(0..3), batch(3..5)]) {
+///     for view in batch?.iter_views() {
+///         let (loc, row) = view?;
+///         last = Some((loc.batch, loc.row, row.id));
+///     }
+/// }
+/// assert_eq!(last, Some((1, 1, 4)));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn typed_batches<R, I>(batches: I) -> TypedBatches<R, I::IntoIter>
+where
+    R: SchemaMeta,
+    I: IntoIterator<Item = RecordBatch>,
+{
+    TypedBatches {
+        inner: batches.into_iter().enumerate(),
+        _record: PhantomData,
+    }
+}
+
+/// Iterator returned by [`typed_batches`].
+pub struct TypedBatches<R, I> {
+    inner: std::iter::Enumerate<I>,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R, I> Iterator for TypedBatches<R, I>
+where
+    R: SchemaMeta,
+    I: Iterator<Item = RecordBatch>,
+{
+    type Item = Result<IndexedBatch<R>, SchemaError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, batch) = self.inner.next()?;
+        Some(TypedBatch::try_new(batch).map(|batch| IndexedBatch { index, batch }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Create views over the rows of several batches, in order.
+///
+/// Every batch is checked up front with [`validate_batch`], as by
+/// [`TypedTable::try_new`].
+///
+/// # Errors
+/// Returns the first batch's `SchemaError` if any batch does not match `R`.
+#[cfg(feature = "views")]
+pub fn iter_batches_views<R: SchemaMeta + FromRecordBatch>(
+    batches: &[RecordBatch],
+) -> Result<BatchesViews<'_, R>, SchemaError> {
+    for batch in batches {
+        validate_batch::<R>(batch)?;
+    }
+    Ok(views_of(batches))
+}
+
+/// Views over batches that are known to conform to `R`.
+#[cfg(feature = "views")]
+fn views_of<R: FromRecordBatch>(batches: &[RecordBatch]) -> BatchesViews<'_, R> {
+    BatchesViews::new(
+        batches
+            .iter()
+            .map(|b| R::from_record_batch(b).expect("batches conform to R"))
+            .enumerate()
+            .collect(),
+    )
+}
+
+/// Iterator over views of several batches, yielding each view with its [`RowLocation`].
+#[cfg(feature = "views")]
+pub struct BatchesViews<'a, R: FromRecordBatch> {
+    views: std::vec::IntoIter<(usize, R::Views<'a>)>,
+    current: Option<(usize, usize, R::Views<'a>)>,
+}
+
+#[cfg(feature = "views")]
+impl<'a, R: FromRecordBatch> BatchesViews<'a, R> {
+    fn new(views: Vec<(usize, R::Views<'a>)>) -> Self {
+        Self {
+            views: views.into_iter(),
+            current: None,
+        }
+    }
+}
+
+#[cfg(feature = "views")]
+impl<'a, R: FromRecordBatch> Iterator for BatchesViews<'a, R> {
+    type Item = Result<(RowLocation, R::View<'a>), ViewAccessError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((batch, row, views)) = &mut self.current
+                && let Some(item) = views.next()
+            {
+                let loc = RowLocation {
+                    batch: *batch,
+                    row: *row,
+                };
+                *row += 1;
+                return Some(item.map(|v| (loc, v)));
+            }
+            let (batch, views) = self.views.next()?;
+            self.current = Some((batch, 0, views));
+        }
+    }
+}
+
+/// A sequence of batches, each validated against `R`, with global row indexing.
+pub struct TypedTable<R> {
+    batches: Vec<RecordBatch>,
+    // offsets[i] is the global index of the first row of batch i; the last entry is len().
+    offsets: Vec<usize>,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: Record> TypedTable<R> {
+    /// Create an empty table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            batches: Vec::new(),
+            offsets: vec![0],
+            _record: PhantomData,
+        }
+    }
+
+    /// Append a batch that is already known to conform to `R`.
+    pub fn push(&mut self, batch: TypedBatch<R>) {
+        let end = self.len() + batch.len();
+        self.batches.push(batch.into_inner());
+        self.offsets.push(end);
+    }
+
+    /// Total number of rows across all batches.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        *self.offsets.last().expect("offsets start with 0")
+    }
+
+    /// Whether the table has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of batches.
+    #[must_use]
+    pub fn num_batches(&self) -> usize {
+        self.batches.len()
+    }
+
+    /// The batches, in order.
+    #[must_use]
+    pub fn batches(&self) -> &[RecordBatch] {
+        &self.batches
+    }
+
+    /// The batch at `index` as a [`TypedBatch`].
+    #[must_use]
+    pub fn batch(&self, index: usize) -> Option<TypedBatch<R>> {
+        self.batches
+            .get(index)
+            .map(|b| TypedBatch::new_unchecked(b.clone()))
+    }
+
+    /// Schema of the first batch, if any.
+    #[must_use]
+    pub fn schema(&self) -> Option<SchemaRef> {
+        self.batches.first().map(RecordBatch::schema)
+    }
+
+    /// Map a global row index to its batch and row.
+    #[must_use]
+    pub fn locate(&self, index: usize) -> Option<RowLocation> {
+        if index >= self.len() {
+            return None;
+        }
+        // Last offset <= index; empty batches share an offset with their successor.
+        let batch = self.offsets.partition_point(|&o| o <= index) - 1;
+        Some(RowLocation {
+            batch,
+            row: index - self.offsets[batch],
+        })
+    }
+
+    /// Unwrap into the batches.
+    #[must_use]
+    pub fn into_batches(self) -> Vec<RecordBatch> {
+        self.batches
+    }
+}
+
+impl<R: SchemaMeta> TypedTable<R> {
+    /// Validate every batch against `R` and collect them into a table.
+    ///
+    /// # Errors
+    /// Returns the first batch's validation error.
+    pub fn try_new(batches: impl IntoIterator<Item = RecordBatch>) -> Result<Self, SchemaError> {
+        typed_batches::<R, _>(batches)
+            .map(|b| b.map(|b| b.batch))
+            .collect()
+    }
+}
+
+#[cfg(feature = "views")]
+impl<R: Record + FromRecordBatch> TypedTable<R> {
+    /// Iterate over views of all rows, each with its [`RowLocation`].
+    #[must_use]
+    pub fn iter_views(&self) -> BatchesViews<'_, R> {
+        views_of(&self.batches)
+    }
+
+    /// View of the row at global `index`.
+    ///
+    /// # Errors
+    /// Returns `ViewAccessError::OutOfBounds` if `index >= len()`, or the error raised
+    /// while reading the row.
+    pub fn row(&self, index: usize) -> Result<R::View<'_>, ViewAccessError> {
+        let Some(loc) = self.locate(index) else {
+            return Err(ViewAccessError::OutOfBounds {
+                index,
+                len: self.len(),
+                field_name: None,
+            });
+        };
+        R::from_record_batch(&self.batches[loc.batch])
+            .expect("TypedTable batches conform to R")
+            .nth(loc.row)
+            .expect("row located within batch")
+    }
+}
+
+impl<R: Record> Default for TypedTable<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Record> FromIterator<TypedBatch<R>> for TypedTable<R> {
+    fn from_iter<T: IntoIterator<Item = TypedBatch<R>>>(iter: T) -> Self {
+        let mut table = Self::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<R: Record> Extend<TypedBatch<R>> for TypedTable<R> {
+    fn extend<T: IntoIterator<Item = TypedBatch<R>>>(&mut self, iter: T) {
+        for batch in iter {
+            self.push(batch);
+        }
+    }
+}
+
+impl<R> Clone for TypedTable<R> {
+    fn clone(&self) -> Self {
+        Self {
+            batches: self.batches.clone(),
+            offsets: self.offsets.clone(),
+            _record: PhantomData,
+        }
+    }
+}
+
+impl<R> Clone for IndexedBatch<R> {
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index,
+            batch: self.batch.clone(),
+        }
+    }
+}
+
+impl<R> std::fmt::Debug for IndexedBatch<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexedBatch")
+            .field("index", &self.index)
+            .field("batch", &self.batch)
+            .finish()
+    }
+}
+
+impl<R> std::fmt::Debug for TypedTable<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedTable")
+            .field("record", &std::any::type_name::<R>())
+            .field("batches", &self.batches.len())
+            .field("rows", &self.offsets.last())
+            .finish()
+    }
+}
@@ -0,0 +1,208 @@
+//! [`TypedBatch<R>`]: a `RecordBatch` known to conform to a Record.
+//!
+//! A plain [`RecordBatch`] carries no proof of its shape, so every
+//! `iter_views::<R>()` or `columns_of::<R>()` call has to check the schema again.
+//! `TypedBatch<R>` is only constructed from the generated builders (which produce
+//! conforming batches by construction) or through
+//! [`validate_batch`](crate::validate::validate_batch), after which typed accessors
+//! only downcast the columns and do not return schema errors.
+//!
+//! ```
+//! use typed_arrow::{TypedBatch, prelude::*};
+//!
+//! #[derive(Record)]
+//! struct Row {
+//!     id: i64,
+//!     name: Option<String>,
+//! }
+//!
+//! let mut b = <Row as BuildRows>::new_builders(2);
+//! b.append_row(Row { id: 1, name: None });
+//! b.append_row(Row {
+//!     id: 2,
+//!     name: Some("b".into()),
+//! });
+//! let typed: TypedBatch<Row> = b.finish().into_typed_batch();
+//!
+//! assert_eq!(typed.len(), 2);
+//! assert_eq!(typed.columns().id.values(), &[1, 2]);
+//!
+//! // Round-trip through an untyped batch, validating once.
+//! let batch = typed.into_inner();
+//! let typed = TypedBatch::<Row>::try_from(batch)?;
+//! assert_eq!(typed.row(1)?.name, Some("b"));
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{borrow::Cow, fmt, marker::PhantomData};
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+
+#[cfg(feature = "views")]
+use crate::schema::{FromRecordBatch, ViewAccessError};
+use crate::{
+    error::SchemaError,
+    schema::{FromRecordBatchColumns, Record, SchemaMeta},
+    validate::validated_columns,
+};
+
+/// A [`RecordBatch`] whose schema has been checked against `R`.
+pub struct TypedBatch<R> {
+    batch: RecordBatch,
+    _record: PhantomData<fn() -> R>,
+}
+
+impl<R: Record> TypedBatch<R> {
+    /// Wrap `batch` without checking it.
+    ///
+    /// Used by the generated `{Name}Arrays::into_typed_batch`. A batch that does not
+    /// match `R` makes the typed accessors panic; use [`TypedBatch::try_new`] for
+    /// batches from elsewhere.
+    #[must_use]
+    pub fn new_unchecked(batch: RecordBatch) -> Self {
+        Self {
+            batch,
+            _record: PhantomData,
+        }
+    }
+
+    /// Number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.batch.num_rows()
+    }
+
+    /// Whether the batch has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.batch.num_rows() == 0
+    }
+
+    /// Schema of the underlying batch.
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        self.batch.schema()
+    }
+
+    /// Zero-copy slice of `len` rows starting at `offset`.
+    ///
+    /// # Panics
+    /// Panics if `offset + len` exceeds the number of rows.
+    #[must_use]
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::new_unchecked(self.batch.slice(offset, len))
+    }
+
+    /// Borrow the underlying batch.
+    #[must_use]
+    pub fn as_record_batch(&self) -> &RecordBatch {
+        &self.batch
+    }
+
+    /// Unwrap into the underlying batch.
+    #[must_use]
+    pub fn into_inner(self) -> RecordBatch {
+        self.batch
+    }
+}
+
+impl<R: SchemaMeta> TypedBatch<R> {
+    /// Validate `batch` against `R` (names, types and nested nullability) and wrap it.
+    ///
+    /// A [`Projection`](crate::schema::Projection) accepts a batch of its source
+    /// record, as its views do, and keeps only its own columns.
+    ///
+    /// # Errors
+    /// Returns the first mismatch reported by [`validate_batch`](crate::validate::validate_batch).
+    pub fn try_new(batch: RecordBatch) -> Result<Self, SchemaError> {
+        if let Cow::Owned(selected) = validated_columns::<R>(&batch)? {
+            return Ok(Self::new_unchecked(selected));
+        }
+        Ok(Self::new_unchecked(batch))
+    }
+}
+
+impl<R: FromRecordBatchColumns> TypedBatch<R> {
+    /// Typed references to every column.
+    ///
+    /// Each column is still downcast to its concrete array type, but the full
+    /// validation done by [`TypedBatch::try_new`] is not repeated.
+    ///
+    /// # Panics
+    /// Panics if the batch was wrapped with [`TypedBatch::new_unchecked`] and does not
+    /// match `R`.
+    #[must_use]
+    pub fn columns(&self) -> R::Columns<'_> {
+        R::columns_from_record_batch(&self.batch).expect("TypedBatch schema conforms to R")
+    }
+}
+
+#[cfg(feature = "views")]
+impl<R: FromRecordBatch> TypedBatch<R> {
+    /// Iterate over row views.
+    ///
+    /// Each column is still downcast to its concrete array type, but the full
+    /// validation done by [`TypedBatch::try_new`] is not repeated.
+    ///
+    /// # Panics
+    /// Panics if the batch was wrapped with [`TypedBatch::new_unchecked`] and does not
+    /// match `R`.
+    #[must_use]
+    pub fn iter_views(&self) -> R::Views<'_> {
+        R::from_record_batch(&self.batch).expect("TypedBatch schema conforms to R")
+    }
+
+    /// View of the row at `index`.
+    ///
+    /// # Errors
+    /// Returns `ViewAccessError::OutOfBounds` if `index >= len()`, or the error raised
+    /// while reading the row.
+    pub fn row(&self, index: usize) -> Result<R::View<'_>, ViewAccessError> {
+        self.iter_views()
+            .nth(index)
+            .unwrap_or(Err(ViewAccessError::OutOfBounds {
+                index,
+                len: self.len(),
+                field_name: None,
+            }))
+    }
+}
+
+impl<R: SchemaMeta> TryFrom<RecordBatch> for TypedBatch<R> {
+    type Error = SchemaError;
+
+    fn try_from(batch: RecordBatch) -> Result<Self, Self::Error> {
+        Self::try_new(batch)
+    }
+}
+
+impl<R> From<TypedBatch<R>> for RecordBatch {
+    fn from(typed: TypedBatch<R>) -> Self {
+        typed.batch
+    }
+}
+
+impl<R> AsRef<RecordBatch> for TypedBatch<R> {
+    fn as_ref(&self) -> &RecordBatch {
+        &self.batch
+    }
+}
+
+impl<R> Clone for TypedBatch<R> {
+    fn clone(&self) -> Self {
+        Self {
+            batch: self.batch.clone(),
+            _record: PhantomData,
+        }
+    }
+}
+
+impl<R> fmt::Debug for TypedBatch<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedBatch")
+            .field("record", &std::any::type_name::<R>())
+            .field("batch", &self.batch)
+            .finish()
+    }
+}
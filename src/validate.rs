@@ -0,0 +1,897 @@
+//! Validate a [`RecordBatch`] against the nullability contract of a Record.
+//!
+//! [`FromRecordBatch`](crate::schema::FromRecordBatch) only checks that each column
+//! downcasts to the expected array type. A batch produced elsewhere can still carry
+//! nulls in columns, struct children, list items or map values that the Rust type
+//! declares non-null; those only surface as `UnexpectedNull` while iterating views.
+//! [`validate_batch`] checks the batch up front against `R::schema()`, walking every
+//! nested level and reporting violations with a dotted path (e.g. `address.city`,
+//! `tags[]`, `attrs.values`).
+//!
+//! ```
+//! use typed_arrow::{prelude::*, validate::validate_batch};
+//!
+//! #[derive(Record)]
+//! struct Row {
+//!     id: i64,
+//!     name: Option<String>,
+//! }
+//!
+//! let mut b = <Row as BuildRows>::new_builders(1);
+//! b.append_row(Row { id: 1, name: None });
+//! let batch = b.finish().into_record_batch();
+//!
+//! validate_batch::<Row>(&batch)?;
+//! # Ok::<_, SchemaError>(())
+//! ```
+
+use std::{borrow::Cow, sync::Arc};
+
+use arrow_array::{
+    Array, ArrayRef, FixedSizeListArray, LargeListArray, ListArray, MapArray, RecordBatch,
+    StructArray, UnionArray,
+};
+use arrow_buffer::{ArrowNativeType, OffsetBuffer};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, UnionFields};
+
+use crate::{error::SchemaError, schema::SchemaMeta};
+
+/// Validate that `batch` matches the schema of `R`, including nested nullability.
+///
+/// Checks column count, column names and data types (ignoring nullability flags
+/// declared by the batch's own schema), then verifies that no value is null where
+/// `R` declares it non-null. For a [`Projection`](crate::schema::Projection), only
+/// its columns are checked, found by name, as its `FromRecordBatch` reads them.
+///
+/// # Errors
+/// Returns the first mismatch as a `SchemaError`; nullability violations are
+/// reported as [`SchemaError::Nullability`].
+pub fn validate_batch<R: SchemaMeta>(batch: &RecordBatch) -> Result<(), SchemaError> {
+    validated_columns::<R>(batch).map(drop)
+}
+
+/// [`validate_batch`], returning the columns of `batch` that `R` reads.
+pub(crate) fn validated_columns<R: SchemaMeta>(
+    batch: &RecordBatch,
+) -> Result<Cow<'_, RecordBatch>, SchemaError> {
+    let schema = R::schema();
+    let batch = select_columns::<R>(&schema, batch)?;
+    check_columns(&schema, &batch)?;
+    validate_nullability(&schema, batch.columns(), &|_| None)?;
+    Ok(batch)
+}
+
+/// Validate that `schema` has the columns of `R`: same count, names and data types,
+/// ignoring nullability flags.
+///
+/// Use it to reject a file or stream up front, before reading any data; each batch
+/// still needs [`validate_batch`] to check its values against `R`'s nullability.
+///
+/// # Errors
+/// Returns the first column that does not match.
+pub fn validate_schema<R: SchemaMeta>(schema: &Schema) -> Result<(), SchemaError> {
+    let expected = R::schema();
+    if R::SELECTS_COLUMNS_BY_NAME {
+        let indices = column_indices(&expected, schema)?;
+        let selected = schema
+            .project(&indices)
+            .map_err(|e| SchemaError::invalid(e.to_string()))?;
+        return check_fields(&expected, &selected, "schema");
+    }
+    check_fields(&expected, schema, "schema")
+}
+
+/// Like [`validate_batch`], but collects every violation instead of stopping at the first.
+///
+/// # Errors
+/// Returns all violations found. If the batch shape or a column type does not match,
+/// only that error is returned since nested values cannot be inspected meaningfully.
+pub fn validate_batch_all<R: SchemaMeta>(batch: &RecordBatch) -> Result<(), Vec<SchemaError>> {
+    let schema = R::schema();
+    let batch = select_columns::<R>(&schema, batch).map_err(|e| vec![e])?;
+    check_columns(&schema, &batch).map_err(|e| vec![e])?;
+    let violations =
+        nullability_violations(&schema, batch.columns(), &|_| None).map_err(|e| vec![e])?;
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// Validate that `arrays`, built without a Record (e.g. by `typed-arrow-dyn`),
+/// satisfy the nullability declared by `schema`.
+///
+/// Arrow unions carry no validity bitmap, so builders that encode `None` as a
+/// null-carrier variant track those rows themselves: `union_null_rows` returns them
+/// for a union array, or `None` if it has none.
+///
+/// # Errors
+/// Returns a `SchemaError::Nullability` describing the first violation encountered,
+/// or `SchemaError::InvalidSchema` if an array does not match its declared type.
+pub fn validate_arrays(
+    schema: &Schema,
+    arrays: &[ArrayRef],
+    union_null_rows: UnionNullRows<'_>,
+) -> Result<(), SchemaError> {
+    validate_nullability(schema, arrays, union_null_rows)
+}
+
+/// Lookup of the rows of a union array that represent a logical null.
+pub type UnionNullRows<'a> = &'a dyn Fn(&ArrayRef) -> Option<Vec<usize>>;
+
+fn validate_nullability(
+    schema: &Schema,
+    arrays: &[ArrayRef],
+    union_null_rows: UnionNullRows<'_>,
+) -> Result<(), SchemaError> {
+    let mut out = Violations::first_only();
+    walk_columns(schema, arrays, union_null_rows, &mut out)
+}
+
+fn nullability_violations(
+    schema: &Schema,
+    arrays: &[ArrayRef],
+    union_null_rows: UnionNullRows<'_>,
+) -> Result<Vec<SchemaError>, SchemaError> {
+    let mut out = Violations::all();
+    walk_columns(schema, arrays, union_null_rows, &mut out)?;
+    Ok(out.found)
+}
+
+/// The columns of `batch` that `R` reads: all of them, or for records that select
+/// their columns by name, those named by `schema` in its order.
+fn select_columns<'a, R: SchemaMeta>(
+    schema: &Schema,
+    batch: &'a RecordBatch,
+) -> Result<Cow<'a, RecordBatch>, SchemaError> {
+    if !R::SELECTS_COLUMNS_BY_NAME {
+        return Ok(Cow::Borrowed(batch));
+    }
+    let indices = column_indices(schema, &batch.schema())?;
+    batch
+        .project(&indices)
+        .map(Cow::Owned)
+        .map_err(|e| SchemaError::invalid(e.to_string()))
+}
+
+fn column_indices(expected: &Schema, actual: &Schema) -> Result<Vec<usize>, SchemaError> {
+    expected
+        .fields()
+        .iter()
+        .map(|f| {
+            actual
+                .index_of(f.name())
+                .map_err(|_| SchemaError::missing_field(f.name().as_str()))
+        })
+        .collect()
+}
+
+fn check_columns(schema: &Schema, batch: &RecordBatch) -> Result<(), SchemaError> {
+    check_fields(schema, &batch.schema(), "RecordBatch")
+}
+
+fn check_fields(expected: &Schema, actual: &Schema, what: &str) -> Result<(), SchemaError> {
+    if actual.fields().len() != expected.fields().len() {
+        return Err(SchemaError::invalid(format!(
+            "Column count mismatch: expected {} columns, but {what} has {} columns",
+            expected.fields().len(),
+            actual.fields().len()
+        )));
+    }
+    for (expected, actual) in expected.fields().iter().zip(actual.fields().iter()) {
+        if expected.name() != actual.name() {
+            return Err(SchemaError::missing_field(expected.name().as_str()));
+        }
+        if !same_type_ignoring_nullability(expected.data_type(), actual.data_type()) {
+            return Err(SchemaError::type_mismatch(
+                expected.data_type().clone(),
+                actual.data_type().clone(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Structural type equality that ignores nullability and metadata of nested fields,
+/// so a producer that marks everything nullable still matches; the data itself is
+/// checked afterwards.
+fn same_type_ignoring_nullability(expected: &DataType, actual: &DataType) -> bool {
+    let same_field =
+        |a: &Field, b: &Field| same_type_ignoring_nullability(a.data_type(), b.data_type());
+    let same_named_field = |a: &Field, b: &Field| a.name() == b.name() && same_field(a, b);
+    match (expected, actual) {
+        (DataType::List(a), DataType::List(b))
+        | (DataType::LargeList(a), DataType::LargeList(b)) => same_field(a, b),
+        (DataType::FixedSizeList(a, n), DataType::FixedSizeList(b, m)) => {
+            n == m && same_field(a, b)
+        }
+        (DataType::Struct(a), DataType::Struct(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| same_named_field(a, b))
+        }
+        (DataType::Map(a, a_sorted), DataType::Map(b, b_sorted)) => {
+            a_sorted == b_sorted && same_field(a, b)
+        }
+        (DataType::Dictionary(ak, av), DataType::Dictionary(bk, bv)) => {
+            ak == bk && same_type_ignoring_nullability(av, bv)
+        }
+        (DataType::Union(a, a_mode), DataType::Union(b, b_mode)) => {
+            a_mode == b_mode
+                && a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|((at, af), (bt, bf))| at == bt && same_named_field(af, bf))
+        }
+        _ => expected == actual,
+    }
+}
+
+/// Sink for violations: either stop at the first one or keep collecting.
+struct Violations {
+    found: Vec<SchemaError>,
+    first_only: bool,
+}
+
+impl Violations {
+    fn first_only() -> Self {
+        Self {
+            found: Vec::new(),
+            first_only: true,
+        }
+    }
+
+    fn all() -> Self {
+        Self {
+            found: Vec::new(),
+            first_only: false,
+        }
+    }
+
+    fn report(
+        &mut self,
+        col: usize,
+        path: String,
+        index: usize,
+        message: &str,
+    ) -> Result<(), SchemaError> {
+        let err = SchemaError::Nullability {
+            col,
+            path,
+            index,
+            message: message.to_string(),
+        };
+        if self.first_only {
+            return Err(err);
+        }
+        self.found.push(err);
+        Ok(())
+    }
+}
+
+/// Extract start and end offsets for a row from an offset buffer.
+fn offset_range<T: ArrowNativeType>(
+    offsets: &OffsetBuffer<T>,
+    row: usize,
+    col_name: &str,
+) -> Result<(usize, usize), SchemaError>
+where
+    usize: TryFrom<T>,
+{
+    let start_raw = offsets.get(row).ok_or_else(|| {
+        SchemaError::invalid(format!("offset index {row} out of range for {col_name}"))
+    })?;
+    let end_raw = offsets.get(row + 1).ok_or_else(|| {
+        SchemaError::invalid(format!(
+            "offset index {} out of range for {col_name}",
+            row + 1
+        ))
+    })?;
+    let start = usize::try_from(*start_raw).map_err(|_| {
+        SchemaError::invalid(format!("negative offset at index {row} for {col_name}"))
+    })?;
+    let end = usize::try_from(*end_raw).map_err(|_| {
+        SchemaError::invalid(format!(
+            "negative offset at index {} for {col_name}",
+            row + 1
+        ))
+    })?;
+    Ok((start, end))
+}
+
+fn walk_columns(
+    schema: &Schema,
+    arrays: &[ArrayRef],
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    for (col, (field, array)) in schema.fields().iter().zip(arrays.iter()).enumerate() {
+        // Top-level field nullability
+        if !field.is_nullable() && array.null_count() > 0 {
+            for idx in null_indices(array.as_ref()) {
+                out.report(
+                    col,
+                    field.name().to_string(),
+                    idx,
+                    "non-nullable field contains null",
+                )?;
+            }
+        }
+
+        // Nested
+        validate_nested(
+            field.name(),
+            field.data_type(),
+            array,
+            col,
+            None,
+            field.is_nullable(),
+            union_null_rows,
+            out,
+        )?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn validate_nested(
+    col_name: &str,
+    dt: &DataType,
+    array: &ArrayRef,
+    col: usize,
+    // An optional mask: when present, only indices with `true` are considered.
+    parent_valid_mask: Option<Vec<bool>>,
+    nullable: bool,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    match dt {
+        DataType::Struct(children) => validate_struct(
+            col_name,
+            children,
+            array,
+            col,
+            parent_valid_mask,
+            union_null_rows,
+            out,
+        ),
+        DataType::List(item) => validate_list(
+            col_name,
+            item,
+            array,
+            col,
+            parent_valid_mask,
+            union_null_rows,
+            out,
+        ),
+        DataType::LargeList(item) => validate_large_list(
+            col_name,
+            item,
+            array,
+            col,
+            parent_valid_mask,
+            union_null_rows,
+            out,
+        ),
+        DataType::FixedSizeList(item, _len) => validate_fixed_list(
+            col_name,
+            item,
+            array,
+            col,
+            parent_valid_mask,
+            union_null_rows,
+            out,
+        ),
+        DataType::Union(children, _) => validate_union(
+            col_name,
+            children,
+            array,
+            col,
+            parent_valid_mask,
+            nullable,
+            union_null_rows,
+            out,
+        ),
+        DataType::Map(entry_field, _) => validate_map(
+            col_name,
+            entry_field,
+            array,
+            col,
+            parent_valid_mask,
+            union_null_rows,
+            out,
+        ),
+        // Other data types have no nested children.
+        _ => Ok(()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn validate_union(
+    col_name: &str,
+    fields: &UnionFields,
+    array: &ArrayRef,
+    col: usize,
+    parent_mask: Option<Vec<bool>>,
+    nullable: bool,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    let union = array
+        .as_any()
+        .downcast_ref::<UnionArray>()
+        .ok_or_else(|| SchemaError::invalid(format!("expected UnionArray for {col_name}")))?;
+
+    let parent_valid = parent_mask.unwrap_or_else(|| validity_mask(union));
+    let null_rows = union_null_rows(array).unwrap_or_default();
+    let null_row_mask = if null_rows.is_empty() {
+        None
+    } else {
+        let mut mask = vec![false; union.len()];
+        for &row in &null_rows {
+            if row >= mask.len() {
+                return Err(SchemaError::invalid(format!(
+                    "union null row index {row} out of bounds"
+                )));
+            }
+            mask[row] = true;
+        }
+        Some(mask)
+    };
+    let is_union_null_row = |row: usize| {
+        null_row_mask
+            .as_ref()
+            .and_then(|mask| mask.get(row))
+            .copied()
+            .unwrap_or(false)
+    };
+
+    if !nullable {
+        for &row in &null_rows {
+            if parent_valid.get(row).copied().unwrap_or(false) {
+                out.report(
+                    col,
+                    col_name.to_string(),
+                    row,
+                    "non-nullable field contains null",
+                )?;
+            }
+        }
+    }
+
+    let variants: Vec<(i8, FieldRef)> = fields
+        .iter()
+        .map(|(tag, field)| (tag, field.clone()))
+        .collect();
+
+    let mut tag_to_index = vec![None; 256];
+    for (idx, (tag, _)) in variants.iter().enumerate() {
+        tag_to_index[tag_slot(*tag)] = Some(idx);
+    }
+
+    let mut rows_per_variant: Vec<Vec<(usize, usize)>> =
+        variants.iter().map(|_| Vec::new()).collect();
+
+    for (row, &is_valid) in parent_valid.iter().enumerate() {
+        if !is_valid {
+            continue;
+        }
+        let tag = union.type_id(row);
+        let Some(idx) = tag_to_index[tag_slot(tag)] else {
+            return Err(SchemaError::invalid(format!(
+                "union value uses unknown type id {tag}"
+            )));
+        };
+        let offset = union.value_offset(row);
+        rows_per_variant[idx].push((row, offset));
+    }
+
+    for (idx, rows) in rows_per_variant.iter().enumerate() {
+        if rows.is_empty() {
+            continue;
+        }
+        let (tag, field) = &variants[idx];
+        let child = union.child(*tag).clone();
+        let path = format!("{}.{}", col_name, field.name());
+        let child_len = child.len();
+        let mut child_mask = vec![false; child_len];
+
+        for &(row_index, child_index) in rows {
+            if child_index >= child_len {
+                return Err(SchemaError::invalid(format!(
+                    "union child index {} out of bounds for variant '{}'",
+                    child_index,
+                    field.name()
+                )));
+            }
+
+            let union_row_is_null = is_union_null_row(row_index);
+
+            if !field.is_nullable() && !union_row_is_null && child.is_null(child_index) {
+                out.report(
+                    col,
+                    path.clone(),
+                    row_index,
+                    "non-nullable union variant contains null",
+                )?;
+            }
+
+            if !union_row_is_null {
+                child_mask[child_index] = true;
+            }
+        }
+
+        validate_nested(
+            &path,
+            field.data_type(),
+            &child,
+            col,
+            Some(child_mask),
+            field.is_nullable(),
+            union_null_rows,
+            out,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn validate_struct(
+    col_name: &str,
+    fields: &Fields,
+    array: &ArrayRef,
+    col: usize,
+    parent_mask: Option<Vec<bool>>,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    let s = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| SchemaError::invalid(format!("expected StructArray for {col_name}")))?;
+
+    // Compute mask of valid parent rows: respect parent validity if provided, else
+    // derive from the struct's own validity.
+    let arr: &dyn Array = s;
+    let mask = parent_mask.unwrap_or_else(|| validity_mask(arr));
+
+    for (child_field, child_array) in fields.iter().zip(s.columns().iter()) {
+        // Enforce child field nullability only where parent struct is valid.
+        if !child_field.is_nullable() {
+            let child = child_array.as_ref();
+            for (i, &pvalid) in mask.iter().enumerate() {
+                if pvalid && child.is_null(i) {
+                    out.report(
+                        col,
+                        format!("{}.{}", col_name, child_field.name()),
+                        i,
+                        "non-nullable struct field contains null",
+                    )?;
+                }
+            }
+        }
+
+        // Recurse into nested children. For struct children, combine the current mask
+        // with the child's validity to handle nested nullable structs correctly.
+        // e.g., if parent.child is None, child's fields should not be validated.
+        let child_mask = if matches!(child_field.data_type(), DataType::Struct(_)) {
+            let child_arr: &dyn Array = child_array.as_ref();
+            let child_valid = validity_mask(child_arr);
+            // Combine: row is valid only if both parent and child struct are valid
+            Some(
+                mask.iter()
+                    .zip(child_valid.iter())
+                    .map(|(&p, &c)| p && c)
+                    .collect(),
+            )
+        } else {
+            Some(mask.clone())
+        };
+        validate_nested(
+            &format!("{}.{}", col_name, child_field.name()),
+            child_field.data_type(),
+            child_array,
+            col,
+            child_mask,
+            child_field.is_nullable(),
+            union_null_rows,
+            out,
+        )?;
+    }
+    Ok(())
+}
+
+fn validate_list(
+    col_name: &str,
+    item: &Arc<Field>,
+    array: &ArrayRef,
+    col: usize,
+    parent_mask: Option<Vec<bool>>,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    let l = array
+        .as_any()
+        .downcast_ref::<ListArray>()
+        .ok_or_else(|| SchemaError::invalid(format!("expected ListArray for {col_name}")))?;
+
+    let arr: &dyn Array = l;
+    let parent_valid = parent_mask.unwrap_or_else(|| validity_mask(arr));
+    let offsets: &OffsetBuffer<i32> = l.offsets();
+    let child = l.values().clone();
+
+    if !item.is_nullable() {
+        for (row, &pvalid) in parent_valid.iter().enumerate() {
+            if !pvalid {
+                continue;
+            }
+            let (start, end) = offset_range(offsets, row, col_name)?;
+            for idx in start..end {
+                if child.is_null(idx) {
+                    out.report(
+                        col,
+                        format!("{col_name}[]"),
+                        idx,
+                        "non-nullable list item contains null",
+                    )?;
+                }
+            }
+        }
+    }
+
+    // Recurse into child type. Construct mask of child indices belonging to
+    // valid parent rows.
+    let mut child_mask = vec![false; child.len()];
+    for (row, &pvalid) in parent_valid.iter().enumerate() {
+        if !pvalid {
+            continue;
+        }
+        let (start, end) = offset_range(offsets, row, col_name)?;
+        for item in child_mask.iter_mut().take(end).skip(start) {
+            *item = true;
+        }
+    }
+
+    validate_nested(
+        &format!("{col_name}[]"),
+        item.data_type(),
+        &child,
+        col,
+        Some(child_mask),
+        item.is_nullable(),
+        union_null_rows,
+        out,
+    )
+}
+
+fn validate_large_list(
+    col_name: &str,
+    item: &Arc<Field>,
+    array: &ArrayRef,
+    col: usize,
+    parent_mask: Option<Vec<bool>>,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    let l = array
+        .as_any()
+        .downcast_ref::<LargeListArray>()
+        .ok_or_else(|| SchemaError::invalid(format!("expected LargeListArray for {col_name}")))?;
+    let arr: &dyn Array = l;
+    let parent_valid = parent_mask.unwrap_or_else(|| validity_mask(arr));
+    let offsets = l.offsets();
+    let child = l.values().clone();
+
+    if !item.is_nullable() {
+        for (row, &pvalid) in parent_valid.iter().enumerate() {
+            if !pvalid {
+                continue;
+            }
+            let (start, end) = offset_range(offsets, row, col_name)?;
+            for idx in start..end {
+                if child.is_null(idx) {
+                    out.report(
+                        col,
+                        format!("{col_name}[]"),
+                        idx,
+                        "non-nullable large-list item contains null",
+                    )?;
+                }
+            }
+        }
+    }
+
+    let mut child_mask = vec![false; child.len()];
+    for (row, &pvalid) in parent_valid.iter().enumerate() {
+        if !pvalid {
+            continue;
+        }
+        let (start, end) = offset_range(offsets, row, col_name)?;
+        for item in child_mask.iter_mut().take(end).skip(start) {
+            *item = true;
+        }
+    }
+
+    validate_nested(
+        &format!("{col_name}[]"),
+        item.data_type(),
+        &child,
+        col,
+        Some(child_mask),
+        item.is_nullable(),
+        union_null_rows,
+        out,
+    )
+}
+
+fn validate_fixed_list(
+    col_name: &str,
+    item: &Arc<Field>,
+    array: &ArrayRef,
+    col: usize,
+    parent_mask: Option<Vec<bool>>,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    let l = array
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .ok_or_else(|| {
+            SchemaError::invalid(format!("expected FixedSizeListArray for {col_name}"))
+        })?;
+    let arr: &dyn Array = l;
+    let parent_valid = parent_mask.unwrap_or_else(|| validity_mask(arr));
+    let child = l.values().clone();
+    let width = usize::try_from(l.value_length()).map_err(|_| {
+        SchemaError::invalid(format!("negative fixed-size list width for {col_name}"))
+    })?;
+
+    if !item.is_nullable() {
+        for (row, &pvalid) in parent_valid.iter().enumerate() {
+            if !pvalid {
+                continue;
+            }
+            let start = row * width;
+            let end = start + width;
+            for idx in start..end {
+                if child.is_null(idx) {
+                    out.report(
+                        col,
+                        format!("{col_name}[]"),
+                        idx,
+                        "non-nullable fixed-size list item contains null",
+                    )?;
+                }
+            }
+        }
+    }
+
+    let mut child_mask = vec![false; child.len()];
+    for (row, &pvalid) in parent_valid.iter().enumerate() {
+        if !pvalid {
+            continue;
+        }
+        let start = row * width;
+        let end = start + width;
+        for item in child_mask.iter_mut().take(end).skip(start) {
+            *item = true;
+        }
+    }
+
+    validate_nested(
+        &format!("{col_name}[]"),
+        item.data_type(),
+        &child,
+        col,
+        Some(child_mask),
+        item.is_nullable(),
+        union_null_rows,
+        out,
+    )
+}
+
+fn validate_map(
+    col_name: &str,
+    entry_field: &Arc<Field>,
+    array: &ArrayRef,
+    col: usize,
+    parent_mask: Option<Vec<bool>>,
+    union_null_rows: UnionNullRows<'_>,
+    out: &mut Violations,
+) -> Result<(), SchemaError> {
+    let map = array
+        .as_any()
+        .downcast_ref::<MapArray>()
+        .ok_or_else(|| SchemaError::invalid(format!("expected MapArray for {col_name}")))?;
+
+    let arr: &dyn Array = map;
+    let parent_valid = parent_mask.unwrap_or_else(|| validity_mask(arr));
+    let offsets = map.offsets();
+    let keys = map.keys().clone();
+    let values = map.values().clone();
+
+    let DataType::Struct(children) = entry_field.data_type() else {
+        return Err(SchemaError::invalid("map entry field is not a struct"));
+    };
+    if children.len() != 2 {
+        return Err(SchemaError::invalid(format!(
+            "map entry struct must have 2 fields, found {}",
+            children.len()
+        )));
+    }
+    let key_field = &children[0];
+    let value_field = &children[1];
+
+    for (row, &pvalid) in parent_valid.iter().enumerate() {
+        if !pvalid {
+            continue;
+        }
+        let (start, end) = offset_range(offsets, row, col_name)?;
+        for idx in start..end {
+            if keys.as_ref().is_null(idx) {
+                out.report(
+                    col,
+                    format!("{col_name}.keys"),
+                    idx,
+                    "map keys cannot contain nulls",
+                )?;
+            }
+            if !value_field.is_nullable() && values.as_ref().is_null(idx) {
+                out.report(
+                    col,
+                    format!("{col_name}.values"),
+                    idx,
+                    "map values marked non-nullable contain null",
+                )?;
+            }
+        }
+    }
+
+    let mut key_mask = vec![false; keys.len()];
+    let mut value_mask = vec![false; values.len()];
+    for (row, &pvalid) in parent_valid.iter().enumerate() {
+        if !pvalid {
+            continue;
+        }
+        let (start, end) = offset_range(offsets, row, col_name)?;
+        for idx in start..end {
+            key_mask[idx] = true;
+            if values.as_ref().is_valid(idx) {
+                value_mask[idx] = true;
+            }
+        }
+    }
+
+    validate_nested(
+        &format!("{col_name}.keys"),
+        key_field.data_type(),
+        &keys,
+        col,
+        Some(key_mask),
+        key_field.is_nullable(),
+        union_null_rows,
+        out,
+    )?;
+    validate_nested(
+        &format!("{col_name}.values"),
+        value_field.data_type(),
+        &values,
+        col,
+        Some(value_mask),
+        value_field.is_nullable(),
+        union_null_rows,
+        out,
+    )?;
+    Ok(())
+}
+
+fn validity_mask(array: &dyn Array) -> Vec<bool> {
+    (0..array.len()).map(|i| array.is_valid(i)).collect()
+}
+
+fn null_indices(array: &dyn Array) -> impl Iterator<Item = usize> + '_ {
+    (0..array.len()).filter(|&i| array.is_null(i))
+}
+
+fn tag_slot(tag: i8) -> usize {
+    (i16::from(tag) + 128) as usize
+}
@@ -0,0 +1,142 @@
+use typed_arrow::{
+    Decimal128, Dictionary, FixedSizeList, LargeList, List, Map, OrderedMap, prelude::*,
+};
+
+#[derive(Record)]
+pub struct Point {
+    pub x: i32,
+    pub label: Option<String>,
+}
+
+#[derive(Union)]
+#[union(mode = "sparse")]
+pub enum Payload {
+    Num(i64),
+    Text(String),
+}
+
+#[derive(Union)]
+pub enum Tag {
+    Code(u16),
+    Name(String),
+}
+
+#[derive(Record)]
+pub struct Event {
+    pub id: i64,
+    pub name: Option<String>,
+    pub price: Decimal128<7, 2>,
+    pub kind: Dictionary<i8, String>,
+    pub digest: [u8; 4],
+    pub at: Point,
+    pub path: List<Point>,
+    pub scores: Option<List<Option<f64>>>,
+    pub big: LargeList<Vec<u8>>,
+    pub rgb: FixedSizeList<u8, 3>,
+    pub attrs: Map<String, Option<i32>>,
+    pub sorted: OrderedMap<String, bool>,
+    pub payload: Payload,
+    pub tag: Tag,
+}
+
+fn event(i: i64) -> Event {
+    let n = i32::try_from(i).unwrap();
+    Event {
+        id: i,
+        name: (i % 3 != 0).then(|| format!("event-{i}")),
+        price: Decimal128::new(i128::from(i) * 101),
+        kind: Dictionary::new(["a", "b", "c"][(i % 3) as usize].to_string()),
+        digest: [i as u8; 4],
+        at: Point {
+            x: n,
+            label: (i % 2 == 0).then(|| format!("p{i}")),
+        },
+        path: (0..i % 3)
+            .map(|j| Point {
+                x: n * 10 + j as i32,
+                label: None,
+            })
+            .collect(),
+        scores: (i % 4 != 0).then(|| List::new(vec![Some(i as f64), None])),
+        big: LargeList::new(vec![vec![1, 2], vec![]]),
+        rgb: FixedSizeList::new([i as u8, 0, 255]),
+        attrs: Map::new(vec![("k".into(), Some(n)), ("z".into(), None)]),
+        sorted: OrderedMap::new(
+            [("b".to_string(), true), ("a".to_string(), false)]
+                .into_iter()
+                .collect(),
+        ),
+        payload: if i % 2 == 0 {
+            Payload::Num(i)
+        } else {
+            Payload::Text(format!("t{i}"))
+        },
+        tag: if i % 2 == 0 {
+            Tag::Code(i as u16)
+        } else {
+            Tag::Name(format!("n{i}"))
+        },
+    }
+}
+
+fn batch_of(ids: impl Iterator<Item = i64>) -> arrow_array::RecordBatch {
+    let mut b = <Event as BuildRows>::new_builders(0);
+    b.append_rows(ids.map(event));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn filtered_views_match_owned_rows() {
+    let source = batch_of(0..12);
+    let mut b = <Event as BuildRows>::new_builders(6);
+    for view in source.iter_views::<Event>().unwrap() {
+        let view = view.unwrap();
+        if view.id % 2 == 1 {
+            b.append_view(view).unwrap();
+        }
+    }
+    let copied = b.finish().into_record_batch();
+    let expected = batch_of((0..12).filter(|i| i % 2 == 1));
+    assert_eq!(copied, expected);
+}
+
+#[test]
+fn views_from_several_batches_concatenate() {
+    let first = batch_of(0..3);
+    let second = batch_of(3..5);
+    let mut b = <Event as BuildRows>::new_builders(5);
+    for batch in [&first, &second] {
+        for view in batch.iter_views::<Event>().unwrap() {
+            b.append_view(view.unwrap()).unwrap();
+        }
+    }
+    assert_eq!(b.finish().into_record_batch(), batch_of(0..5));
+}
+
+#[derive(Record)]
+pub struct Wrapper<T> {
+    pub id: i64,
+    pub value: Option<T>,
+}
+
+#[test]
+fn generic_record_append_view() {
+    let mut b = <Wrapper<String> as BuildRows>::new_builders(2);
+    b.append_row(Wrapper {
+        id: 1,
+        value: Some("x".to_string()),
+    });
+    b.append_row(Wrapper { id: 2, value: None });
+    let source = b.finish().into_record_batch();
+
+    let mut copy = <Wrapper<String> as BuildRows>::new_builders(2);
+    for view in source.iter_views::<Wrapper<String>>().unwrap().rev() {
+        copy.append_view(view.unwrap()).unwrap();
+    }
+    let copied = copy.finish();
+    assert_eq!(copied.id.values(), &[2, 1]);
+    assert_eq!(
+        copied.value.iter().collect::<Vec<_>>(),
+        vec![None, Some("x")]
+    );
+}
@@ -0,0 +1,173 @@
+use typed_arrow::{
+    Decimal128,
+    arrow_array::{Array, Int64Array, StringArray},
+    batch_writer::BatchWriter,
+    prelude::*,
+    schema::SchemaMeta,
+};
+
+#[derive(Record)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Record)]
+struct Row {
+    id: i64,
+    name: String,
+    at: Option<Point>,
+}
+
+fn row(id: i64) -> Row {
+    Row {
+        id,
+        name: format!("n{id}"),
+        at: (id % 2 == 0).then_some(Point {
+            x: id as f64,
+            y: 0.0,
+        }),
+    }
+}
+
+#[test]
+fn emits_batches_by_row_count() {
+    let mut writer = BatchWriter::<Row>::new(3);
+    let mut batches = writer.write_rows((0..7).map(row));
+    assert_eq!(writer.pending_rows(), 1);
+    batches.extend(writer.flush());
+    assert!(writer.flush().is_none());
+    assert!(writer.write(row(7)).is_none());
+    batches.extend(writer.finish());
+
+    let sizes: Vec<usize> = batches.iter().map(|b| b.num_rows()).collect();
+    assert_eq!(sizes, vec![3, 3, 1, 1]);
+
+    let mut ids = Vec::new();
+    let mut names = Vec::new();
+    for batch in &batches {
+        let id = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        let name = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        ids.extend(id.values().iter().copied());
+        names.extend(name.iter().map(|n| n.unwrap().to_string()));
+        assert_eq!(batch.schema(), <Row as SchemaMeta>::schema());
+    }
+    assert_eq!(ids, (0..8).collect::<Vec<_>>());
+    assert_eq!(names[5], "n5");
+    assert_eq!(batches[0].column(2).null_count(), 1);
+}
+
+/// Bytes the builders hold after appending `rows`.
+fn bytes_of(rows: std::ops::Range<i64>) -> usize {
+    let mut b = <Row as BuildRows>::new_builders(0);
+    b.append_rows(rows.map(row));
+    b.memory_size()
+}
+
+#[test]
+fn byte_cap_is_checked_after_each_row() {
+    // The batch is emitted by the row that reaches the cap.
+    let cap = bytes_of(0..4);
+    let mut writer = BatchWriter::<Row>::new(1_000).with_max_bytes(cap);
+    let batches = writer.write_rows((0..4).map(row));
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        [4]
+    );
+
+    // Longer rows fill the cap sooner.
+    let mut wide = BatchWriter::<Row>::new(1_000).with_max_bytes(cap);
+    let long = |id: i64| Row {
+        name: "x".repeat(cap),
+        ..row(id)
+    };
+    assert_eq!(wide.write(long(0)).map(|b| b.num_rows()), Some(1));
+
+    let mut writer = BatchWriter::<Row>::new(2).with_max_bytes(1 << 20);
+    assert!(writer.write(row(0)).is_none());
+    assert_eq!(writer.pending_bytes(), bytes_of(0..1));
+    assert_eq!(writer.write(row(1)).map(|b| b.num_rows()), Some(2));
+    assert_eq!(writer.pending_bytes(), bytes_of(0..0));
+    assert!(writer.finish().is_none());
+
+    let mut tiny = BatchWriter::<Row>::new(10).with_max_bytes(1);
+    assert_eq!(tiny.write(row(0)).map(|b| b.num_rows()), Some(1));
+}
+
+#[test]
+fn byte_cap_alone_with_unbounded_rows() {
+    let cap = bytes_of(0..3);
+    let mut writer = BatchWriter::<Row>::new(usize::MAX).with_max_bytes(cap);
+    let batches = writer.write_rows((0..7).map(row));
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        [3, 3]
+    );
+    assert_eq!(writer.pending_rows(), 1);
+}
+
+#[test]
+fn write_view_copies_rows_of_another_batch() {
+    let mut b = <Row as BuildRows>::new_builders(4);
+    b.append_rows((0..4).map(row));
+    let batch = b.finish().into_record_batch();
+
+    let mut writer = BatchWriter::<Row>::new(3);
+    let mut batches = Vec::new();
+    for view in batch.iter_views::<Row>().unwrap() {
+        batches.extend(writer.write_view(view.unwrap()).unwrap());
+    }
+    batches.extend(writer.finish());
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        [3, 1]
+    );
+    assert_eq!(batches[0], batch.slice(0, 3));
+}
+
+#[test]
+fn try_write_skips_rejected_rows() {
+    #[derive(Record)]
+    struct Price {
+        amount: Decimal128<3, 0>,
+    }
+
+    let mut writer = BatchWriter::<Price>::new(2);
+    assert!(
+        writer
+            .try_write(Price {
+                amount: Decimal128::new(1),
+            })
+            .unwrap()
+            .is_none()
+    );
+    let err = writer
+        .try_write(Price {
+            amount: Decimal128::new(1_000),
+        })
+        .unwrap_err();
+    assert!(err.to_string().contains("precision"), "{err}");
+    assert_eq!(writer.pending_rows(), 1);
+
+    let batch = writer
+        .try_write(Price {
+            amount: Decimal128::new(2),
+        })
+        .unwrap()
+        .expect("second accepted row fills the batch");
+    assert_eq!(batch.num_rows(), 2);
+}
+
+#[test]
+#[should_panic(expected = "max_rows must be positive")]
+fn zero_max_rows_panics() {
+    let _ = BatchWriter::<Row>::new(0);
+}
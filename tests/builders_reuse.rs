@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use typed_arrow::{
+    Dictionary, List, Map, Millisecond, OrderedMap, TimestampTz, Utc,
+    arrow_array::{Array, RecordBatch},
+    prelude::*,
+    schema::{ReusableRowBuilder, SchemaMeta},
+};
+
+#[derive(Record)]
+struct Inner {
+    a: i32,
+    b: Option<String>,
+}
+
+#[derive(Record)]
+struct Row {
+    id: i64,
+    tag: Dictionary<i8, String>,
+    inner: Option<Inner>,
+    items: List<Option<u16>>,
+    attrs: Map<String, i32>,
+}
+
+fn row(id: i64) -> Row {
+    Row {
+        id,
+        tag: Dictionary::new(format!("t{}", id % 2)),
+        inner: (id % 3 != 0).then(|| Inner {
+            a: id as i32,
+            b: (id % 2 == 0).then(|| format!("b{id}")),
+        }),
+        items: List::new((0..id as u16).map(Some).collect()),
+        attrs: Map::new(vec![(format!("k{id}"), id as i32)]),
+    }
+}
+
+fn expected(ids: std::ops::Range<i64>) -> RecordBatch {
+    let mut b = <Row as BuildRows>::new_builders(0);
+    b.append_rows(ids.map(row));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn finish_and_reset_reuses_builders_across_batches() {
+    let mut b = <Row as BuildRows>::new_builders(4);
+    assert!(b.is_empty());
+
+    b.append_rows((0..4).map(row));
+    assert_eq!(b.len(), 4);
+    let first = b.finish_and_reset().into_record_batch();
+    assert!(b.is_empty());
+    assert_eq!(first, expected(0..4));
+
+    b.append_rows((4..7).map(row));
+    assert_eq!(b.len(), 3);
+    let second = b.finish_and_reset().into_record_batch();
+    assert_eq!(second, expected(4..7));
+    assert!(second.column(2).is_null(2));
+
+    // The dictionary starts fresh for every batch.
+    b.append_row(row(9));
+    let third = b.finish_and_reset().into_record_batch();
+    assert_eq!(third, expected(9..10));
+}
+
+#[derive(Record)]
+struct Adjusted {
+    at: TimestampTz<Millisecond, Utc>,
+    sorted: OrderedMap<String, i32>,
+}
+
+#[test]
+fn finishing_keeps_adjusted_data_types() {
+    let mut b = <Adjusted as BuildRows>::new_builders(0);
+    b.append_row(Adjusted {
+        at: TimestampTz::new(1),
+        sorted: OrderedMap::new(BTreeMap::from([("a".to_string(), 1)])),
+    });
+    let snapshot = b.finish_cloned().into_record_batch();
+    assert_eq!(snapshot.schema(), <Adjusted as SchemaMeta>::schema());
+    let batch = b.finish_and_reset().into_record_batch();
+    assert_eq!(batch.schema(), <Adjusted as SchemaMeta>::schema());
+    assert!(b.is_empty());
+}
+
+#[test]
+fn finish_cloned_snapshots_without_consuming() {
+    let mut b = <Row as BuildRows>::new_builders(0);
+    b.append_rows((0..3).map(row));
+    let snapshot = b.finish_cloned().into_record_batch();
+    assert_eq!(snapshot, expected(0..3));
+    assert_eq!(b.len(), 3);
+
+    b.append_rows((3..5).map(row));
+    assert_eq!(b.finish().into_record_batch(), expected(0..5));
+}
+
+#[test]
+fn memory_size_tracks_appended_rows() {
+    let mut b = <Row as BuildRows>::new_builders(0);
+    // Only the leading offsets of `items` and `inner.b`.
+    let empty = 2 * 4;
+    assert_eq!(b.memory_size(), empty);
+
+    b.append_rows((0..10).map(row));
+    // Ten i64 values and no validity bitmap.
+    assert_eq!(b.column_memory_size(RowColumn::Id), 10 * 8);
+    // Eleven i32 offsets and 45 u16 items.
+    assert_eq!(b.column_memory_size(RowColumn::Items), 11 * 4 + 45 * 2);
+    // Struct and child validity bitmaps (2 bytes each), ten i32 values, eleven
+    // offsets and the bytes of "b2", "b4" and "b8".
+    assert_eq!(
+        b.column_memory_size(RowColumn::Inner),
+        2 + (2 + 10 * 4) + (2 + 11 * 4 + 6)
+    );
+    let total: usize = RowColumn::ALL
+        .iter()
+        .map(|&c| b.column_memory_size(c))
+        .sum();
+    assert_eq!(b.memory_size(), total);
+
+    b.append_row(row(10));
+    assert!(b.memory_size() > total);
+    let total = b.memory_size();
+
+    // The same figures are available through the generic trait.
+    fn report<B: ReusableRowBuilder<Row>>(b: &B) -> (usize, usize) {
+        (b.len(), b.memory_size())
+    }
+    assert_eq!(report(&b), (11, total));
+
+    let _ = ReusableRowBuilder::finish_and_reset(&mut b);
+    assert_eq!(report(&b), (0, empty));
+}
@@ -0,0 +1,75 @@
+use typed_arrow::{arrow_array::RecordBatch, prelude::*};
+
+#[derive(Record, Clone)]
+struct Inner {
+    x: i16,
+    y: Option<f32>,
+}
+
+#[derive(Record, Clone)]
+struct Row {
+    id: u64,
+    score: Option<f64>,
+    level: Option<i8>,
+    flag: bool,
+    maybe: Option<bool>,
+    name: String,
+    inner: Option<Inner>,
+}
+
+fn row(i: u64) -> Row {
+    Row {
+        id: i,
+        score: (!i.is_multiple_of(7)).then_some(i as f64 * 0.25),
+        // Present for every row: exercises the no-null slice path.
+        level: Some((i % 100) as i8),
+        flag: i.is_multiple_of(2),
+        maybe: (!i.is_multiple_of(5)).then_some(i.is_multiple_of(3)),
+        name: format!("r{i}"),
+        inner: (!i.is_multiple_of(4)).then_some(Inner {
+            x: i as i16,
+            y: i.is_multiple_of(3).then_some(i as f32),
+        }),
+    }
+}
+
+fn per_row(rows: &[Row]) -> RecordBatch {
+    let mut b = <Row as BuildRows>::new_builders(0);
+    for r in rows.iter().cloned() {
+        b.append_row(r);
+    }
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn bulk_append_matches_per_row_append() {
+    // Crosses several internal chunk boundaries, including an exact multiple.
+    for n in [0, 1, 1023, 1024, 2048, 3000] {
+        let rows: Vec<Row> = (0..n).map(row).collect();
+        let expected = per_row(&rows);
+
+        let mut owned = <Row as BuildRows>::new_builders(0);
+        owned.append_rows(rows.iter().cloned());
+        assert_eq!(owned.finish().into_record_batch(), expected, "n = {n}");
+
+        let mut borrowed = <Row as BuildRows>::new_builders(0);
+        borrowed.append_rows_ref(&rows);
+        assert_eq!(borrowed.finish().into_record_batch(), expected, "n = {n}");
+    }
+}
+
+#[test]
+fn bulk_append_interleaves_with_row_appends() {
+    let rows: Vec<Row> = (0..1500).map(row).collect();
+    let mut b = <Row as BuildRows>::new_builders(0);
+    b.append_row(rows[0].clone());
+    b.append_rows(rows[1..1200].iter().cloned());
+    b.append_rows_ref(&rows[1200..]);
+    let batch = b.finish().into_record_batch();
+
+    let mut expected = <Row as BuildRows>::new_builders(0);
+    expected.append_rows(rows[..1200].iter().cloned());
+    expected.append_rows(rows[1200..].iter().cloned());
+    assert_eq!(batch, expected.finish().into_record_batch());
+    assert_eq!(batch.num_rows(), 1500);
+}
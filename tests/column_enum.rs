@@ -0,0 +1,155 @@
+use arrow_schema::DataType;
+use typed_arrow::{
+    List,
+    prelude::*,
+    schema::{ColAt, ColumnRef, Projection, RecordColumns, SchemaMeta, TypedColumn},
+};
+
+#[derive(Record)]
+pub struct Address {
+    pub city: String,
+    pub zip_code: Option<i32>,
+}
+
+#[derive(Record)]
+pub struct Person {
+    pub id: i64,
+    #[record(name = "fullName")]
+    pub full_name: Option<String>,
+    pub home_address: Address,
+    pub tags: List<String>,
+    pub kind: u8,
+}
+
+#[test]
+fn variants_carry_name_index_and_type() {
+    assert_eq!(PersonColumn::ALL.len(), <Person as Record>::LEN);
+    assert_eq!(PersonColumn::Id.index(), 0);
+    assert_eq!(PersonColumn::FullName.name(), "fullName");
+    assert_eq!(PersonColumn::Kind.name(), "kind");
+    assert_eq!(PersonColumn::Id.data_type(), DataType::Int64);
+    assert!(PersonColumn::FullName.is_nullable());
+    assert!(!PersonColumn::HomeAddress.is_nullable());
+
+    let schema = <Person as SchemaMeta>::schema();
+    for col in PersonColumn::ALL {
+        let field = schema.field(col.index());
+        assert_eq!(field.name(), col.name());
+        assert_eq!(field.data_type(), &col.data_type());
+        assert_eq!(field.is_nullable(), col.is_nullable());
+    }
+}
+
+#[derive(Record)]
+pub struct Awkward {
+    pub _1: i32,
+    pub self_: bool,
+    pub all: u8,
+}
+
+#[test]
+fn awkward_field_names_get_valid_variants() {
+    assert_eq!(AwkwardColumn::Column1.name(), "_1");
+    assert_eq!(AwkwardColumn::ColumnSelf.name(), "self_");
+}
+
+#[test]
+fn typed_constants_name_their_variant() {
+    fn native<R: ColAt<I>, const I: usize>(_: TypedColumn<R, I>, v: R::Native) -> R::Native {
+        v
+    }
+    assert_eq!(native(PersonColumn::FULL_NAME, "a".to_string()), "a");
+    assert_eq!(PersonColumn::FULL_NAME.column(), PersonColumn::FullName);
+    assert_eq!(PersonColumn::FULL_NAME.name(), "fullName");
+    assert_eq!(PersonColumn::KIND.index(), 4);
+    assert_eq!(AwkwardColumn::_1.column(), AwkwardColumn::Column1);
+    assert_eq!(AwkwardColumn::SELF_.column(), AwkwardColumn::ColumnSelf);
+    // `ALL` stays the column list; that field's constant gets its index appended.
+    assert_eq!(AwkwardColumn::ALL_2.name(), "all");
+}
+
+// The generated builders and arrays reuse the field names, so the lint covers them too.
+#[allow(non_snake_case)]
+mod clashing {
+    use typed_arrow::prelude::*;
+
+    #[derive(Record)]
+    pub struct Clashing {
+        pub a_b: i32,
+        pub a__b: i64,
+        pub fooBar: u8,
+        pub foobar: u16,
+        pub x: bool,
+    }
+}
+use clashing::ClashingColumn;
+
+#[test]
+fn clashing_field_names_get_their_index_appended() {
+    assert_eq!(ClashingColumn::AB0.name(), "a_b");
+    assert_eq!(ClashingColumn::AB1.name(), "a__b");
+    assert_eq!(ClashingColumn::FooBar.name(), "fooBar");
+    assert_eq!(ClashingColumn::Foobar.name(), "foobar");
+    assert_eq!(ClashingColumn::A_B.column(), ClashingColumn::AB0);
+    assert_eq!(ClashingColumn::A__B.column(), ClashingColumn::AB1);
+    assert_eq!(ClashingColumn::FOOBAR_2.column(), ClashingColumn::FooBar);
+    assert_eq!(ClashingColumn::FOOBAR_3.column(), ClashingColumn::Foobar);
+    // A variant would shadow a constant of the same name.
+    assert_eq!(ClashingColumn::X_4.column(), ClashingColumn::X);
+}
+
+// User types already named like the generated ones.
+pub struct TripColumn;
+pub struct TripColumns;
+pub struct TripVecs;
+
+#[derive(Record)]
+#[record(column_enum = TripCol, columns_struct = "TripCols", vecs_struct = TripColVecs)]
+pub struct Trip {
+    pub id: i64,
+}
+
+#[test]
+fn generated_type_names_can_be_chosen() {
+    let _ = (TripColumn, TripColumns, TripVecs);
+    assert_eq!(TripCol::ID.column(), TripCol::Id);
+    let vecs = TripColVecs { id: vec![1, 2] };
+    let batch = vecs.into_arrays().unwrap().into_record_batch();
+    let cols: TripCols<'_> = batch.columns_of::<Trip>().unwrap();
+    assert_eq!(cols.id.values(), &[1, 2]);
+    assert_eq!(TripId::source_indices(), vec![0]);
+}
+
+#[derive(Record)]
+#[record(project_of = Trip)]
+pub struct TripId {
+    pub id: i64,
+}
+
+#[test]
+fn names_are_usable_in_const_context() {
+    const ID: &str = PersonColumn::Id.name();
+    const TAGS: usize = PersonColumn::Tags.index();
+    assert_eq!((ID, TAGS), ("id", 3));
+}
+
+#[test]
+fn nested_paths() {
+    let path = PersonColumn::HomeAddress
+        .path()
+        .child(AddressColumn::ZipCode);
+    assert_eq!(path.to_string(), "home_address.zip_code");
+    assert_eq!(path.names(), &["home_address", "zip_code"]);
+    assert_eq!(path.indices(), &[2, 1]);
+}
+
+#[test]
+fn generic_code_via_record_columns() {
+    fn names<R: RecordColumns>() -> Vec<&'static str> {
+        R::COLUMNS.iter().map(|c| c.name()).collect()
+    }
+    assert_eq!(
+        names::<Person>(),
+        vec!["id", "fullName", "home_address", "tags", "kind"]
+    );
+}
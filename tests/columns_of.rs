@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use typed_arrow::{List, prelude::*};
+
+#[derive(Record)]
+pub struct Address {
+    pub city: String,
+}
+
+#[derive(Record)]
+pub struct Person {
+    pub id: i64,
+    pub name: Option<String>,
+    pub scores: List<f64>,
+    pub address: Option<Address>,
+}
+
+fn people() -> RecordBatch {
+    let mut b = <Person as BuildRows>::new_builders(2);
+    b.append_row(Person {
+        id: 1,
+        name: Some("Ada".into()),
+        scores: List::new(vec![1.5, 2.5]),
+        address: Some(Address {
+            city: "London".into(),
+        }),
+    });
+    b.append_row(Person {
+        id: 2,
+        name: None,
+        scores: List::new(vec![]),
+        address: None,
+    });
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn columns_are_typed_arrays() {
+    let batch = people();
+    let cols = batch.columns_of::<Person>().unwrap();
+
+    let ids: &Int64Array = cols.id;
+    assert_eq!(ids.values(), &[1, 2]);
+    let names: &StringArray = cols.name;
+    assert_eq!(names.value(0), "Ada");
+    assert!(names.is_null(1));
+    assert_eq!(cols.scores.value_offsets(), &[0, 2, 2]);
+    assert_eq!(cols.address.null_count(), 1);
+}
+
+#[test]
+fn generic_code_can_use_the_trait() {
+    fn num_columns<R: FromRecordBatchColumns>(batch: &RecordBatch) -> usize {
+        R::columns_from_record_batch(batch).map_or(0, |_| R::LEN)
+    }
+    assert_eq!(num_columns::<Person>(&people()), 4);
+}
+
+#[test]
+fn mismatched_batches_are_rejected() {
+    #[derive(Record)]
+    pub struct Ids {
+        pub id: i64,
+    }
+
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)]));
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(StringArray::from(vec!["x"]))]).unwrap();
+    assert!(matches!(
+        batch.columns_of::<Ids>(),
+        Err(SchemaError::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        people().columns_of::<Ids>(),
+        Err(SchemaError::InvalidSchema { .. })
+    ));
+}
@@ -0,0 +1,211 @@
+use typed_arrow::{
+    Date32, Decimal128, Duration, List, Millisecond, Second, Timestamp, TypedBatch,
+    arrow_array::{Array, RecordBatch},
+    arrow_ord::sort::SortOptions,
+    compute::{filter_rows, sort_by, take_rows},
+    error::ComputeError,
+    prelude::*,
+};
+
+#[derive(Record)]
+struct Tag {
+    key: String,
+}
+
+#[derive(Record)]
+struct Item {
+    id: i64,
+    group: String,
+    score: Option<f64>,
+    tags: List<Tag>,
+}
+
+fn batch() -> RecordBatch {
+    let mut b = <Item as BuildRows>::new_builders(5);
+    for (id, group, score) in [
+        (1, "b", Some(2.0)),
+        (2, "a", None),
+        (3, "b", Some(1.0)),
+        (4, "a", Some(3.0)),
+        (5, "c", Some(2.0)),
+    ] {
+        b.append_row(Item {
+            id,
+            group: group.into(),
+            score,
+            tags: List::new(
+                (0..id % 3)
+                    .map(|i| Tag {
+                        key: format!("t{id}{i}"),
+                    })
+                    .collect(),
+            ),
+        });
+    }
+    b.finish().into_record_batch()
+}
+
+fn ids(typed: &TypedBatch<Item>) -> Vec<i64> {
+    typed.columns().id.values().to_vec()
+}
+
+#[test]
+fn filter_rows_by_view_keeps_nested_columns() -> Result<(), ComputeError> {
+    let batch = batch();
+    let kept = filter_rows::<Item, _>(&batch, |row| {
+        row.score.is_some_and(|s| s >= 2.0) && !row.tags.is_empty()
+    })?;
+    assert_eq!(ids(&kept), vec![1, 4, 5]);
+    assert_eq!(kept.schema(), batch.schema());
+
+    let tags: Vec<Vec<String>> = kept
+        .iter_views()
+        .map(|row| {
+            row.unwrap()
+                .tags
+                .map(|t| t.unwrap().key.to_string())
+                .collect()
+        })
+        .collect();
+    assert_eq!(tags, vec![vec!["t10"], vec!["t40"], vec!["t50", "t51"]]);
+
+    let none = kept.filter_rows(|_| false)?;
+    assert!(none.is_empty());
+    Ok(())
+}
+
+#[test]
+fn take_rows_gathers_in_order_and_checks_bounds() -> Result<(), ComputeError> {
+    let batch = batch();
+    let taken = take_rows::<Item>(&batch, &[4, 0, 0, 2])?;
+    assert_eq!(ids(&taken), vec![5, 1, 1, 3]);
+    assert_eq!(taken.row(1).unwrap().group, "b");
+
+    let err = taken.take_rows(&[0, 4]).unwrap_err();
+    assert!(matches!(err, ComputeError::Arrow(_)), "{err}");
+    Ok(())
+}
+
+#[test]
+fn sort_by_column_keys() -> Result<(), ComputeError> {
+    let batch = batch();
+    let asc = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let sorted = sort_by::<Item>(&batch, &[ItemColumn::Group, ItemColumn::Score], asc)?;
+    assert_eq!(ids(&sorted), vec![2, 4, 3, 1, 5]);
+
+    let desc = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let sorted = sorted.sort_by(&[ItemColumn::Score, ItemColumn::Id], desc)?;
+    assert_eq!(ids(&sorted), vec![4, 5, 1, 3, 2]);
+    assert_eq!(sorted.columns().score.null_count(), 1);
+
+    let unchanged = sorted.sort_by(&[], desc)?;
+    assert_eq!(ids(&unchanged), ids(&sorted));
+
+    Ok(())
+}
+
+#[test]
+fn free_functions_reject_foreign_batches() {
+    #[derive(Record)]
+    struct Other {
+        id: i64,
+    }
+
+    let mut b = <Other as BuildRows>::new_builders(1);
+    b.append_row(Other { id: 1 });
+    let other = b.finish().into_record_batch();
+
+    assert!(matches!(
+        filter_rows::<Item, _>(&other, |_| true),
+        Err(ComputeError::Schema(_))
+    ));
+    assert!(matches!(
+        take_rows::<Item>(&other, &[0]),
+        Err(ComputeError::Schema(_))
+    ));
+    assert!(matches!(
+        sort_by::<Item>(&other, &[ItemColumn::Id], SortOptions::default()),
+        Err(ComputeError::Schema(_))
+    ));
+}
+
+#[derive(Record)]
+struct Reading {
+    sensor: String,
+    at: Timestamp<Millisecond>,
+    day: Date32,
+    amount: Option<Decimal128<10, 2>>,
+    elapsed: Duration<Second>,
+    level: u8,
+}
+
+fn readings() -> TypedBatch<Reading> {
+    let mut b = <Reading as BuildRows>::new_builders(4);
+    for (sensor, at, amount, elapsed) in [
+        ("s1", 3_000, Some(1_250), 5),
+        ("s2", 1_000, None, 7),
+        ("s1", 2_000, Some(-50), 1),
+        ("s3", 4_000, Some(1_250), 2),
+    ] {
+        b.append_row(Reading {
+            sensor: sensor.into(),
+            at: Timestamp::new(at),
+            day: Date32::new((at / 1_000) as i32),
+            amount: amount.map(Decimal128::new),
+            elapsed: Duration::new(elapsed),
+            level: 200,
+        });
+    }
+    b.finish().into_typed_batch()
+}
+
+#[test]
+fn column_aggregates_keep_rust_types() -> Result<(), ComputeError> {
+    let typed = readings();
+
+    let total: Option<Decimal128<10, 2>> = typed.sum::<3>()?;
+    assert_eq!(total.map(|d| d.value()), Some(2_450));
+    let low: Option<Decimal128<10, 2>> = typed.min::<3>();
+    assert_eq!(low.map(|d| d.value()), Some(-50));
+
+    let first: Option<Timestamp<Millisecond>> = typed.min::<1>();
+    let last: Option<Timestamp<Millisecond>> = typed.max::<1>();
+    assert_eq!(first.map(|t| t.value()), Some(1_000));
+    assert_eq!(last.map(|t| t.value()), Some(4_000));
+    assert_eq!(typed.max::<2>().map(|d| d.value()), Some(4));
+
+    let busy: Option<Duration<Second>> = typed.sum::<4>()?;
+    assert_eq!(busy.map(|d| d.value()), Some(15));
+
+    assert_eq!(typed.count_nulls::<3>(), 1);
+    assert_eq!(typed.count_nulls::<0>(), 0);
+    assert_eq!(typed.distinct_count::<0>()?, 3);
+    assert_eq!(typed.distinct_count::<3>()?, 2);
+    assert_eq!(typed.distinct_count::<5>()?, 1);
+    Ok(())
+}
+
+#[test]
+fn column_aggregates_on_empty_and_overflowing_columns() -> Result<(), ComputeError> {
+    let typed = readings();
+
+    let empty = typed.slice(0, 0);
+    assert!(empty.sum::<3>()?.is_none());
+    assert!(empty.min::<1>().is_none());
+    assert_eq!(empty.distinct_count::<0>()?, 0);
+
+    let nulls = typed.slice(1, 1);
+    assert!(nulls.sum::<3>()?.is_none());
+    assert_eq!(nulls.distinct_count::<3>()?, 0);
+
+    // 4 * 200 does not fit in u8.
+    assert!(matches!(typed.sum::<5>(), Err(ComputeError::Arrow(_))));
+    assert_eq!(typed.slice(0, 1).sum::<5>()?, Some(200));
+    Ok(())
+}
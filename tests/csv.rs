@@ -0,0 +1,246 @@
+use typed_arrow::{
+    Date32, Date64, Decimal128, Dictionary, Microsecond, Millisecond, Second, Time32, Time64,
+    TimeZoneSpec, Timestamp, TimestampTz, Utc,
+    error::{CsvError, SchemaError},
+    io::csv::{TypedCsvReader, TypedCsvReaderBuilder, TypedCsvWriter, text_schema, writer_builder},
+    prelude::*,
+};
+
+enum PlusEight {}
+impl TimeZoneSpec for PlusEight {
+    const NAME: Option<&'static str> = Some("+08:00");
+}
+
+#[derive(Record)]
+struct Reading {
+    id: i64,
+    sensor: Dictionary<i32, String>,
+    day: Date32,
+    at: Timestamp<Millisecond>,
+    at_utc: Option<TimestampTz<Microsecond, Utc>>,
+    at_local: TimestampTz<Second, PlusEight>,
+    logged: Date64,
+    time_of_day: Time32<Millisecond>,
+    elapsed: Option<Time64<Microsecond>>,
+    amount: Decimal128<12, 3>,
+    ok: bool,
+    value: Option<f64>,
+    label: String,
+}
+
+fn reading(id: i64) -> Reading {
+    Reading {
+        id,
+        sensor: Dictionary::new(format!("s{}", id % 3)),
+        day: Date32::new(19_000 + id as i32),
+        at: Timestamp::new(1_700_000_000_000 + id * 1_500),
+        at_utc: (id % 2 == 0).then(|| TimestampTz::new(1_700_000_000_000_000 + id * 7)),
+        at_local: TimestampTz::new(1_700_000_000 + id),
+        logged: Date64::new(1_700_000_000_000 + id * 86_400_000),
+        time_of_day: Time32::new(3_600_000 + id as i32 * 250),
+        elapsed: (id % 3 != 0).then(|| Time64::new(id * 1_000_001)),
+        amount: Decimal128::new(i128::from(-12_345 + id * 1_001)),
+        ok: id % 2 == 1,
+        value: (id % 4 != 0).then_some(id as f64 / 8.0),
+        label: if id == 0 {
+            String::new()
+        } else {
+            format!("a, \"b\" {id}")
+        },
+    }
+}
+
+#[test]
+fn round_trips_temporal_decimal_and_dictionary_columns() {
+    let mut writer = TypedCsvWriter::<Reading, _>::try_new(Vec::new())
+        .unwrap()
+        .with_batch_writer(typed_arrow::batch_writer::BatchWriter::new(4));
+    writer.write_rows((0..10).map(reading)).unwrap();
+    let bytes = writer.finish().unwrap();
+    let text = String::from_utf8(bytes.clone()).unwrap();
+    let mut lines = text.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "id,sensor,day,at,at_utc,at_local,logged,time_of_day,elapsed,amount,ok,value,label"
+    );
+    assert_eq!(
+        lines.nth(1).unwrap(),
+        "1,s1,2022-01-09,2023-11-14T22:13:21.500,,2023-11-14T22:13:21+00:00,2023-11-15T22:13:20,\
+         01:00:00.250,00:00:01.000001,-11.344,true,0.125,\"a, \"\"b\"\" 1\""
+    );
+
+    let back: Vec<Reading> = TypedCsvReaderBuilder::<Reading>::new()
+        .with_batch_size(3)
+        .build(bytes.as_slice())
+        .unwrap()
+        .into_records()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back.len(), 10);
+    for (row, expected) in back.iter().zip((0..10).map(reading)) {
+        assert_eq!(row.sensor.value(), expected.sensor.value());
+        assert_eq!(row.at.value(), expected.at.value());
+        assert_eq!(
+            row.at_utc.as_ref().map(|t| t.value()),
+            expected.at_utc.map(|t| t.value())
+        );
+        assert_eq!(row.at_local.value(), expected.at_local.value());
+        assert_eq!(row.logged.value(), expected.logged.value());
+        assert_eq!(row.time_of_day.value(), expected.time_of_day.value());
+        assert_eq!(
+            row.elapsed.as_ref().map(|t| t.value()),
+            expected.elapsed.map(|t| t.value())
+        );
+        assert_eq!(row.amount.value(), expected.amount.value());
+        assert_eq!(row.value, expected.value);
+        assert_eq!(row.label, expected.label);
+    }
+
+    // Writing what was read produces the same text.
+    let mut again = TypedCsvWriter::<Reading, _>::try_new(Vec::new()).unwrap();
+    again.write_rows(back).unwrap();
+    assert_eq!(again.finish().unwrap(), bytes);
+}
+
+#[test]
+fn timestamps_with_offsets_read_as_instants() {
+    #[derive(Record)]
+    struct Row {
+        at: TimestampTz<Second, Utc>,
+    }
+
+    let csv = "at\n1970-01-01T08:00:01+08:00\n1970-01-01T00:00:02Z\n1970-01-01T00:00:03\n";
+    let values: Vec<i64> = TypedCsvReader::<Row, _>::try_new(csv.as_bytes())
+        .unwrap()
+        .into_records()
+        .map(|row| row.unwrap().at.value())
+        .collect();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[test]
+fn parse_errors_name_the_row_column_and_text() {
+    #[derive(Record, Debug)]
+    struct Row {
+        id: i64,
+        at: Option<Timestamp<Millisecond>>,
+    }
+
+    let csv = "id,at\n1,2024-01-01T00:00:00\n2,\n3,2024-01-01T00:00:00\n4,yesterday\n";
+    let mut reader = TypedCsvReaderBuilder::<Row>::new()
+        .with_batch_size(2)
+        .build(csv.as_bytes())
+        .unwrap();
+    assert_eq!(reader.next().unwrap().unwrap().len(), 2);
+    match reader.next().unwrap() {
+        Err(CsvError::Parse {
+            row,
+            column,
+            value,
+            message,
+        }) => {
+            assert_eq!(
+                (row, column.as_str(), value.as_str()),
+                (3, "at", "yesterday")
+            );
+            assert!(!message.is_empty());
+        }
+        other => panic!("expected a parse error, got {other:?}"),
+    }
+
+    let missing = "id,at\n1,\n,2024-01-01T00:00:00\n";
+    let err = TypedCsvReader::<Row, _>::try_new(missing.as_bytes())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap_err();
+    assert!(
+        matches!(&err, CsvError::Parse { row: 1, column, value, .. } if column == "id" && value.is_empty()),
+        "{err}"
+    );
+}
+
+#[test]
+fn headers_and_options() {
+    #[derive(Record, Debug, PartialEq)]
+    struct Pair {
+        key: String,
+        count: u32,
+    }
+
+    let swapped = "count,key\n1,a\n";
+    let err = TypedCsvReader::<Pair, _>::try_new(swapped.as_bytes())
+        .err()
+        .expect("header names are checked");
+    assert!(
+        matches!(err, CsvError::Schema(SchemaError::InvalidSchema { .. })),
+        "{err}"
+    );
+
+    let rows: Vec<Pair> = TypedCsvReaderBuilder::<Pair>::new()
+        .with_header(false)
+        .with_delimiter(b';')
+        .build("'x;y';2\nz;3\n".replace('\'', "\"").as_bytes())
+        .unwrap()
+        .into_records()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            Pair {
+                key: "x;y".into(),
+                count: 2
+            },
+            Pair {
+                key: "z".into(),
+                count: 3
+            }
+        ]
+    );
+
+    let mut writer = TypedCsvWriter::<Pair, _>::try_new_with_builder(
+        Vec::new(),
+        writer_builder().with_delimiter(b'\t'),
+    )
+    .unwrap();
+    writer
+        .write_row(Pair {
+            key: "k".into(),
+            count: 9,
+        })
+        .unwrap();
+    assert_eq!(writer.finish().unwrap(), b"key\tcount\nk\t9\n");
+
+    // An empty file still has its header, and reads back as no rows.
+    let empty = TypedCsvWriter::<Pair, _>::try_new(Vec::new())
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(empty, b"key,count\n");
+    assert_eq!(
+        TypedCsvReader::<Pair, _>::try_new(empty.as_slice())
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn nested_and_binary_columns_are_rejected() {
+    #[derive(Record)]
+    struct Tags {
+        id: i64,
+        tags: typed_arrow::List<String>,
+    }
+
+    #[derive(Record)]
+    struct Blob {
+        data: Vec<u8>,
+    }
+
+    let err = text_schema::<Tags>().unwrap_err();
+    assert!(err.to_string().contains("`tags`"), "{err}");
+    assert!(TypedCsvWriter::<Blob, _>::try_new(Vec::new()).is_err());
+    assert!(TypedCsvReader::<Blob, _>::try_new(&b"data\n"[..]).is_err());
+}
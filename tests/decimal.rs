@@ -43,3 +43,29 @@ fn decimal256_bindings() {
     assert_eq!(a.value(0), i256::from(12345i64));
     assert_eq!(a.value(1), i256::from(-7i64));
 }
+
+#[test]
+fn decimal_checked_constructors() {
+    type D = typed_arrow::Decimal128<5, 2>;
+    assert_eq!(D::try_new(99_999).unwrap().value(), 99_999);
+    assert_eq!(D::try_new(-99_999).unwrap().value(), -99_999);
+    let err = D::try_new(100_000).err().unwrap();
+    assert_eq!(err.precision, 5);
+    assert_eq!(err.value, "100000");
+    assert!(!D::new(100_000).fits_precision());
+
+    type W = typed_arrow::Decimal256<3, 0>;
+    assert!(W::try_new(i256::from(999i64)).is_ok());
+    assert!(W::try_new(i256::from(-1000i64)).is_err());
+}
+
+#[test]
+fn decimal_try_append_rejects_out_of_precision() {
+    type D = typed_arrow::Decimal128<3, 1>;
+    let mut b = <D as ArrowBinding>::new_builder(2);
+    <D as ArrowBinding>::try_append_value(&mut b, &D::new(999)).unwrap();
+    assert!(<D as ArrowBinding>::try_append_value(&mut b, &D::new(1_000)).is_err());
+    let a: Decimal128Array = <D as ArrowBinding>::finish(b);
+    assert_eq!(a.len(), 1);
+    a.validate_decimal_precision(3).unwrap();
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use arrow_array::{Decimal128Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+use typed_arrow::{Decimal128, error::DecimalPrecisionError, prelude::*};
+
+#[derive(Record)]
+pub struct Payment {
+    pub amount: Decimal128<4, 2>,
+}
+
+fn checked_amount(view: PaymentView<'_>) -> Result<i128, ViewAccessError> {
+    Ok(view.amount.checked()?.value())
+}
+
+#[test]
+fn views_reject_out_of_precision_values_on_request() {
+    // Built without precision validation, as an external producer might.
+    let amounts = Decimal128Array::from(vec![9_999i128, 12_345])
+        .with_precision_and_scale(4, 2)
+        .unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "amount",
+        DataType::Decimal128(4, 2),
+        false,
+    )]));
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(amounts)]).unwrap();
+
+    let mut views = batch.iter_views::<Payment>().unwrap();
+    assert_eq!(
+        checked_amount(views.next().unwrap().unwrap()).unwrap(),
+        9_999
+    );
+
+    // Reading is unchecked; the check only runs where it is asked for.
+    let view = views.next().unwrap().unwrap();
+    assert_eq!(view.amount.value(), 12_345);
+    match checked_amount(view) {
+        Err(ViewAccessError::DecimalPrecision(DecimalPrecisionError { precision, value })) => {
+            assert_eq!(precision, 4);
+            assert_eq!(value, "12345");
+        }
+        other => panic!("expected precision error, got {other:?}"),
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use typed_arrow::{
+    List,
+    arrow_array::{
+        Array, Int64Array, RecordBatch, StringArray, StructArray, ffi::to_ffi,
+        ffi_stream::ArrowArrayStreamReader,
+    },
+    arrow_schema::{DataType, Field},
+    batch_writer::BatchWriter,
+    error::{FfiError, SchemaError},
+    ffi::{
+        TypedArrowStreamReader, export_batch, export_batches, export_rows, export_typed_batch,
+        import_batch,
+    },
+    prelude::*,
+    schema::SchemaMeta,
+};
+
+#[derive(Record)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Record)]
+#[schema_metadata(k = "owner", v = "ingest")]
+struct Track {
+    id: i64,
+    name: Option<String>,
+    path: List<Point>,
+}
+
+#[derive(Record)]
+struct Other {
+    id: i64,
+    label: String,
+}
+
+fn track(id: i64) -> Track {
+    Track {
+        id,
+        name: (id % 2 == 0).then(|| format!("t{id}")),
+        path: List::new(
+            (0..id % 3)
+                .map(|i| Point {
+                    x: i as f64,
+                    y: -(i as f64),
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn batch(ids: std::ops::Range<i64>) -> RecordBatch {
+    let mut b = <Track as BuildRows>::new_builders(0);
+    b.append_rows(ids.map(track));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn batch_round_trip_keeps_rows_and_metadata() {
+    let typed = TypedBatch::<Track>::try_new(batch(0..6)).unwrap();
+    let (array, schema) = export_typed_batch(&typed).unwrap();
+    let back = unsafe { import_batch::<Track>(array, &schema) }.unwrap();
+
+    assert_eq!(back.as_record_batch(), typed.as_record_batch());
+    assert_eq!(
+        back.as_record_batch().schema().metadata().get("owner"),
+        Some(&"ingest".to_string())
+    );
+    let rows: Vec<(i64, Option<String>, usize)> = back
+        .iter_views()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.id, row.name.map(str::to_owned), row.path.len())
+        })
+        .collect();
+    assert_eq!(rows[4], (4, Some("t4".into()), 1));
+    assert_eq!(rows[5], (5, None, 2));
+}
+
+#[test]
+fn export_batch_rejects_batches_of_another_record() {
+    let mut b = <Other as BuildRows>::new_builders(1);
+    b.append_row(Other {
+        id: 1,
+        label: "x".into(),
+    });
+    let other = b.finish().into_record_batch();
+    assert!(matches!(
+        export_batch::<Track>(&other),
+        Err(FfiError::Schema(_))
+    ));
+    assert!(export_batch::<Other>(&other).is_ok());
+}
+
+#[test]
+fn import_validates_types_and_nullability() {
+    let (array, schema) = export_batch::<Track>(&batch(0..3)).unwrap();
+    assert!(matches!(
+        unsafe { import_batch::<Other>(array, &schema) },
+        Err(FfiError::Schema(_))
+    ));
+
+    // A foreign producer declaring `label` nullable and sending a null.
+    let label = Field::new("label", DataType::Utf8, true);
+    let foreign = StructArray::new(
+        vec![Field::new("id", DataType::Int64, false), label].into(),
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+        ],
+        None,
+    );
+    let (array, schema) = to_ffi(&foreign.to_data()).unwrap();
+    assert!(matches!(
+        unsafe { import_batch::<Other>(array, &schema) },
+        Err(FfiError::Schema(SchemaError::Nullability { .. }))
+    ));
+
+    // Not a struct array at all.
+    let (array, schema) = to_ffi(&Int64Array::from(vec![1]).to_data()).unwrap();
+    assert!(matches!(
+        unsafe { import_batch::<Other>(array, &schema) },
+        Err(FfiError::Schema(SchemaError::InvalidSchema { .. }))
+    ));
+}
+
+#[test]
+fn row_stream_is_pulled_in_batches() {
+    let stream = export_rows((0..7).map(track), BatchWriter::new(3));
+    let reader = TypedArrowStreamReader::<Track>::try_new(stream).unwrap();
+    assert_eq!(
+        reader.schema().fields(),
+        <Track as SchemaMeta>::schema().fields()
+    );
+    let sizes: Vec<usize> = reader.map(|b| b.unwrap().len()).collect();
+    assert_eq!(sizes, vec![3, 3, 1]);
+
+    let stream = export_rows((0..7).map(track), BatchWriter::new(3));
+    let ids: Vec<i64> = TypedArrowStreamReader::<Track>::try_new(stream)
+        .unwrap()
+        .into_records()
+        .map(|row| row.unwrap().id)
+        .collect();
+    assert_eq!(ids, (0..7).collect::<Vec<_>>());
+}
+
+#[test]
+fn batch_stream_crosses_a_raw_pointer() {
+    let batches = vec![
+        TypedBatch::<Track>::try_new(batch(0..2)).unwrap(),
+        TypedBatch::<Track>::try_new(batch(2..5)).unwrap(),
+    ];
+    // What a C consumer would receive.
+    let raw = Box::into_raw(Box::new(export_batches(batches)));
+    let reader = unsafe { ArrowArrayStreamReader::from_raw(raw) }.unwrap();
+    drop(unsafe { Box::from_raw(raw) });
+    let rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+    assert_eq!(rows, 5);
+
+    // Empty streams still carry the schema.
+    let stream = export_batches(Vec::<TypedBatch<Track>>::new());
+    assert_eq!(
+        TypedArrowStreamReader::<Track>::try_new(stream)
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn stream_schema_is_checked_up_front() {
+    let stream = export_rows(
+        [Other {
+            id: 1,
+            label: "x".into(),
+        }],
+        BatchWriter::new(8),
+    );
+    assert!(matches!(
+        TypedArrowStreamReader::<Track>::try_new(stream),
+        Err(FfiError::Schema(_))
+    ));
+}
@@ -0,0 +1,144 @@
+use std::{io::Cursor, sync::Arc};
+
+use typed_arrow::{
+    List,
+    arrow_array::{Int64Array, RecordBatch, StringArray},
+    batch_writer::BatchWriter,
+    error::{IpcError, SchemaError},
+    io::ipc::{TypedIpcReader, TypedIpcWriter},
+    prelude::*,
+    schema::SchemaMeta,
+};
+
+#[derive(Record)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Record)]
+struct Spill {
+    id: i64,
+    name: String,
+    score: Option<f32>,
+    tags: List<String>,
+}
+
+#[derive(Record)]
+struct Other {
+    id: i64,
+    at: Option<Point>,
+}
+
+fn spill(id: i64) -> Spill {
+    Spill {
+        id,
+        name: format!("s{id}"),
+        score: (id % 2 == 0).then_some(id as f32),
+        tags: List::new((0..id % 3).map(|t| format!("t{t}")).collect()),
+    }
+}
+
+fn batch(ids: std::ops::Range<i64>) -> RecordBatch {
+    let mut b = <Spill as BuildRows>::new_builders(0);
+    b.append_rows(ids.map(spill));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn file_round_trip_mixes_rows_and_batches() {
+    let mut writer = TypedIpcWriter::<Spill, _>::try_new_file(Vec::new())
+        .unwrap()
+        .with_batch_writer(BatchWriter::new(4));
+    writer.write_rows((0..6).map(spill)).unwrap();
+    assert_eq!(writer.pending_rows(), 2);
+    // Pending rows are written before the batch so row order is preserved.
+    writer.write_batch(&batch(6..9)).unwrap();
+    writer.write_row(spill(9)).unwrap();
+    let bytes = writer.finish().unwrap();
+
+    let reader = TypedIpcReader::<Spill, _>::try_new_file(Cursor::new(bytes.clone())).unwrap();
+    assert_eq!(reader.schema(), Spill::schema());
+    let sizes: Vec<usize> = reader.map(|b| b.unwrap().len()).collect();
+    assert_eq!(sizes, vec![4, 2, 3, 1]);
+
+    let rows: Vec<Spill> = TypedIpcReader::<Spill, _>::try_new_file(Cursor::new(bytes))
+        .unwrap()
+        .into_records()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows.len(), 10);
+    for (row, expected) in rows.iter().zip((0..10).map(spill)) {
+        assert_eq!(row.id, expected.id);
+        assert_eq!(row.name, expected.name);
+        assert_eq!(row.score, expected.score);
+        assert_eq!(row.tags.values(), expected.tags.values());
+    }
+}
+
+#[test]
+fn stream_round_trip_yields_views() {
+    let mut writer = TypedIpcWriter::<Spill, _>::try_new_stream(Vec::new()).unwrap();
+    writer.write_rows((0..5).map(spill)).unwrap();
+    let bytes = writer.finish().unwrap();
+
+    let mut names = Vec::new();
+    for batch in TypedIpcReader::<Spill, _>::try_new_stream(bytes.as_slice()).unwrap() {
+        for view in batch.unwrap().iter_views() {
+            names.push(view.unwrap().name.to_string());
+        }
+    }
+    assert_eq!(names, vec!["s0", "s1", "s2", "s3", "s4"]);
+}
+
+#[test]
+fn mismatched_file_schema_is_rejected_on_open() {
+    let mut writer = TypedIpcWriter::<Other, _>::try_new_file(Vec::new()).unwrap();
+    writer
+        .write_row(Other {
+            id: 1,
+            at: Some(Point { x: 0.0, y: 1.0 }),
+        })
+        .unwrap();
+    let bytes = writer.finish().unwrap();
+
+    match TypedIpcReader::<Spill, _>::try_new_file(Cursor::new(bytes)) {
+        Err(IpcError::Schema(SchemaError::InvalidSchema { message })) => {
+            assert!(message.contains("Column count mismatch"), "{message}");
+        }
+        Err(other) => panic!("expected a schema error, got {other}"),
+        Ok(_) => panic!("expected a schema error"),
+    }
+}
+
+#[test]
+fn batches_that_break_the_record_are_not_written() {
+    #[derive(Record)]
+    struct Pair {
+        id: i64,
+        name: String,
+    }
+
+    let mut writer = TypedIpcWriter::<Pair, _>::try_new_stream(Vec::new()).unwrap();
+    let with_null = RecordBatch::try_from_iter([
+        ("id", Arc::new(Int64Array::from(vec![1, 2])) as _),
+        (
+            "name",
+            Arc::new(StringArray::from(vec![Some("a"), None])) as _,
+        ),
+    ])
+    .unwrap();
+    let err = writer.write_batch(&with_null).unwrap_err();
+    assert!(
+        matches!(err, IpcError::Schema(SchemaError::Nullability { .. })),
+        "{err}"
+    );
+
+    writer.write_batch(&with_null.slice(0, 1)).unwrap();
+    let bytes = writer.finish().unwrap();
+    let rows: usize = TypedIpcReader::<Pair, _>::try_new_stream(bytes.as_slice())
+        .unwrap()
+        .map(|b| b.unwrap().len())
+        .sum();
+    assert_eq!(rows, 1);
+}
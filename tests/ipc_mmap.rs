@@ -0,0 +1,129 @@
+use typed_arrow::{
+    Dictionary,
+    arrow_array::{Array, Int64Array},
+    arrow_buffer::Buffer,
+    error::IpcError,
+    io::ipc::{MappedIpcFile, TypedIpcWriter},
+    prelude::*,
+};
+
+#[derive(Record)]
+struct Trade {
+    id: i64,
+    venue: String,
+    price: Option<f64>,
+}
+
+#[derive(Record)]
+struct Tagged {
+    id: i64,
+    venue: Dictionary<i32, String>,
+}
+
+#[derive(Record)]
+struct Other {
+    id: i32,
+}
+
+fn trade(id: i64) -> Trade {
+    Trade {
+        id,
+        venue: ["xnas", "xnys", "bats"][id as usize % 3].to_string(),
+        price: (id % 4 != 0).then_some(id as f64 * 1.25),
+    }
+}
+
+fn file_bytes(n: i64) -> Vec<u8> {
+    let mut writer = TypedIpcWriter::<Trade, _>::try_new_file(Vec::new())
+        .unwrap()
+        .with_batch_writer(typed_arrow::batch_writer::BatchWriter::new(100));
+    writer.write_rows((0..n).map(trade)).unwrap();
+    writer.finish().unwrap()
+}
+
+#[test]
+fn decodes_without_copying_the_buffer() {
+    let buffer = Buffer::from_vec(file_bytes(250));
+    let range = buffer.as_ptr() as usize..buffer.as_ptr() as usize + buffer.len();
+    let file = MappedIpcFile::<Trade>::try_from_buffer(buffer).unwrap();
+
+    assert_eq!(file.len(), 250);
+    assert_eq!(file.table().num_batches(), 3);
+    for batch in file.table().batches() {
+        let ids = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert!(range.contains(&(ids.values().as_ptr() as usize)));
+    }
+
+    let mut seen = 0;
+    for row in file.iter_views() {
+        let (_, view) = row.unwrap();
+        assert_eq!(view.venue, ["xnas", "xnys", "bats"][view.id as usize % 3]);
+        assert_eq!(view.price.is_none(), view.id % 4 == 0);
+        seen += 1;
+    }
+    assert_eq!(seen, 250);
+}
+
+#[test]
+fn memory_maps_a_file() {
+    let path = std::env::temp_dir().join(format!("typed_arrow_mmap_{}.arrow", std::process::id()));
+    std::fs::write(&path, file_bytes(42)).unwrap();
+
+    // SAFETY: the test owns the file and does not modify it while mapped.
+    let file = unsafe { MappedIpcFile::<Trade>::open(&path) }.unwrap();
+    assert_eq!(file.schema().fields().len(), 3);
+    assert_eq!(file.table().row(41).unwrap().id, 41);
+
+    let table = file.into_table();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(table.len(), 42);
+}
+
+#[test]
+fn reads_dictionary_blocks() {
+    // The file format allows one dictionary per field, so this is a single batch.
+    let mut writer = TypedIpcWriter::<Tagged, _>::try_new_file(Vec::new()).unwrap();
+    writer
+        .write_rows((0..10).map(|id| Tagged {
+            id,
+            venue: Dictionary::new(format!("v{}", id % 3)),
+        }))
+        .unwrap();
+    let file = MappedIpcFile::<Tagged>::try_from_buffer(Buffer::from_vec(writer.finish().unwrap()))
+        .unwrap();
+    let venues: Vec<String> = file
+        .iter_views()
+        .map(|r| r.unwrap().1.venue.to_string())
+        .collect();
+    assert_eq!(venues[..4], ["v0", "v1", "v2", "v0"]);
+}
+
+#[test]
+fn rejects_mismatched_or_truncated_files() {
+    let bytes = file_bytes(5);
+    match MappedIpcFile::<Other>::try_from_buffer(Buffer::from_vec(bytes.clone())) {
+        Err(IpcError::Schema(_)) => {}
+        Err(other) => panic!("expected a schema error, got {other}"),
+        Ok(_) => panic!("expected a schema error"),
+    }
+
+    let truncated = Buffer::from_vec(bytes[..bytes.len() - 4].to_vec());
+    assert!(matches!(
+        MappedIpcFile::<Trade>::try_from_buffer(truncated),
+        Err(IpcError::Arrow(_))
+    ));
+    assert!(matches!(
+        MappedIpcFile::<Trade>::try_from_buffer(Buffer::from_vec(vec![0u8; 4])),
+        Err(IpcError::Arrow(_))
+    ));
+
+    // The streaming format has no footer.
+    let mut writer = TypedIpcWriter::<Trade, _>::try_new_stream(Vec::new()).unwrap();
+    writer.write_row(trade(1)).unwrap();
+    let stream = writer.finish().unwrap();
+    assert!(MappedIpcFile::<Trade>::try_from_buffer(Buffer::from_vec(stream)).is_err());
+}
@@ -0,0 +1,219 @@
+use typed_arrow::{
+    Dictionary, List, Map, Millisecond, TimestampTz, Utc,
+    error::JsonError,
+    io::json::{TypedJsonReader, TypedJsonReaderBuilder, TypedJsonWriter},
+    prelude::*,
+};
+
+#[derive(Record)]
+struct Device {
+    model: String,
+    #[record(name = "fw")]
+    firmware: Option<i32>,
+}
+
+#[derive(Union)]
+enum Payload {
+    Click(i32),
+    #[union(field = "text")]
+    Typed(String),
+}
+
+#[derive(Union)]
+#[union(mode = "sparse")]
+enum Score {
+    Int(i64),
+    Float(f64),
+}
+
+#[derive(Record)]
+struct Event {
+    #[record(name = "event_id")]
+    id: i64,
+    kind: Dictionary<i32, String>,
+    at: TimestampTz<Millisecond, Utc>,
+    device: Option<Device>,
+    payload: Payload,
+    score: Score,
+    history: List<Payload>,
+    counts: Map<i32, String>,
+    tags: Option<List<String>>,
+}
+
+fn event(id: i64) -> Event {
+    Event {
+        id,
+        kind: Dictionary::new(if id % 2 == 0 { "click" } else { "key" }.into()),
+        at: TimestampTz::new(1_700_000_000_000 + id),
+        device: (id % 3 != 0).then(|| Device {
+            model: format!("m{id}"),
+            firmware: (id % 2 == 1).then_some(id as i32),
+        }),
+        payload: if id % 2 == 0 {
+            Payload::Click(id as i32)
+        } else {
+            Payload::Typed(format!("t{id}"))
+        },
+        score: if id % 2 == 0 {
+            Score::Int(id)
+        } else {
+            Score::Float(id as f64 / 2.0)
+        },
+        history: List::new(vec![Payload::Click(1), Payload::Typed(format!("h{id}"))]),
+        counts: Map::new(vec![(id as i32, format!("c{id}"))]),
+        tags: (id % 2 == 0).then(|| List::new(vec!["a".into(), "b".into()])),
+    }
+}
+
+#[test]
+fn round_trips_nested_structs_lists_maps_and_unions() {
+    let mut writer = TypedJsonWriter::<Event, _>::try_new(Vec::new())
+        .unwrap()
+        .with_batch_writer(typed_arrow::batch_writer::BatchWriter::new(4));
+    writer.write_rows((0..10).map(event)).unwrap();
+    let bytes = writer.finish().unwrap();
+    let text = String::from_utf8(bytes.clone()).unwrap();
+    assert_eq!(text.lines().count(), 10);
+    assert_eq!(
+        text.lines().nth(1).unwrap(),
+        "{\"event_id\":1,\"kind\":\"key\",\"at\":\"2023-11-14T22:13:20.001Z\",\
+         \"device\":{\"model\":\"m1\",\"fw\":1},\"payload\":{\"text\":\"t1\"},\
+         \"score\":{\"Float\":0.5},\
+         \"history\":[{\"Click\":1},{\"text\":\"h1\"}],\"counts\":{\"1\":\"c1\"}}"
+    );
+
+    let back: Vec<Event> = TypedJsonReaderBuilder::<Event>::new()
+        .with_batch_size(3)
+        .build(bytes.as_slice())
+        .unwrap()
+        .into_records()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back.len(), 10);
+    for (row, expected) in back.iter().zip((0..10).map(event)) {
+        assert_eq!(row.id, expected.id);
+        assert_eq!(row.kind.value(), expected.kind.value());
+        assert_eq!(row.at.value(), expected.at.value());
+        assert_eq!(
+            row.device.as_ref().map(|d| (&d.model, d.firmware)),
+            expected.device.as_ref().map(|d| (&d.model, d.firmware))
+        );
+        match (&row.payload, &expected.payload) {
+            (Payload::Click(a), Payload::Click(b)) => assert_eq!(a, b),
+            (Payload::Typed(a), Payload::Typed(b)) => assert_eq!(a, b),
+            _ => panic!("payload variant changed for row {}", row.id),
+        }
+        match (&row.score, &expected.score) {
+            (Score::Int(a), Score::Int(b)) => assert_eq!(a, b),
+            (Score::Float(a), Score::Float(b)) => assert_eq!(a, b),
+            _ => panic!("score variant changed for row {}", row.id),
+        }
+        assert!(matches!(
+            &row.history.values()[..],
+            [Payload::Click(1), Payload::Typed(h)] if *h == format!("h{}", row.id)
+        ));
+        assert_eq!(row.counts.entries(), expected.counts.entries());
+        assert_eq!(
+            row.tags.as_ref().map(|t| t.values().clone()),
+            expected.tags.map(List::into_inner)
+        );
+    }
+
+    // Writing what was read produces the same text.
+    let mut again = TypedJsonWriter::<Event, _>::try_new(Vec::new()).unwrap();
+    again.write_rows(back).unwrap();
+    assert_eq!(again.finish().unwrap(), bytes);
+}
+
+#[test]
+fn views_and_batches_write_like_rows() {
+    let mut writer = TypedJsonWriter::<Event, _>::try_new(Vec::new()).unwrap();
+    writer.write_rows((0..4).map(event)).unwrap();
+    let expected = writer.finish().unwrap();
+
+    let batch = TypedJsonReader::<Event, _>::try_new(expected.as_slice())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let mut writer = TypedJsonWriter::<Event, _>::try_new(Vec::new()).unwrap();
+    for view in batch.iter_views().take(2) {
+        writer.write_view(view.unwrap()).unwrap();
+    }
+    writer
+        .write_batch(&batch.as_record_batch().slice(2, 2))
+        .unwrap();
+    assert_eq!(writer.finish().unwrap(), expected);
+}
+
+#[test]
+fn decoder_accepts_rows_split_across_chunks() {
+    #[derive(Record)]
+    struct Hit {
+        path: String,
+        status: Option<u16>,
+    }
+
+    let body = b"{\"path\":\"/a\",\"status\":200}\n{\"path\":\"/b\"}\n{\"pa";
+    let rest = b"th\":\"/c\",\"status\":404}\n";
+    let mut decoder = TypedJsonReaderBuilder::<Hit>::new()
+        .build_decoder()
+        .unwrap();
+    assert_eq!(decoder.decode(body).unwrap(), body.len());
+    assert_eq!(decoder.decode(rest).unwrap(), rest.len());
+    assert_eq!(decoder.len(), 3);
+    let batch = decoder.flush().unwrap().unwrap();
+    let hits: Vec<(String, Option<u16>)> = batch
+        .iter_views()
+        .map(|view| {
+            let view = view.unwrap();
+            (view.path.to_owned(), view.status)
+        })
+        .collect();
+    assert_eq!(
+        hits,
+        vec![
+            ("/a".into(), Some(200)),
+            ("/b".into(), None),
+            ("/c".into(), Some(404))
+        ]
+    );
+    assert!(decoder.flush().unwrap().is_none());
+}
+
+#[test]
+fn rejects_ambiguous_unions_missing_columns_and_unknown_keys() {
+    #[derive(Record)]
+    struct Row {
+        payload: Payload,
+        n: i32,
+    }
+
+    let read = |text: &str, strict: bool| {
+        TypedJsonReaderBuilder::<Row>::new()
+            .with_strict_mode(strict)
+            .build(text.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+    };
+
+    let ok = read(
+        "{\"payload\":{\"Click\":1},\"n\":1,\"extra\":true}\n",
+        false,
+    )
+    .unwrap();
+    assert_eq!(ok.len(), 1);
+    assert!(matches!(
+        read("{\"payload\":{\"Click\":1},\"n\":1,\"extra\":true}\n", true),
+        Err(JsonError::Arrow(_))
+    ));
+    assert!(matches!(
+        read("{\"payload\":{\"Click\":1,\"text\":\"x\"},\"n\":1}\n", false),
+        Err(JsonError::Arrow(e)) if e.to_string().contains("sets both `Click` and `text`")
+    ));
+    assert!(matches!(
+        read("{\"payload\":{\"Click\":1}}\n", false),
+        Err(JsonError::Arrow(_))
+    ));
+}
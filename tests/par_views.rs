@@ -0,0 +1,118 @@
+use rayon::prelude::*;
+use typed_arrow::{List, par::AsParViewsIterator, prelude::*};
+
+#[derive(Record, Debug, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Record, Debug)]
+pub struct Row {
+    pub id: i64,
+    pub name: Option<String>,
+    pub pos: Point,
+    pub tags: List<String>,
+}
+
+fn rows(n: i64) -> Vec<Row> {
+    (0..n)
+        .map(|id| Row {
+            id,
+            name: (id % 3 != 0).then(|| format!("n{id}")),
+            pos: Point {
+                x: id as i32,
+                y: -(id as i32),
+            },
+            tags: List::new((0..id % 4).map(|t| format!("t{t}")).collect()),
+        })
+        .collect()
+}
+
+type Key = (i64, Option<String>, i32, Vec<String>);
+
+fn key(r: Row) -> Key {
+    (r.id, r.name, r.pos.y, r.tags.into_inner())
+}
+
+fn batch(n: i64) -> arrow_array::RecordBatch {
+    let mut b = <Row as BuildRows>::new_builders(n as usize);
+    b.append_rows(rows(n));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn parallel_views_match_sequential_order() {
+    let batch = batch(10_000);
+    let seq: Vec<i64> = batch
+        .iter_views::<Row>()
+        .unwrap()
+        .map(|r| r.unwrap().id)
+        .collect();
+    let par: Vec<i64> = batch
+        .par_iter_views::<Row>()
+        .unwrap()
+        .with_min_len(7)
+        .map(|r| r.unwrap().id)
+        .collect();
+    assert_eq!(seq, par);
+
+    let views = batch.par_iter_views::<Row>().unwrap();
+    assert_eq!(views.len(), 10_000);
+    let (idx, last) = views
+        .enumerate()
+        .map(|(i, r)| (i, r.unwrap()))
+        .max_by_key(|(i, _)| *i)
+        .unwrap();
+    assert_eq!(idx, 9_999);
+    assert_eq!((last.id, last.name), (9_999, None));
+}
+
+#[test]
+fn parallel_conversion_to_owned_records() {
+    let batch = batch(2_000);
+    let owned: Vec<Row> = batch
+        .par_iter_views::<Row>()
+        .unwrap()
+        .try_into_records()
+        .unwrap();
+    let expected: Vec<Key> = rows(2_000).into_iter().map(key).collect();
+    assert_eq!(owned.into_iter().map(key).collect::<Vec<_>>(), expected);
+
+    let typed = TypedBatch::<Row>::try_new(batch).unwrap();
+    let sum: i64 = typed
+        .par_iter_views()
+        .map(|r| r.map(|v| v.pos.x as i64))
+        .sum::<Result<i64, _>>()
+        .unwrap();
+    assert_eq!(sum, (0..2_000).sum::<i64>());
+}
+
+#[test]
+fn sliced_views_and_empty_batches() {
+    let batch = batch(100);
+    let typed = TypedBatch::<Row>::try_new(batch).unwrap();
+    let sliced = typed.iter_views().slice(10..20);
+    let ids: Vec<i64> = typed_arrow::par::ParViews::new(sliced)
+        .map(|r| r.unwrap().id)
+        .collect();
+    assert_eq!(ids, (10..20).collect::<Vec<_>>());
+
+    let empty = self::batch(0);
+    assert_eq!(empty.par_iter_views::<Row>().unwrap().count(), 0);
+    let owned: Vec<Row> = empty
+        .par_iter_views::<Row>()
+        .unwrap()
+        .try_into_records()
+        .unwrap();
+    assert!(owned.is_empty());
+}
+
+#[test]
+fn schema_mismatch_is_reported_up_front() {
+    #[derive(Record)]
+    pub struct Other {
+        pub id: String,
+    }
+    assert!(batch(3).par_iter_views::<Other>().is_err());
+}
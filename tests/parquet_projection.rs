@@ -0,0 +1,65 @@
+use std::fs::File;
+
+use typed_arrow::{
+    io::parquet::projection_mask,
+    parquet::arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
+    prelude::*,
+};
+
+#[derive(Record)]
+pub struct Wide {
+    pub id: i64,
+    pub name: String,
+    pub score: f64,
+    pub ts: i64,
+}
+
+#[derive(Record)]
+#[record(project_of = Wide)]
+pub struct TsId {
+    pub ts: i64,
+    pub id: i64,
+}
+
+#[test]
+fn projection_mask_reads_only_projected_columns() {
+    let mut b = <Wide as BuildRows>::new_builders(3);
+    b.append_rows((0..3).map(|i| Wide {
+        id: i,
+        name: format!("n{i}"),
+        score: i as f64,
+        ts: i * 10,
+    }));
+    let batch = b.finish().into_record_batch();
+
+    let path = std::env::temp_dir().join(format!(
+        "typed_arrow_parquet_projection_{}.parquet",
+        std::process::id()
+    ));
+    let mut writer =
+        ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+    let mask = projection_mask::<TsId>(builder.parquet_schema()).unwrap();
+    let batches: Vec<_> = builder
+        .with_projection(mask)
+        .build()
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    std::fs::remove_file(&path).ok();
+
+    let read = &batches[0];
+    assert_eq!(read.num_columns(), 2);
+    let rows: Vec<(i64, i64)> = read
+        .iter_views::<TsId>()
+        .unwrap()
+        .try_flatten()
+        .unwrap()
+        .into_iter()
+        .map(|v| (v.ts, v.id))
+        .collect();
+    assert_eq!(rows, vec![(0, 0), (10, 1), (20, 2)]);
+}
@@ -0,0 +1,172 @@
+use std::{fs::File, path::PathBuf};
+
+use typed_arrow::{
+    Millisecond, Timestamp,
+    io::parquet::{ColumnPredicateExt, TypedParquetReaderBuilder, TypedParquetWriter},
+    parquet::file::properties::WriterProperties,
+    prelude::*,
+};
+
+const DAY: i64 = 86_400_000;
+
+fn ms(value: i64) -> Timestamp<Millisecond> {
+    Timestamp::new(value)
+}
+
+#[derive(Record)]
+struct Event {
+    ts: Timestamp<Millisecond>,
+    kind: String,
+    score: Option<i64>,
+}
+
+#[derive(Record)]
+#[record(project_of = Event)]
+struct Kind {
+    kind: String,
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "typed_arrow_parquet_pruning_{name}_{}.parquet",
+        std::process::id()
+    ))
+}
+
+/// One row group per day with 100 events each. Day 2 has no scores and its kinds
+/// are `"late"`; the other days use `"k0"`..`"k2"`.
+fn write_days(name: &str, days: i64, properties: WriterProperties) -> PathBuf {
+    let path = temp_path(name);
+    let mut writer = TypedParquetWriter::<Event, _>::try_new_with_properties(
+        File::create(&path).unwrap(),
+        properties.into_builder(),
+    )
+    .unwrap();
+    for day in 0..days {
+        writer
+            .write_rows((0..100).map(|i| Event {
+                ts: ms(day * DAY + i * 1000),
+                kind: if day == 2 {
+                    "late".into()
+                } else {
+                    format!("k{}", i % 3)
+                },
+                score: (day != 2).then_some(day * 100 + i),
+            }))
+            .unwrap();
+        writer.flush_row_group().unwrap();
+    }
+    writer.finish().unwrap();
+    path
+}
+
+fn read_ts(builder: TypedParquetReaderBuilder<Event, File>) -> Vec<i64> {
+    let mut out = Vec::new();
+    for batch in builder.build().unwrap() {
+        out.extend(batch.unwrap().columns().ts.values().iter().copied());
+    }
+    out
+}
+
+fn open(path: &PathBuf) -> TypedParquetReaderBuilder<Event, File> {
+    TypedParquetReaderBuilder::try_new(File::open(path).unwrap()).unwrap()
+}
+
+#[test]
+fn between_skips_row_groups_outside_the_range() {
+    let path = write_days("between", 4, WriterProperties::builder().build());
+    assert_eq!(open(&path).metadata().num_row_groups(), 4);
+
+    let ts = read_ts(
+        open(&path).with_predicate(EventColumn::TS.between(ms(DAY + 5_000), ms(DAY + 10_000))),
+    );
+    assert_eq!(ts.len(), 100);
+    assert!(ts.iter().all(|&t| (DAY..2 * DAY).contains(&t)));
+
+    // A range spanning two row groups keeps both; one past the data keeps none.
+    let spanning =
+        open(&path).with_predicate(EventColumn::TS.between(ms(DAY + 50_000), ms(3 * DAY)));
+    assert_eq!(read_ts(spanning).len(), 300);
+    let after = open(&path).with_predicate(EventColumn::TS.eq(ms(9 * DAY)));
+    assert!(read_ts(after).is_empty());
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn eq_and_in_set_on_strings() {
+    let path = write_days("strings", 4, WriterProperties::builder().build());
+
+    let late = read_ts(open(&path).with_predicate(EventColumn::KIND.eq("late".to_string())));
+    assert_eq!(late.len(), 100);
+    assert!(late.iter().all(|&t| t / DAY == 2));
+
+    let set = open(&path).with_predicate(EventColumn::KIND.in_set([
+        "a".to_string(),
+        "late".to_string(),
+        "zz".to_string(),
+    ]));
+    assert_eq!(read_ts(set).len(), 100);
+
+    let none = open(&path).with_predicate(EventColumn::KIND.in_set(Vec::<String>::new()));
+    assert!(read_ts(none).is_empty());
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn predicates_combine_and_respect_chosen_row_groups() {
+    let path = write_days("combine", 4, WriterProperties::builder().build());
+
+    // Scores are null on day 2, so a predicate on them never matches it.
+    let scores = open(&path).with_predicate(EventColumn::SCORE.between(0_i64, 1_000));
+    let days: Vec<i64> = read_ts(scores).iter().map(|t| t / DAY).collect();
+    assert_eq!(days.len(), 300);
+    assert!(!days.contains(&2));
+
+    let both = open(&path)
+        .with_predicate(EventColumn::SCORE.between(0_i64, 1_000))
+        .with_predicate(EventColumn::TS.between(ms(0), ms(2 * DAY)));
+    assert_eq!(read_ts(both).len(), 200);
+
+    let chosen = open(&path)
+        .with_row_groups(vec![3, 0])
+        .with_predicate(EventColumn::SCORE.between(0_i64, 1_000));
+    let first: Vec<i64> = read_ts(chosen).iter().map(|t| t / DAY).collect();
+    assert_eq!((first[0], first[100], first.len()), (3, 0, 200));
+
+    // Predicates may name columns that a projection drops.
+    let kinds: usize = open(&path)
+        .with_predicate(EventColumn::TS.between(ms(3 * DAY), ms(4 * DAY)))
+        .project::<Kind>()
+        .unwrap()
+        .build()
+        .unwrap()
+        .map(|batch| batch.unwrap().len())
+        .sum();
+    assert_eq!(kinds, 100);
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn page_index_skips_pages_within_a_row_group() {
+    let properties = WriterProperties::builder()
+        .set_data_page_row_count_limit(10)
+        .set_write_batch_size(10)
+        .build();
+    let path = write_days("pages", 1, properties);
+    let builder = open(&path);
+    assert!(builder.metadata().offset_index().is_some());
+
+    let ts = read_ts(builder.with_predicate(EventColumn::TS.between(ms(42_000), ms(47_000))));
+    assert_eq!(ts, (40..50).map(|i| i * 1000).collect::<Vec<_>>());
+
+    // Without the page index only row groups are pruned.
+    let without = TypedParquetReaderBuilder::<Event, _>::from_builder(
+        typed_arrow::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+            File::open(&path).unwrap(),
+        )
+        .unwrap(),
+    )
+    .with_predicate(EventColumn::TS.between(ms(42_000), ms(47_000)));
+    assert_eq!(read_ts(without).len(), 100);
+    std::fs::remove_file(&path).ok();
+}
@@ -0,0 +1,214 @@
+use std::{fs::File, path::PathBuf};
+
+use typed_arrow::{
+    List,
+    error::ParquetError,
+    io::parquet::{TypedParquetReaderBuilder, TypedParquetWriter, writer_properties},
+    parquet::{basic::Encoding, file::properties::WriterProperties},
+    prelude::*,
+    schema::{ParquetColumnHints, SchemaMeta},
+};
+
+#[derive(Record)]
+struct Location {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Record)]
+struct Reading {
+    #[record(parquet(encoding = "delta_binary_packed"))]
+    ts: i64,
+    #[record(parquet(dictionary = false, bloom_filter = true))]
+    sensor: String,
+    #[record(parquet(statistics = "none"))]
+    note: Option<String>,
+    #[record(parquet(encoding = "byte_stream_split"))]
+    at: Option<Location>,
+    #[record(name = "Samples", parquet(encoding = "delta_binary_packed"))]
+    samples: List<i32>,
+}
+
+#[derive(Record)]
+#[record(project_of = Reading)]
+struct SensorTs {
+    sensor: String,
+    ts: i64,
+}
+
+/// Projects a column whose Arrow name differs from its field name.
+#[derive(Record)]
+#[record(project_of = Reading)]
+struct Samples {
+    samples: List<i32>,
+}
+
+fn reading(ts: i64) -> Reading {
+    Reading {
+        ts,
+        sensor: format!("s{}", ts % 5),
+        note: (ts % 3 == 0).then(|| format!("n{ts}")),
+        at: (ts % 2 == 0).then_some(Location {
+            lat: ts as f64,
+            lon: -(ts as f64),
+        }),
+        samples: List::new((0..(ts % 4) as i32).collect()),
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "typed_arrow_parquet_typed_{name}_{}.parquet",
+        std::process::id()
+    ))
+}
+
+fn write_file(name: &str, n: i64) -> PathBuf {
+    let path = temp_path(name);
+    let mut writer =
+        TypedParquetWriter::<Reading, _>::try_new(File::create(&path).unwrap()).unwrap();
+    writer.write_rows((0..n).map(reading)).unwrap();
+    writer.finish().unwrap();
+    path
+}
+
+#[test]
+fn derive_collects_hints_by_arrow_name() {
+    let hints = Reading::parquet_hints();
+    let names: Vec<&str> = hints.iter().map(|(n, _)| *n).collect();
+    assert_eq!(names, vec!["ts", "sensor", "note", "at", "Samples"]);
+    assert_eq!(
+        hints[1].1,
+        ParquetColumnHints {
+            dictionary: Some(false),
+            bloom_filter: Some(true),
+            ..Default::default()
+        }
+    );
+    assert!(Location::parquet_hints().is_empty());
+}
+
+#[test]
+fn hints_reach_the_column_chunks() {
+    let path = write_file("hints", 50);
+    let builder =
+        TypedParquetReaderBuilder::<Reading, _>::try_new(File::open(&path).unwrap()).unwrap();
+    let row_group = builder.metadata().row_group(0);
+    let column = |path: &str| {
+        row_group
+            .columns()
+            .iter()
+            .find(|c| c.column_path().string() == path)
+            .unwrap_or_else(|| panic!("no column {path}"))
+    };
+    let encodings = |path: &str| column(path).encodings().collect::<Vec<_>>();
+
+    assert!(encodings("ts").contains(&Encoding::DELTA_BINARY_PACKED));
+    assert!(!encodings("sensor").contains(&Encoding::RLE_DICTIONARY));
+    assert!(column("sensor").bloom_filter_offset().is_some());
+    assert!(column("ts").bloom_filter_offset().is_none());
+    assert!(column("note").statistics().is_none());
+    assert!(column("ts").statistics().is_some());
+    // Hints on nested and list fields apply to every leaf under them.
+    assert!(encodings("at.lat").contains(&Encoding::BYTE_STREAM_SPLIT));
+    assert!(encodings("at.lon").contains(&Encoding::BYTE_STREAM_SPLIT));
+    assert!(encodings("Samples.list.item").contains(&Encoding::DELTA_BINARY_PACKED));
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn round_trips_records_and_projections() {
+    let path = write_file("round_trip", 30);
+
+    let rows: Vec<Reading> =
+        TypedParquetReaderBuilder::<Reading, _>::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .with_batch_size(8)
+            .build()
+            .unwrap()
+            .into_records()
+            .collect::<Result<_, _>>()
+            .unwrap();
+    assert_eq!(rows.len(), 30);
+    for (row, expected) in rows.iter().zip((0..30).map(reading)) {
+        assert_eq!(row.ts, expected.ts);
+        assert_eq!(row.note, expected.note);
+        assert_eq!(row.at.as_ref().map(|l| l.lat), expected.at.map(|l| l.lat));
+        assert_eq!(row.samples.values(), expected.samples.values());
+    }
+
+    // The projection lists its columns in a different order than the file.
+    let reader = TypedParquetReaderBuilder::<Reading, _>::try_new(File::open(&path).unwrap())
+        .unwrap()
+        .project::<SensorTs>()
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(reader.schema().fields().len(), 2);
+    let mut pairs = Vec::new();
+    for batch in reader {
+        for view in batch.unwrap().iter_views() {
+            let view = view.unwrap();
+            pairs.push((view.sensor.to_string(), view.ts));
+        }
+    }
+    assert_eq!(pairs[7], ("s2".to_string(), 7));
+
+    let reader = TypedParquetReaderBuilder::<Reading, _>::try_new(File::open(&path).unwrap())
+        .unwrap()
+        .project::<Samples>()
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(reader.schema().field(0).name(), "Samples");
+    let lens: Vec<usize> = reader
+        .flat_map(|batch| {
+            let batch = batch.unwrap();
+            batch
+                .iter_views()
+                .map(|v| v.unwrap().samples.len())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(lens[..4], [0, 1, 2, 3]);
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn mismatched_files_and_batches_are_rejected() {
+    #[derive(Record)]
+    struct Other {
+        ts: i64,
+        sensor: i32,
+    }
+
+    let path = write_file("mismatch", 3);
+    let err = TypedParquetReaderBuilder::<Other, _>::try_new(File::open(&path).unwrap())
+        .unwrap()
+        .build()
+        .err()
+        .expect("schema mismatch");
+    assert!(matches!(err, ParquetError::Schema(_)), "{err}");
+    std::fs::remove_file(&path).ok();
+
+    let mut b = <Other as BuildRows>::new_builders(1);
+    b.append_row(Other { ts: 1, sensor: 2 });
+    let other = b.finish().into_record_batch();
+    let mut writer = TypedParquetWriter::<Reading, _>::try_new(Vec::new()).unwrap();
+    assert!(matches!(
+        writer.write_batch(&other),
+        Err(ParquetError::Schema(_))
+    ));
+}
+
+#[test]
+fn invalid_compression_levels_fail_when_building_properties() {
+    #[derive(Record)]
+    struct Packed {
+        #[record(parquet(compression = "zstd(99)"))]
+        id: i64,
+    }
+
+    assert!(writer_properties::<Packed>(WriterProperties::builder()).is_err());
+    assert!(TypedParquetWriter::<Packed, _>::try_new(Vec::new()).is_err());
+}
@@ -0,0 +1,151 @@
+use typed_arrow::{
+    TypedBatch,
+    prelude::*,
+    schema::{Projection, SchemaMeta},
+    validate::validate_batch,
+};
+
+#[derive(Record)]
+pub struct Wide {
+    pub id: i64,
+    #[record(name = "displayName")]
+    pub display_name: Option<String>,
+    pub score: f64,
+    pub ts: i64,
+}
+
+#[derive(Record)]
+#[record(project_of = Wide)]
+pub struct TsId {
+    pub ts: i64,
+    pub id: i64,
+}
+
+#[derive(Record)]
+#[record(project_of = Wide)]
+pub struct Names {
+    pub display_name: Option<String>,
+}
+
+fn wide_batch() -> arrow_array::RecordBatch {
+    let mut b = <Wide as BuildRows>::new_builders(2);
+    b.append_row(Wide {
+        id: 1,
+        display_name: Some("one".into()),
+        score: 0.5,
+        ts: 100,
+    });
+    b.append_row(Wide {
+        id: 2,
+        display_name: None,
+        score: 1.5,
+        ts: 200,
+    });
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn source_metadata() {
+    assert_eq!(TsId::source_indices(), vec![3, 0]);
+    assert_eq!(TsId::source_names(), vec!["ts", "id"]);
+    assert_eq!(Names::source_names(), vec!["displayName"]);
+    assert_eq!(TsId::SOURCE_COLUMNS, &[WideColumn::Ts, WideColumn::Id]);
+}
+
+#[test]
+fn views_select_columns_by_name_from_wide_batch() {
+    let batch = wide_batch();
+    let rows: Vec<(i64, i64)> = batch
+        .iter_views::<TsId>()
+        .unwrap()
+        .try_flatten()
+        .unwrap()
+        .into_iter()
+        .map(|v| (v.ts, v.id))
+        .collect();
+    assert_eq!(rows, vec![(100, 1), (200, 2)]);
+
+    let names: Vec<Option<&str>> = batch
+        .iter_views::<Names>()
+        .unwrap()
+        .map(|v| v.unwrap().display_name)
+        .collect();
+    assert_eq!(names, vec![Some("one"), None]);
+}
+
+#[test]
+fn columns_select_by_name() {
+    let batch = wide_batch();
+    let cols = batch.columns_of::<TsId>().unwrap();
+    assert_eq!(cols.ts.values(), &[100, 200]);
+    assert_eq!(cols.id.values(), &[1, 2]);
+}
+
+#[test]
+fn projected_batch_is_also_accepted() {
+    let wide = wide_batch();
+    let narrow = wide
+        .project(&TsId::source_indices())
+        .expect("valid projection");
+    assert_eq!(narrow.schema().fields().len(), 2);
+    let first = narrow
+        .iter_views::<TsId>()
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!((first.ts, first.id), (100, 1));
+
+    // Columns missing from the batch are reported by name.
+    let only_id = wide.project(&[0]).unwrap();
+    assert!(matches!(
+        only_id.iter_views::<TsId>(),
+        Err(SchemaError::MissingField { field_name }) if field_name == "ts"
+    ));
+}
+
+#[test]
+fn projection_schema_matches_source_fields() {
+    fn check<P: Projection + SchemaMeta>()
+    where
+        P::Source: SchemaMeta,
+    {
+        let wide = <P::Source as SchemaMeta>::schema();
+        let proj = <P as SchemaMeta>::schema();
+        for (field, src) in proj.fields().iter().zip(P::source_indices()) {
+            assert_eq!(field.as_ref(), wide.field(src));
+        }
+    }
+    check::<TsId>();
+    check::<Names>();
+    assert_eq!(NamesColumn::DisplayName.name(), "displayName");
+}
+
+#[test]
+fn projected_batch_with_renamed_column_validates() {
+    let narrow = wide_batch()
+        .project(&Names::source_indices())
+        .expect("valid projection");
+    validate_batch::<Names>(&narrow).unwrap();
+    let typed = TypedBatch::<Names>::try_new(narrow).unwrap();
+    assert_eq!(typed.row(0).unwrap().display_name, Some("one"));
+}
+
+#[test]
+fn typed_batch_accepts_the_wide_batch_like_views_do() {
+    let wide = wide_batch();
+    validate_batch::<TsId>(&wide).unwrap();
+    let typed = TypedBatch::<TsId>::try_new(wide.clone()).unwrap();
+    assert_eq!(typed.as_record_batch().schema(), TsId::schema());
+    let first = typed.row(1).unwrap();
+    assert_eq!((first.ts, first.id), (200, 2));
+
+    // Reordered columns are found by name too; missing ones are still rejected.
+    let reordered = wide.project(&[0, 3]).unwrap();
+    TypedBatch::<TsId>::try_new(reordered).unwrap();
+    assert!(matches!(
+        validate_batch::<TsId>(&wide.project(&[0]).unwrap()),
+        Err(SchemaError::MissingField { field_name }) if field_name == "ts"
+    ));
+    assert!(validate_batch::<Wide>(&wide.project(&[0, 1, 2]).unwrap()).is_err());
+}
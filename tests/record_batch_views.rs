@@ -226,7 +226,7 @@ fn test_binary_record_batch_views() -> Result<(), SchemaError> {
 
     // Check third row with empty binary
     assert_eq!(collected[2].id, 3);
-    assert_eq!(collected[2].data, &[][..]);
+    assert_eq!(collected[2].data, &b""[..]);
     assert_eq!(collected[2].optional_data, Some(&[0x10][..]));
 
     Ok(())
@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use futures::{StreamExt, executor::block_on, stream};
+use typed_arrow::{
+    arrow_array::{Int32Array, RecordBatch},
+    batch_writer::BatchWriter,
+    error::StreamError,
+    prelude::*,
+    stream::{RecordBatchStreamExt, RowStreamExt},
+};
+
+#[derive(Record, Clone, Debug, PartialEq)]
+struct Event {
+    id: i64,
+    kind: String,
+    score: Option<f64>,
+}
+
+fn event(id: i64) -> Event {
+    Event {
+        id,
+        kind: format!("k{}", id % 3),
+        score: (id % 2 == 0).then_some(id as f64),
+    }
+}
+
+fn sizes(batches: &[RecordBatch]) -> Vec<usize> {
+    batches.iter().map(RecordBatch::num_rows).collect()
+}
+
+#[test]
+fn rows_are_batched_by_writer_caps() {
+    let rows = stream::iter((0..11).map(event));
+    let batches: Vec<_> = block_on(rows.record_batches(BatchWriter::new(5)).collect());
+    assert_eq!(sizes(&batches), vec![5, 5, 1]);
+
+    let mut three = <Event as BuildRows>::new_builders(0);
+    three.append_rows((0..3).map(event));
+    let writer = BatchWriter::new(100).with_max_bytes(three.memory_size());
+    let rows = stream::iter((0..7).map(event));
+    let batches: Vec<_> = block_on(rows.record_batches(writer).collect());
+    assert_eq!(sizes(&batches), vec![3, 3, 1]);
+
+    let empty = stream::iter(Vec::<Event>::new());
+    assert!(
+        block_on(
+            empty
+                .record_batches(BatchWriter::new(5))
+                .collect::<Vec<_>>()
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn max_delay_flushes_a_slow_source() {
+    // Three rows arrive quickly, then the source stalls well past the delay.
+    let rows = stream::unfold(0i64, |id| async move {
+        match id {
+            0..3 => Some((event(id), id + 1)),
+            3 => {
+                stall(Duration::from_millis(200)).await;
+                Some((event(id), id + 1))
+            }
+            _ => None,
+        }
+    });
+    let batches: Vec<_> = block_on(
+        rows.boxed()
+            .record_batches(BatchWriter::new(100))
+            .with_max_delay(Duration::from_millis(20))
+            .collect(),
+    );
+    assert_eq!(sizes(&batches), vec![3, 1]);
+}
+
+async fn stall(d: Duration) {
+    // Sleep on a helper thread so the executor sees a pending source.
+    let (tx, rx) = futures::channel::oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(d);
+        let _ = tx.send(());
+    });
+    let _ = rx.await;
+}
+
+#[test]
+fn batches_round_trip_to_owned_records() {
+    let expected: Vec<Event> = (0..9).map(event).collect();
+    let batches: Vec<_> = block_on(
+        stream::iter(expected.clone())
+            .record_batches(BatchWriter::new(4))
+            .collect(),
+    );
+    let back: Vec<Event> = block_on(
+        stream::iter(batches)
+            .records::<Event>()
+            .map(Result::unwrap)
+            .collect(),
+    );
+    assert_eq!(back, expected);
+}
+
+#[test]
+fn mismatched_batch_yields_one_error_and_is_skipped() {
+    let mut b = <Event as BuildRows>::new_builders(2);
+    b.append_rows((0..2).map(event));
+    let good = b.finish().into_record_batch();
+    let other = RecordBatch::try_from_iter([(
+        "id",
+        std::sync::Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+    )])
+    .unwrap();
+
+    let items: Vec<_> = block_on(
+        stream::iter(vec![good.clone(), other, good])
+            .records::<Event>()
+            .collect(),
+    );
+    assert_eq!(items.len(), 5);
+    assert!(matches!(items[2], Err(StreamError::Schema(_))));
+    let ids: Vec<i64> = items
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec![0, 1, 0, 1]);
+}
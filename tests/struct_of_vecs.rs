@@ -0,0 +1,104 @@
+use typed_arrow::{
+    List, Millisecond, Timestamp,
+    arrow_array::{Array, RecordBatch},
+    error::SchemaError,
+    prelude::*,
+};
+
+#[derive(Record)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Record)]
+struct Sample {
+    id: u64,
+    value: Option<f64>,
+    label: String,
+    at: Timestamp<Millisecond>,
+    pos: Option<Point>,
+    hist: List<i16>,
+}
+
+fn sample(i: u64) -> Sample {
+    Sample {
+        id: i,
+        value: (!i.is_multiple_of(3)).then_some(i as f64 / 2.0),
+        label: format!("s{i}"),
+        at: Timestamp::new(1_000 * i as i64),
+        pos: i.is_multiple_of(2).then_some(Point {
+            x: i as f32,
+            y: -(i as f32),
+        }),
+        hist: List::new(vec![i as i16; (i % 4) as usize]),
+    }
+}
+
+fn vecs(n: u64) -> SampleVecs {
+    let rows: Vec<Sample> = (0..n).map(sample).collect();
+    let mut v = SampleVecs {
+        id: Vec::new(),
+        value: Vec::new(),
+        label: Vec::new(),
+        at: Vec::new(),
+        pos: Vec::new(),
+        hist: Vec::new(),
+    };
+    for r in rows {
+        v.id.push(r.id);
+        v.value.push(r.value);
+        v.label.push(r.label);
+        v.at.push(r.at);
+        v.pos.push(r.pos);
+        v.hist.push(r.hist);
+    }
+    v
+}
+
+fn from_rows(n: u64) -> RecordBatch {
+    let mut b = <Sample as BuildRows>::new_builders(n as usize);
+    b.append_rows((0..n).map(sample));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn into_arrays_matches_row_building() {
+    let batch = vecs(10).into_arrays().unwrap().into_record_batch();
+    assert_eq!(batch, from_rows(10));
+
+    let empty = vecs(0).into_arrays().unwrap().into_record_batch();
+    assert_eq!(empty.num_rows(), 0);
+    assert_eq!(empty.schema(), from_rows(0).schema());
+}
+
+#[test]
+fn primitive_columns_reuse_the_vec_buffer() {
+    let v = vecs(64);
+    let id_ptr = v.id.as_ptr();
+    let arrays = v.into_arrays().unwrap();
+
+    assert_eq!(arrays.id.values().as_ptr(), id_ptr);
+    assert!(arrays.id.nulls().is_none());
+
+    assert_eq!(arrays.value.null_count(), 22);
+    assert!(arrays.value.is_null(3));
+    assert_eq!(arrays.value.value(4), 2.0);
+
+    let all_present = SampleVecs {
+        value: vec![Some(1.0); 64],
+        ..vecs(64)
+    };
+    assert!(all_present.into_arrays().unwrap().value.nulls().is_none());
+}
+
+#[test]
+fn mismatched_lengths_are_rejected() {
+    let mut v = vecs(3);
+    v.label.pop();
+    let err = match v.into_arrays() {
+        Err(SchemaError::InvalidSchema { message }) => message,
+        other => panic!("expected InvalidSchema, got {:?}", other.err()),
+    };
+    assert_eq!(err, "column `label` has 2 values, expected 3");
+}
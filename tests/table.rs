@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use typed_arrow::{
+    prelude::*,
+    table::{RowLocation, TypedTable, iter_batches_views, typed_batches},
+};
+
+#[derive(Record)]
+pub struct Event {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+fn batch(ids: std::ops::Range<i64>) -> RecordBatch {
+    let mut b = <Event as BuildRows>::new_builders(0);
+    b.append_rows(ids.map(|id| Event {
+        id,
+        name: (id % 2 == 0).then(|| format!("e{id}")),
+    }));
+    b.finish().into_record_batch()
+}
+
+fn wrong_batch() -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)]));
+    RecordBatch::try_new(
+        schema,
+        vec![Arc::new(StringArray::from(vec!["x"])) as ArrayRef],
+    )
+    .unwrap()
+}
+
+/// Matches `Event`'s column types but holds a null in the non-null `id`.
+fn null_id_batch() -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(vec![Some(1), None])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn views_across_batches_carry_locations() {
+    let batches = vec![batch(0..2), batch(2..2), batch(2..5)];
+    let rows = iter_batches_views::<Event>(&batches)
+        .unwrap()
+        .map(|r| r.map(|(loc, v)| (loc, v.id, v.name)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let locs: Vec<RowLocation> = rows.iter().map(|r| r.0).collect();
+    assert_eq!(
+        locs,
+        vec![
+            RowLocation { batch: 0, row: 0 },
+            RowLocation { batch: 0, row: 1 },
+            RowLocation { batch: 2, row: 0 },
+            RowLocation { batch: 2, row: 1 },
+            RowLocation { batch: 2, row: 2 },
+        ]
+    );
+    assert_eq!(
+        rows.iter().map(|r| r.1).collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4]
+    );
+    assert_eq!(rows[2].2, Some("e2"));
+    assert_eq!(rows[3].2, None);
+
+    assert!(iter_batches_views::<Event>(&[batch(0..1), wrong_batch()]).is_err());
+    // Batches get the same full validation as `TypedTable::try_new`.
+    assert!(matches!(
+        iter_batches_views::<Event>(&[null_id_batch()]),
+        Err(SchemaError::Nullability { .. })
+    ));
+    assert!(TypedTable::<Event>::try_new(vec![null_id_batch()]).is_err());
+}
+
+#[test]
+fn table_indexes_rows_globally() {
+    let table = TypedTable::<Event>::try_new(vec![batch(0..3), batch(3..3), batch(3..7)]).unwrap();
+    assert_eq!(table.len(), 7);
+    assert_eq!(table.num_batches(), 3);
+    assert_eq!(table.locate(2), Some(RowLocation { batch: 0, row: 2 }));
+    // The empty batch owns no rows; index 3 lands in the batch after it.
+    assert_eq!(table.locate(3), Some(RowLocation { batch: 2, row: 0 }));
+    assert_eq!(table.locate(6), Some(RowLocation { batch: 2, row: 3 }));
+    assert_eq!(table.locate(7), None);
+
+    assert_eq!(table.row(5).unwrap().id, 5);
+    assert!(matches!(
+        table.row(7),
+        Err(ViewAccessError::OutOfBounds {
+            index: 7,
+            len: 7,
+            ..
+        })
+    ));
+
+    let ids: Vec<i64> = table
+        .iter_views()
+        .map(|r| r.map(|(_, v)| v.id))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(ids, (0..7).collect::<Vec<_>>());
+    assert_eq!(table.batch(2).unwrap().len(), 4);
+    assert!(table.batch(3).is_none());
+}
+
+#[test]
+fn typed_batches_validates_lazily() {
+    let mut it = typed_batches::<Event, _>(vec![batch(0..2), wrong_batch(), batch(2..3)]);
+    assert_eq!(it.next().unwrap().unwrap().batch.len(), 2);
+    assert!(it.next().unwrap().is_err());
+    let last = it.next().unwrap().unwrap();
+    assert_eq!((last.index, last.batch.len()), (2, 1));
+    assert!(it.next().is_none());
+
+    assert!(TypedTable::<Event>::try_new(vec![batch(0..1), wrong_batch()]).is_err());
+}
+
+#[test]
+fn typed_batches_yield_views_with_locations() {
+    let mut rows = Vec::new();
+    for batch in typed_batches::<Event, _>(vec![batch(0..2), batch(2..2), batch(2..4)]) {
+        for view in batch.unwrap().iter_views() {
+            let (loc, view) = view.unwrap();
+            rows.push((loc, view.id));
+        }
+    }
+    assert_eq!(
+        rows,
+        vec![
+            (RowLocation { batch: 0, row: 0 }, 0),
+            (RowLocation { batch: 0, row: 1 }, 1),
+            (RowLocation { batch: 2, row: 0 }, 2),
+            (RowLocation { batch: 2, row: 1 }, 3),
+        ]
+    );
+}
+
+#[test]
+fn table_collects_and_extends_typed_batches() {
+    let mut table: TypedTable<Event> = typed_batches::<Event, _>(vec![batch(0..2)])
+        .map(|b| b.map(|b| b.batch))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert!(!table.is_empty());
+    table.extend([TypedBatch::try_new(batch(2..4)).unwrap()]);
+    assert_eq!(table.len(), 4);
+    assert_eq!(table.clone().into_batches().len(), 2);
+
+    let empty = TypedTable::<Event>::default();
+    assert!(empty.is_empty());
+    assert!(empty.schema().is_none());
+    assert!(empty.iter_views().next().is_none());
+}
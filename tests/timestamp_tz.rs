@@ -1,4 +1,6 @@
-use typed_arrow::{Millisecond, Nanosecond, Second, TimestampTz, Utc, prelude::*};
+use typed_arrow::{
+    Millisecond, Nanosecond, Second, TimestampTz, Utc, prelude::*, schema::SchemaMeta,
+};
 
 #[derive(Record)]
 struct RowTz {
@@ -34,6 +36,17 @@ fn timestamp_tz_schema_and_types() {
     assert_eq!(a0.len(), 1);
 }
 
+#[test]
+fn built_batches_carry_the_timezone() {
+    let mut b = <RowTz as BuildRows>::new_builders(2);
+    b.append_row(RowTz {
+        s_utc: TimestampTz::new(1),
+        ms_utc: None,
+    });
+    let batch = b.finish().into_record_batch();
+    assert_eq!(batch.schema(), <RowTz as SchemaMeta>::schema());
+}
+
 // Demonstrate a custom timezone marker
 enum AsiaShanghai {}
 impl typed_arrow::TimeZoneSpec for AsiaShanghai {
@@ -0,0 +1,268 @@
+use arrow_array::{Array, cast::AsArray, types::Int8Type};
+use arrow_schema::DataType;
+use typed_arrow::{
+    Decimal128, Dictionary, List, bridge::ArrowBinding, error::DecimalPrecisionError, prelude::*,
+};
+
+#[derive(Record)]
+pub struct Price {
+    pub id: i64,
+    pub amount: Decimal128<5, 2>,
+    pub discount: Option<Decimal128<3, 2>>,
+}
+
+#[derive(Record)]
+pub struct Order {
+    pub id: i64,
+    pub prices: List<Price>,
+}
+
+#[derive(Record)]
+pub struct Tagged {
+    pub tag: Dictionary<i8, String>,
+    pub id: i64,
+}
+
+#[derive(Record)]
+pub struct Labelled {
+    pub id: i64,
+    pub name: String,
+    pub labels: List<Dictionary<u8, String>>,
+    pub tag: Dictionary<i8, String>,
+}
+
+fn unwrap_column(err: AppendError) -> (usize, &'static str, AppendError) {
+    match err {
+        AppendError::Column {
+            col,
+            field_name,
+            source,
+        } => (col, field_name, *source),
+        other => panic!("expected column error, got {other:?}"),
+    }
+}
+
+#[test]
+fn decimal_out_of_precision_is_rejected_without_partial_write() {
+    let mut b = <Price as BuildRows>::new_builders(2);
+    b.try_append_row(Price {
+        id: 1,
+        amount: Decimal128::new(99_999), // 999.99
+        discount: Some(Decimal128::new(1_00)),
+    })
+    .unwrap();
+
+    let err = b
+        .try_append_row(Price {
+            id: 2,
+            amount: Decimal128::new(1),
+            discount: Some(Decimal128::new(10_00)),
+        })
+        .unwrap_err();
+    let (col, field_name, source) = unwrap_column(err);
+    assert_eq!(col, 2);
+    assert_eq!(field_name, "discount");
+    assert!(matches!(
+        source,
+        AppendError::DecimalPrecision(DecimalPrecisionError { precision: 3, .. })
+    ));
+
+    // None is always accepted for nullable columns.
+    b.try_append_row(Price {
+        id: 3,
+        amount: Decimal128::new(-99_999), // -999.99
+        discount: None,
+    })
+    .unwrap();
+
+    let batch = b.finish().into_record_batch();
+    assert_eq!(batch.num_rows(), 2);
+    let ids = batch
+        .column(0)
+        .as_primitive::<arrow_array::types::Int64Type>();
+    assert_eq!(ids.values(), &[1, 3]);
+}
+
+#[test]
+fn nested_struct_errors_carry_nested_column_context() {
+    let mut b = <Order as BuildRows>::new_builders(1);
+    let err = b
+        .try_append_row(Order {
+            id: 1,
+            prices: List::new(vec![
+                Price {
+                    id: 1,
+                    amount: Decimal128::new(1),
+                    discount: None,
+                },
+                Price {
+                    id: 2,
+                    amount: Decimal128::new(100_000),
+                    discount: None,
+                },
+            ]),
+        })
+        .unwrap_err();
+    let (col, field_name, source) = unwrap_column(err);
+    assert_eq!((col, field_name), (1, "prices"));
+    let (col, field_name, source) = unwrap_column(source);
+    assert_eq!((col, field_name), (1, "amount"));
+    assert!(matches!(source, AppendError::DecimalPrecision(_)));
+
+    let arrays = b.finish();
+    assert_eq!(arrays.id.len(), 0);
+    assert_eq!(arrays.prices.len(), 0);
+    assert_eq!(arrays.prices.values().len(), 0);
+}
+
+#[test]
+fn dictionary_key_overflow_is_reported() {
+    let mut b = <Tagged as BuildRows>::new_builders(200);
+    let rows = (0..200).map(|i| Tagged {
+        id: i,
+        tag: Dictionary::new(format!("tag-{i}")),
+    });
+    let err = b.try_append_rows(rows).unwrap_err();
+    let AppendError::Row { row, source } = err else {
+        panic!("expected row error");
+    };
+    // i8 keys address 128 distinct values (0..=127).
+    assert_eq!(row, 128);
+    let (col, field_name, source) = unwrap_column(*source);
+    assert_eq!((col, field_name), (0, "tag"));
+    assert!(matches!(
+        source,
+        AppendError::DictionaryKeyOverflow {
+            key_type: DataType::Int8
+        }
+    ));
+
+    // Repeated values still fit after the dictionary is full.
+    b.try_append_row(Tagged {
+        id: 0,
+        tag: Dictionary::new("tag-0".to_string()),
+    })
+    .unwrap();
+
+    let arrays = b.finish();
+    assert_eq!(arrays.id.len(), 129);
+    let tags: &arrow_array::DictionaryArray<Int8Type> = &arrays.tag;
+    assert_eq!(tags.len(), 129);
+    let values = tags.downcast_dict::<arrow_array::StringArray>().unwrap();
+    assert_eq!(values.into_iter().last().flatten(), Some("tag-0"));
+}
+
+#[test]
+fn dictionary_overflow_in_a_later_column_keeps_columns_aligned() {
+    let mut b = <Labelled as BuildRows>::new_builders(0);
+    let row = |i: i64, labels: Vec<String>| Labelled {
+        id: i,
+        name: format!("n{i}"),
+        labels: List::new(labels.into_iter().map(Dictionary::new).collect()),
+        tag: Dictionary::new(format!("tag-{i}")),
+    };
+    b.try_append_rows((0..128).map(|i| row(i, vec![format!("l{i}")])))
+        .unwrap();
+
+    // `tag` is full: the row is rejected before `id`, `name` or `labels` take it.
+    let (col, field_name, _) = unwrap_column(b.try_append_row(row(128, vec![])).unwrap_err());
+    assert_eq!((col, field_name), (3, "tag"));
+
+    // `labels` has room for 128 more values, but not for 129 in one list.
+    let many = (128..257).map(|i| format!("l{i}")).collect();
+    let err = b
+        .try_append_row(Labelled {
+            tag: Dictionary::new("tag-0".into()),
+            ..row(129, many)
+        })
+        .unwrap_err();
+    let (col, field_name, source) = unwrap_column(err);
+    assert_eq!((col, field_name), (2, "labels"));
+    assert!(matches!(
+        source,
+        AppendError::DictionaryKeyOverflow {
+            key_type: DataType::UInt8
+        }
+    ));
+
+    let batch = b.finish().into_record_batch();
+    assert_eq!(batch.num_rows(), 128);
+    assert!(batch.columns().iter().all(|c| c.len() == 128));
+}
+
+#[test]
+fn fixed_size_binary_dictionary_counts_distinct_values_past_key_range() {
+    type Id = Dictionary<u8, [u8; 4]>;
+    let mut b = <Id as ArrowBinding>::new_builder(0);
+    let id = |i: u32| Dictionary::new(i.to_le_bytes());
+    // Far more rows than u8 keys, but only 16 distinct values.
+    for i in 0..20_000 {
+        <Id as ArrowBinding>::try_append_value(&mut b, &id(i % 16)).unwrap();
+    }
+    for i in 16..256 {
+        <Id as ArrowBinding>::try_append_value(&mut b, &id(i)).unwrap();
+    }
+    assert!(matches!(
+        <Id as ArrowBinding>::try_append_value(&mut b, &id(256)),
+        Err(AppendError::DictionaryKeyOverflow {
+            key_type: DataType::UInt8
+        })
+    ));
+    <Id as ArrowBinding>::try_append_value(&mut b, &id(255)).unwrap();
+
+    let dict = <Id as ArrowBinding>::finish(b);
+    assert_eq!(dict.len(), 20_241);
+    assert_eq!(dict.keys().value(20_240), 255);
+}
+
+#[test]
+fn try_append_value_reserves_nested_dictionary_keys() {
+    type Labels = List<Dictionary<i8, String>>;
+    let mut b = <Labels as ArrowBinding>::new_builder(0);
+    let labels = |range: std::ops::Range<i32>| {
+        Labels::new(range.map(|i| Dictionary::new(format!("l{i}"))).collect())
+    };
+    <Labels as ArrowBinding>::try_append_value(&mut b, &labels(0..100)).unwrap();
+    assert!(matches!(
+        <Labels as ArrowBinding>::try_append_value(&mut b, &labels(100..200)),
+        Err(AppendError::DictionaryKeyOverflow { .. })
+    ));
+    <Labels as ArrowBinding>::try_append_value(&mut b, &labels(0..128)).unwrap();
+
+    let lists = <Labels as ArrowBinding>::finish(b);
+    assert_eq!(lists.len(), 2);
+    assert_eq!(lists.values().len(), 228);
+}
+
+#[test]
+fn hand_written_row_builder_gets_default_try_append() {
+    use typed_arrow::schema::RowBuilder;
+
+    struct Ids(Vec<i64>);
+    impl RowBuilder<i64> for Ids {
+        type Arrays = arrow_array::RecordBatch;
+        fn append_row(&mut self, row: i64) {
+            self.0.push(row);
+        }
+        fn append_null_row(&mut self) {}
+        fn append_option_row(&mut self, row: Option<i64>) {
+            self.0.extend(row);
+        }
+        fn append_rows<I: IntoIterator<Item = i64>>(&mut self, rows: I) {
+            self.0.extend(rows);
+        }
+        fn append_option_rows<I: IntoIterator<Item = Option<i64>>>(&mut self, rows: I) {
+            self.0.extend(rows.into_iter().flatten());
+        }
+        fn finish(self) -> Self::Arrays {
+            let ids = arrow_array::Int64Array::from(self.0);
+            arrow_array::RecordBatch::try_from_iter([("id", std::sync::Arc::new(ids) as _)])
+                .unwrap()
+        }
+    }
+
+    let mut b = Ids(Vec::new());
+    b.try_append_rows([1, 2]).unwrap();
+    b.try_append_row(3).unwrap();
+    assert_eq!(b.finish().num_rows(), 3);
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use arrow_array::{Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use typed_arrow::{TypedBatch, prelude::*};
+
+#[derive(Record)]
+pub struct Row {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+fn typed() -> TypedBatch<Row> {
+    let mut b = <Row as BuildRows>::new_builders(3);
+    b.append_rows((0..3).map(|i| Row {
+        id: i,
+        name: (i % 2 == 0).then(|| format!("n{i}")),
+    }));
+    b.finish().into_typed_batch()
+}
+
+#[test]
+fn accessors() {
+    let t = typed();
+    assert_eq!(t.len(), 3);
+    assert!(!t.is_empty());
+    assert_eq!(t.columns().id.values(), &[0, 1, 2]);
+    assert_eq!(
+        t.schema(),
+        <Row as typed_arrow::schema::SchemaMeta>::schema()
+    );
+
+    let names: Vec<Option<&str>> = t.iter_views().map(|v| v.unwrap().name).collect();
+    assert_eq!(names, vec![Some("n0"), None, Some("n2")]);
+    assert_eq!(t.row(2).unwrap().id, 2);
+    assert!(matches!(
+        t.row(3),
+        Err(ViewAccessError::OutOfBounds {
+            index: 3,
+            len: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn slice_stays_typed() {
+    let s = typed().slice(1, 2);
+    assert_eq!(s.len(), 2);
+    assert_eq!(s.columns().id.values(), &[1, 2]);
+    assert_eq!(s.row(0).unwrap().id, 1);
+    assert_eq!(s.into_inner().num_rows(), 2);
+}
+
+#[test]
+fn try_from_validates_once() {
+    let batch: RecordBatch = typed().into();
+    let t = TypedBatch::<Row>::try_from(batch).unwrap();
+    assert_eq!(t.len(), 3);
+
+    // A nullable `id` column holding a null violates `Row`.
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let bad = RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(vec![Some(1), None])),
+            Arc::new(StringArray::from(vec![Some("a"), Some("b")])),
+        ],
+    )
+    .unwrap();
+    assert!(matches!(
+        TypedBatch::<Row>::try_new(bad),
+        Err(SchemaError::Nullability { col: 0, .. })
+    ));
+}
@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, Int64Array, ListArray, RecordBatch, StringArray, StructArray,
+    builder::{ListBuilder, StringBuilder},
+};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Field, Fields, Schema};
+use typed_arrow::{
+    List,
+    prelude::*,
+    validate::{validate_batch, validate_batch_all},
+};
+
+#[derive(Record)]
+pub struct Address {
+    pub city: String,
+    pub zip: Option<i64>,
+}
+
+#[derive(Record)]
+pub struct Person {
+    pub id: i64,
+    pub address: Option<Address>,
+    pub tags: List<String>,
+}
+
+// Mirror of `Person::schema()` with every level marked nullable, as an external
+// producer might emit it.
+fn permissive_schema() -> (Fields, Arc<Schema>) {
+    let address_fields = Fields::from(vec![
+        Field::new("city", DataType::Utf8, true),
+        Field::new("zip", DataType::Int64, true),
+    ]);
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, true),
+        Field::new("address", DataType::Struct(address_fields.clone()), true),
+        Field::new(
+            "tags",
+            DataType::List(Field::new("item", DataType::Utf8, true).into()),
+            true,
+        ),
+    ]);
+    (address_fields, Arc::new(schema))
+}
+
+fn tags(rows: &[&[Option<&str>]]) -> ListArray {
+    let mut b =
+        ListBuilder::new(StringBuilder::new()).with_field(Field::new("item", DataType::Utf8, true));
+    for row in rows {
+        for v in *row {
+            b.values().append_option(*v);
+        }
+        b.append(true);
+    }
+    b.finish()
+}
+
+fn batch(
+    ids: Vec<Option<i64>>,
+    cities: Vec<Option<&str>>,
+    address_valid: Vec<bool>,
+    tags: ListArray,
+) -> RecordBatch {
+    let (address_fields, schema) = permissive_schema();
+    let zips: Vec<Option<i64>> = vec![None; cities.len()];
+    let address = StructArray::new(
+        address_fields,
+        vec![
+            Arc::new(StringArray::from(cities)) as ArrayRef,
+            Arc::new(Int64Array::from(zips)) as ArrayRef,
+        ],
+        Some(NullBuffer::from(address_valid)),
+    );
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(ids)),
+            Arc::new(address),
+            Arc::new(tags),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn valid_batch_passes() {
+    let mut b = <Person as BuildRows>::new_builders(2);
+    b.append_row(Person {
+        id: 1,
+        address: Some(Address {
+            city: "Paris".into(),
+            zip: None,
+        }),
+        tags: List::new(vec!["a".into()]),
+    });
+    b.append_row(Person {
+        id: 2,
+        address: None,
+        tags: List::new(vec![]),
+    });
+    let batch = b.finish().into_record_batch();
+    validate_batch::<Person>(&batch).unwrap();
+    validate_batch_all::<Person>(&batch).unwrap();
+}
+
+#[test]
+fn permissive_schema_without_nulls_passes() {
+    let batch = batch(
+        vec![Some(1), Some(2)],
+        vec![Some("Paris"), None],
+        vec![true, false],
+        tags(&[&[Some("a")], &[]]),
+    );
+    // The null city sits under a null address, which `Option<Address>` allows.
+    validate_batch::<Person>(&batch).unwrap();
+}
+
+#[test]
+fn top_level_null_is_reported() {
+    let batch = batch(
+        vec![Some(1), None],
+        vec![Some("Paris"), Some("Oslo")],
+        vec![true, true],
+        tags(&[&[], &[]]),
+    );
+    match validate_batch::<Person>(&batch) {
+        Err(SchemaError::Nullability {
+            col, path, index, ..
+        }) => {
+            assert_eq!(col, 0);
+            assert_eq!(path, "id");
+            assert_eq!(index, 1);
+        }
+        other => panic!("expected nullability error, got {other:?}"),
+    }
+}
+
+#[test]
+fn struct_child_null_is_reported_with_path() {
+    let batch = batch(
+        vec![Some(1), Some(2)],
+        vec![Some("Paris"), None],
+        vec![true, true],
+        tags(&[&[], &[]]),
+    );
+    match validate_batch::<Person>(&batch) {
+        Err(SchemaError::Nullability {
+            col, path, index, ..
+        }) => {
+            assert_eq!(col, 1);
+            assert_eq!(path, "address.city");
+            assert_eq!(index, 1);
+        }
+        other => panic!("expected nullability error, got {other:?}"),
+    }
+}
+
+#[test]
+fn list_item_null_is_reported_with_path() {
+    let batch = batch(
+        vec![Some(1)],
+        vec![Some("Paris")],
+        vec![true],
+        tags(&[&[Some("a"), None]]),
+    );
+    match validate_batch::<Person>(&batch) {
+        Err(SchemaError::Nullability { col, path, .. }) => {
+            assert_eq!(col, 2);
+            assert_eq!(path, "tags[]");
+        }
+        other => panic!("expected nullability error, got {other:?}"),
+    }
+}
+
+#[test]
+fn validate_all_collects_every_violation() {
+    let batch = batch(
+        vec![None, Some(2)],
+        vec![Some("Paris"), None],
+        vec![true, true],
+        tags(&[&[None], &[]]),
+    );
+    let errors = validate_batch_all::<Person>(&batch).unwrap_err();
+    let paths: Vec<&str> = errors
+        .iter()
+        .map(|e| match e {
+            SchemaError::Nullability { path, .. } => path.as_str(),
+            other => panic!("unexpected error {other:?}"),
+        })
+        .collect();
+    assert_eq!(paths, vec!["id", "address.city", "tags[]"]);
+}
+
+#[test]
+fn shape_mismatches_are_reported() {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)]));
+    let narrow = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(StringArray::from(vec!["x"])) as ArrayRef],
+    )
+    .unwrap();
+    assert!(matches!(
+        validate_batch::<Person>(&narrow),
+        Err(SchemaError::InvalidSchema { .. })
+    ));
+
+    #[derive(Record)]
+    struct Single {
+        id: i64,
+    }
+    assert!(matches!(
+        validate_batch::<Single>(&narrow),
+        Err(SchemaError::TypeMismatch { .. })
+    ));
+
+    #[derive(Record)]
+    struct Renamed {
+        #[allow(dead_code)]
+        key: String,
+    }
+    assert!(matches!(
+        validate_batch::<Renamed>(&narrow),
+        Err(SchemaError::MissingField { .. })
+    ));
+}
+
+#[test]
+fn fixed_size_list_item_null_uses_the_list_path() {
+    #[derive(Record)]
+    struct Pair {
+        #[allow(dead_code)]
+        xy: typed_arrow::FixedSizeList<i64, 2>,
+    }
+
+    let item = Arc::new(Field::new("item", DataType::Int64, true));
+    let xy = arrow_array::FixedSizeListArray::new(
+        item.clone(),
+        2,
+        Arc::new(Int64Array::from(vec![Some(1), Some(2), Some(3), None])),
+        None,
+    );
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "xy",
+        DataType::FixedSizeList(item, 2),
+        false,
+    )]));
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(xy) as ArrayRef]).unwrap();
+    match validate_batch::<Pair>(&batch) {
+        Err(SchemaError::Nullability { path, index, .. }) => {
+            assert_eq!(path, "xy[]");
+            assert_eq!(index, 3);
+        }
+        other => panic!("expected nullability error, got {other:?}"),
+    }
+}
@@ -0,0 +1,94 @@
+use typed_arrow::prelude::*;
+
+#[derive(Record)]
+pub struct Event {
+    pub ts: i64,
+    pub name: Option<String>,
+}
+
+fn batch(n: i64) -> arrow_array::RecordBatch {
+    let mut b = <Event as BuildRows>::new_builders(n as usize);
+    b.append_rows((0..n).map(|i| Event {
+        ts: i * 10,
+        name: (i % 3 != 0).then(|| format!("e{i}")),
+    }));
+    b.finish().into_record_batch()
+}
+
+#[test]
+fn get_reads_without_advancing() {
+    let batch = batch(5);
+    let mut views = batch.iter_views::<Event>().unwrap();
+    assert_eq!(views.get(3).unwrap().ts, 30);
+    assert_eq!(views.len(), 5);
+    views.next();
+    // `get` is relative to the remaining rows.
+    assert_eq!(views.get(0).unwrap().ts, 10);
+    assert!(matches!(
+        views.get(4),
+        Err(ViewAccessError::OutOfBounds {
+            index: 4,
+            len: 4,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn double_ended_and_nth() {
+    let batch = batch(6);
+    let ts: Vec<i64> = batch
+        .iter_views::<Event>()
+        .unwrap()
+        .rev()
+        .map(|v| v.unwrap().ts)
+        .collect();
+    assert_eq!(ts, vec![50, 40, 30, 20, 10, 0]);
+
+    let mut views = batch.iter_views::<Event>().unwrap();
+    assert_eq!(views.nth(2).unwrap().unwrap().ts, 20);
+    assert_eq!(views.next_back().unwrap().unwrap().ts, 50);
+    assert_eq!(views.nth_back(1).unwrap().unwrap().ts, 30);
+    assert_eq!(views.len(), 0);
+    assert!(views.nth(10).is_none());
+
+    let skipped: Vec<i64> = batch
+        .iter_views::<Event>()
+        .unwrap()
+        .skip(4)
+        .map(|v| v.unwrap().ts)
+        .collect();
+    assert_eq!(skipped, vec![40, 50]);
+}
+
+#[test]
+fn slice_paginates() {
+    let batch = batch(10);
+    let views = batch.iter_views::<Event>().unwrap();
+    let page: Vec<i64> = views.slice(3..6).map(|v| v.unwrap().ts).collect();
+    assert_eq!(page, vec![30, 40, 50]);
+    assert_eq!(views.slice(..2).len(), 2);
+    assert_eq!(views.slice(8..).len(), 2);
+    assert_eq!(views.slice(2..=2).get(0).unwrap().ts, 20);
+
+    let mut rest = views.slice(5..);
+    rest.next();
+    assert_eq!(rest.slice(1..2).get(0).unwrap().ts, 70);
+}
+
+#[test]
+fn binary_search_over_sorted_views() {
+    let batch = batch(100);
+    let views = batch.iter_views::<Event>().unwrap();
+    let (mut lo, mut hi) = (0, views.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if views.get(mid).unwrap().ts < 425 {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    assert_eq!(lo, 43);
+    assert_eq!(views.get(lo).unwrap().name, Some("e43"));
+}
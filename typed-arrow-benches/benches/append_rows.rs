@@ -153,6 +153,19 @@ fn bench_primitives(c: &mut Criterion) {
             })
         });
 
+        // typed-arrow: chunked bulk append of primitive columns
+        group.bench_with_input(
+            BenchmarkId::new("typed_bulk", size),
+            &records,
+            |b, records| {
+                b.iter(|| {
+                    let mut builders = Primitive::new_builders(records.len());
+                    builders.append_rows(records.iter().copied());
+                    black_box(builders.finish().into_record_batch())
+                })
+            },
+        );
+
         // typed-arrow-dyn: dynamic dispatch
         group.bench_with_input(
             BenchmarkId::new("dynamic", size),
@@ -247,6 +260,19 @@ fn bench_with_strings(c: &mut Criterion) {
             })
         });
 
+        // typed-arrow: bulk append for the primitive columns, per-row for strings
+        group.bench_with_input(
+            BenchmarkId::new("typed_bulk", size),
+            &records,
+            |b, records| {
+                b.iter(|| {
+                    let mut builders = WithStrings::new_builders(records.len());
+                    builders.append_rows_ref(records);
+                    black_box(builders.finish().into_record_batch())
+                })
+            },
+        );
+
         // typed-arrow-dyn: dynamic dispatch + string handling
         group.bench_with_input(
             BenchmarkId::new("dynamic", size),
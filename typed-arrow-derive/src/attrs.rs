@@ -2,7 +2,7 @@
 use quote::ToTokens;
 #[cfg(feature = "ext-hooks")]
 use syn::Path;
-use syn::{Attribute, LitStr};
+use syn::{Attribute, Ident, LitBool, LitStr};
 
 pub(crate) fn parse_schema_metadata_pairs(
     attrs: &[Attribute],
@@ -176,6 +176,211 @@ pub(crate) fn parse_field_name_override(attrs: &[Attribute]) -> syn::Result<Opti
     Ok(None)
 }
 
+/// Parquet storage hints for one field: `#[record(parquet(...))]`.
+#[derive(Default)]
+pub(crate) struct ParquetHintsAttr {
+    pub(crate) encoding: Option<String>,
+    pub(crate) compression: Option<String>,
+    pub(crate) statistics: Option<String>,
+    pub(crate) dictionary: Option<bool>,
+    pub(crate) bloom_filter: Option<bool>,
+}
+
+// Dictionary encodings are requested with `dictionary = true`, not as the value encoding.
+const PARQUET_ENCODINGS: &[&str] = &[
+    "plain",
+    "rle",
+    "delta_binary_packed",
+    "delta_length_byte_array",
+    "delta_byte_array",
+    "byte_stream_split",
+];
+const PARQUET_CODECS: &[&str] = &[
+    "uncompressed",
+    "snappy",
+    "gzip",
+    "lzo",
+    "brotli",
+    "lz4",
+    "zstd",
+    "lz4_raw",
+];
+const PARQUET_STATISTICS: &[&str] = &["none", "chunk", "page"];
+
+/// Parse field-level Parquet hints:
+/// `#[record(parquet(encoding = "...", compression = "...", statistics = "...",
+/// dictionary = bool, bloom_filter = bool))]`.
+///
+/// Names are checked here so typos fail at compile time; compression levels
+/// (e.g. `"zstd(3)"`) are checked when the writer properties are built.
+pub(crate) fn parse_field_parquet_hints(
+    attrs: &[Attribute],
+) -> syn::Result<Option<ParquetHintsAttr>> {
+    let mut out: Option<ParquetHintsAttr> = None;
+    for attr in attrs {
+        if !attr.path().is_ident("record") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("parquet") {
+                let hints = out.get_or_insert_with(ParquetHintsAttr::default);
+                meta.parse_nested_meta(|inner| {
+                    let name = |known: &[&str]| -> syn::Result<String> {
+                        let s: LitStr = inner.value()?.parse()?;
+                        let value = s.value();
+                        let base = value.split('(').next().unwrap_or_default();
+                        if known.contains(&base.to_ascii_lowercase().as_str()) {
+                            Ok(value)
+                        } else {
+                            Err(syn::Error::new(
+                                s.span(),
+                                format!(
+                                    "unknown value `{value}`, expected one of: {}",
+                                    known.join(", ")
+                                ),
+                            ))
+                        }
+                    };
+                    if inner.path.is_ident("encoding") {
+                        hints.encoding = Some(name(PARQUET_ENCODINGS)?);
+                    } else if inner.path.is_ident("compression") {
+                        hints.compression = Some(name(PARQUET_CODECS)?);
+                    } else if inner.path.is_ident("statistics") {
+                        hints.statistics = Some(name(PARQUET_STATISTICS)?);
+                    } else if inner.path.is_ident("dictionary") {
+                        hints.dictionary = Some(inner.value()?.parse::<LitBool>()?.value);
+                    } else if inner.path.is_ident("bloom_filter") {
+                        hints.bloom_filter = Some(inner.value()?.parse::<LitBool>()?.value);
+                    } else {
+                        return Err(inner.error(
+                            "unknown parquet hint, expected `encoding`, `compression`, \
+                             `statistics`, `dictionary` or `bloom_filter`",
+                        ));
+                    }
+                    Ok(())
+                })?;
+            } else if let Ok(v) = meta.value() {
+                // Consume unknown nested entries
+                let _expr: syn::Expr = v.parse()?;
+            } else if !meta.input.is_empty() {
+                meta.parse_nested_meta(|inner| {
+                    if let Ok(v2) = inner.value() {
+                        let _expr: syn::Expr = v2.parse()?;
+                    } else if !inner.input.is_empty() {
+                        let _ = inner.parse_nested_meta(|_| Ok(()));
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(out)
+}
+
+/// Parse container-level projection source: `#[record(project_of = Wide)]`.
+///
+/// Accepts a path or a string literal holding a path.
+pub(crate) fn parse_record_project_of(attrs: &[Attribute]) -> syn::Result<Option<syn::Path>> {
+    let mut out: Option<syn::Path> = None;
+    for attr in attrs {
+        if attr.path().is_ident("record") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("project_of") {
+                    let v = meta.value()?;
+                    let p = if let Ok(ls) = v.parse::<LitStr>() {
+                        syn::parse_str(&ls.value())?
+                    } else {
+                        v.parse::<syn::ExprPath>()?.path
+                    };
+                    if out.replace(p).is_some() {
+                        return Err(meta.error("duplicate `project_of`"));
+                    }
+                } else {
+                    // Consume unknown nested entries
+                    if let Ok(v) = meta.value() {
+                        let _expr: syn::Expr = v.parse()?;
+                    } else if meta.input.is_empty() {
+                        // bare flag
+                    } else {
+                        meta.parse_nested_meta(|inner| {
+                            if let Ok(v2) = inner.value() {
+                                let _expr: syn::Expr = v2.parse()?;
+                            } else if inner.input.is_empty() {
+                                // bare flag inside list
+                            } else {
+                                let _ = inner.parse_nested_meta(|_| Ok(()));
+                            }
+                            Ok(())
+                        })?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(out)
+}
+
+/// Names given to the generated companion types, overriding the defaults.
+#[derive(Default)]
+pub(crate) struct RecordTypeNames {
+    pub(crate) column_enum: Option<Ident>,
+    pub(crate) columns_struct: Option<Ident>,
+    pub(crate) vecs_struct: Option<Ident>,
+}
+
+/// Parse container-level type renames:
+/// `#[record(column_enum = PersonCol, columns_struct = PersonCols, vecs_struct = PersonColVecs)]`.
+///
+/// Accepts identifiers or string literals holding them.
+pub(crate) fn parse_record_type_names(attrs: &[Attribute]) -> syn::Result<RecordTypeNames> {
+    let mut out = RecordTypeNames::default();
+    for attr in attrs {
+        if attr.path().is_ident("record") {
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("column_enum") {
+                    &mut out.column_enum
+                } else if meta.path.is_ident("columns_struct") {
+                    &mut out.columns_struct
+                } else if meta.path.is_ident("vecs_struct") {
+                    &mut out.vecs_struct
+                } else {
+                    // Consume unknown nested entries
+                    if let Ok(v) = meta.value() {
+                        let _expr: syn::Expr = v.parse()?;
+                    } else if meta.input.is_empty() {
+                        // bare flag
+                    } else {
+                        meta.parse_nested_meta(|inner| {
+                            if let Ok(v2) = inner.value() {
+                                let _expr: syn::Expr = v2.parse()?;
+                            } else if inner.input.is_empty() {
+                                // bare flag inside list
+                            } else {
+                                let _ = inner.parse_nested_meta(|_| Ok(()));
+                            }
+                            Ok(())
+                        })?;
+                    }
+                    return Ok(());
+                };
+                let v = meta.value()?;
+                let ident = if let Ok(ls) = v.parse::<LitStr>() {
+                    ls.parse::<Ident>()?
+                } else {
+                    v.parse::<Ident>()?
+                };
+                if slot.replace(ident).is_some() {
+                    return Err(meta.error("duplicate type name"));
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(out)
+}
+
 // -------- extension hooks parsing (feature-gated) --------
 
 // Container-level: #[record(visit(path::ToVisitor, other::Visitor))]
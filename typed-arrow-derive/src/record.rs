@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::{ToTokens, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::{
     Attribute, Data, DataStruct, DeriveInput, Fields, GenericParam, Generics, Ident, Lifetime,
     LifetimeParam, Path, Type, parse_quote, punctuated::Punctuated,
@@ -21,7 +21,8 @@ use crate::attrs::parse_record_fields_macros;
 #[cfg(feature = "ext-hooks")]
 use crate::attrs::parse_record_record_macros;
 use crate::attrs::{
-    parse_field_metadata_pairs, parse_field_name_override, parse_schema_metadata_pairs,
+    parse_field_metadata_pairs, parse_field_name_override, parse_field_parquet_hints,
+    parse_record_project_of, parse_record_type_names, parse_schema_metadata_pairs,
 };
 
 pub(crate) fn derive_record(input: &DeriveInput) -> TokenStream {
@@ -36,6 +37,18 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
     let builders_ident = Ident::new(&format!("{name}Builders"), name.span());
     let arrays_ident = Ident::new(&format!("{name}Arrays"), name.span());
+    // `#[record(column_enum = ..., columns_struct = ..., vecs_struct = ...)]` rename
+    // these when the default names clash with other types in scope.
+    let type_names = parse_record_type_names(&input.attrs)?;
+    let columns_ident = type_names
+        .columns_struct
+        .unwrap_or_else(|| Ident::new(&format!("{name}Columns"), name.span()));
+    let vecs_ident = type_names
+        .vecs_struct
+        .unwrap_or_else(|| Ident::new(&format!("{name}Vecs"), name.span()));
+    let column_ident = type_names
+        .column_enum
+        .unwrap_or_else(|| Ident::new(&format!("{name}Column"), name.span()));
 
     let Data::Struct(DataStruct {
         fields: Fields::Named(fields),
@@ -49,6 +62,18 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     };
 
     let view_lt = fresh_view_lifetime(&input.generics);
+
+    // Projection records select their columns by name from a wider source record.
+    let project_of = parse_record_project_of(&input.attrs)?;
+    if project_of.is_some() && !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[record(project_of = ...)] is not supported on generic records",
+        ));
+    }
+    let source_column_enum = project_of
+        .as_ref()
+        .map(|src| quote! { <#src as ::typed_arrow::schema::RecordColumns>::Column });
     let generic_type_idents: HashSet<Ident> = input
         .generics
         .params
@@ -69,12 +94,30 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
 
     // Row-builders supporting code
     let mut builder_struct_fields = Vec::with_capacity(len);
+    let mut vecs_struct_fields = Vec::with_capacity(len);
+    let mut vecs_into_fields = Vec::with_capacity(len);
     let mut arrays_struct_fields = Vec::with_capacity(len);
+    let mut columns_struct_fields = Vec::with_capacity(len);
+    let mut columns_init_fields = Vec::with_capacity(len);
+    let mut variant_names: Vec<String> = Vec::with_capacity(len);
+    let mut constant_names: Vec<String> = Vec::with_capacity(len);
+    let mut field_consts: Vec<Ident> = Vec::with_capacity(len);
+    let mut source_columns = Vec::with_capacity(len);
+    let mut batch_col_exprs = Vec::with_capacity(len);
+    let mut projection_asserts = Vec::with_capacity(len);
     let mut builders_init_fields = Vec::with_capacity(len);
     let mut append_row_stmts = Vec::with_capacity(len);
     let mut append_row_ref_stmts = Vec::with_capacity(len);
+    let mut validate_field_stmts = Vec::with_capacity(len);
+    let mut reserve_field_stmts = Vec::with_capacity(len);
+    let mut reserve_struct_stmts = Vec::with_capacity(len);
     let mut finish_fields = Vec::with_capacity(len);
+    let mut finish_reset_fields = Vec::with_capacity(len);
+    let mut child_memory_size_terms = Vec::with_capacity(len);
+    let mut finish_cloned_fields = Vec::with_capacity(len);
     let mut field_idents: Vec<&Ident> = Vec::with_capacity(len);
+    let mut bulk_fields: Vec<bool> = Vec::with_capacity(len);
+    let mut parquet_hint_entries = Vec::new();
     let mut append_struct_owned_stmts = Vec::with_capacity(len);
     let mut append_struct_null_stmts = Vec::with_capacity(len);
     let mut append_struct_borrowed_stmts = Vec::with_capacity(len);
@@ -87,6 +130,9 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         inner_ty_ts: proc_macro2::TokenStream,
         nullable: bool,
         arrow_field_name: String,
+        // Projections take the name and nullability of their source column.
+        arrow_name_ts: proc_macro2::TokenStream,
+        nullable_ts: proc_macro2::TokenStream,
     }
 
     // Parse top-level schema metadata from struct attributes
@@ -134,28 +180,73 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         let fname = f.ident.as_ref().expect("named");
         field_idents.push(fname);
         let (inner_ty, nullable) = unwrap_option(&f.ty);
+        bulk_fields.push(is_bulk_primitive(&inner_ty));
         // Backward-compat cleanup: #[record(nested)] and #[nested] are no longer supported.
         // Nested structs are now the default behavior.
         check_no_legacy_nested_attr(&f.attrs)?;
 
         // Check for field name override: #[record(name = "...")]
         let field_name_override = parse_field_name_override(&f.attrs)?;
+        if field_name_override.is_some() && project_of.is_some() {
+            return Err(syn::Error::new(
+                fname.span(),
+                "projection fields take their Arrow name from the source column; remove #[record(name = ...)]",
+            ));
+        }
         let arrow_field_name = field_name_override
             .as_ref()
             .map_or_else(|| fname.to_string(), |s| s.clone());
 
         let inner_ty_ts = inner_ty.to_token_stream();
         inner_tys_for_view.push(inner_ty_ts.clone());
+        variant_names.push(upper_camel(fname));
+        constant_names.push(upper_snake(fname));
+        // Projections find their source column through the field's name, which the
+        // source's column variant may not be derived from alone.
+        let field_const = field_const_ident(fname);
+        let source_column = source_column_enum
+            .as_ref()
+            .map(|src_col| quote! { <#src_col>::#field_const });
+        field_consts.push(field_const);
+        let arrow_name_ts = match &source_column {
+            Some(src_column) => quote! { #src_column.name() },
+            None => quote! { #arrow_field_name },
+        };
+        if let (Some(src), Some(src_column)) = (&project_of, &source_column) {
+            batch_col_exprs.push(quote! {{
+                let __name = #src_column.name();
+                batch.column_by_name(__name)
+                    .ok_or_else(|| ::typed_arrow::error::SchemaError::missing_field(__name))?
+            }});
+            let nullable_msg = format!(
+                "projection field `{fname}` must be `Option<_>`: the source column is nullable"
+            );
+            projection_asserts.push(quote! {
+                const _: () = {
+                    const __I: usize = #src_column.index();
+                    let _: ::core::marker::PhantomData<<#src as ::typed_arrow::schema::ColAt<__I>>::Native> =
+                        ::core::marker::PhantomData::<#inner_ty_ts>;
+                    assert!(#nullable || !<#src as ::typed_arrow::schema::ColAt<__I>>::NULLABLE, #nullable_msg);
+                };
+            });
+        } else {
+            batch_col_exprs.push(quote! { batch.column(#idx) });
+        }
+        if let Some(src_column) = &source_column {
+            source_columns.push(src_column.clone());
+        }
         let needs_try_into = !(is_copy_primitive(&inner_ty)
             || is_string(&inner_ty)
             || is_fixed_size_binary(&inner_ty));
         if needs_try_into && type_contains_generic(&inner_ty, &generic_type_idents) {
             try_from_tys_for_view.push(inner_ty_ts.clone());
         }
-        let nullable_lit = if nullable {
-            quote!(true)
-        } else {
-            quote!(<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::NULLABLE)
+        let nullable_lit = match (&project_of, &source_column) {
+            (Some(src), Some(src_column)) => quote! {
+                <#src as ::typed_arrow::schema::ColAt<{ #src_column.index() }>>::NULLABLE
+            },
+            _ if nullable => quote!(true),
+            _ => quote!(<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::NULLABLE),
         };
 
         col_infos.push(ColInfo {
@@ -163,16 +254,56 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             inner_ty_ts: inner_ty_ts.clone(),
             nullable,
             arrow_field_name: arrow_field_name.clone(),
+            arrow_name_ts: arrow_name_ts.clone(),
+            nullable_ts: nullable_lit.clone(),
         });
 
         // V::visit::<I, Arrow, Rust>(FieldMeta::new(name, nullable))
         let visit = quote! {
             V::visit::<{ #idx }, #inner_ty_ts>(
-                ::typed_arrow::schema::FieldMeta::new(#arrow_field_name, #nullable_lit)
+                ::typed_arrow::schema::FieldMeta::new(#arrow_name_ts, #nullable_lit)
             );
         };
         visit_calls.push(visit);
 
+        // Field-level Parquet storage hints
+        if let Some(hints) = parse_field_parquet_hints(&f.attrs)? {
+            // Parquet only writes booleans with the RLE value encoding.
+            if hints
+                .encoding
+                .as_deref()
+                .is_some_and(|e| e.eq_ignore_ascii_case("rle"))
+                && !is_bool(&inner_ty)
+            {
+                return Err(syn::Error::new(
+                    fname.span(),
+                    "`encoding = \"rle\"` only applies to `bool` and `Option<bool>` fields",
+                ));
+            }
+            let opt_str = |v: Option<String>| match v {
+                Some(v) => quote!(::core::option::Option::Some(#v)),
+                None => quote!(::core::option::Option::None),
+            };
+            let opt_bool = |v: Option<bool>| match v {
+                Some(v) => quote!(::core::option::Option::Some(#v)),
+                None => quote!(::core::option::Option::None),
+            };
+            let encoding = opt_str(hints.encoding);
+            let compression = opt_str(hints.compression);
+            let statistics = opt_str(hints.statistics);
+            let dictionary = opt_bool(hints.dictionary);
+            let bloom_filter = opt_bool(hints.bloom_filter);
+            parquet_hint_entries.push(quote! {
+                (#arrow_name_ts, ::typed_arrow::schema::ParquetColumnHints {
+                    encoding: #encoding,
+                    compression: #compression,
+                    statistics: #statistics,
+                    dictionary: #dictionary,
+                    bloom_filter: #bloom_filter,
+                })
+            });
+        }
+
         // Field-level metadata
         let field_meta_pairs = parse_field_metadata_pairs(&f.attrs)?;
 
@@ -183,7 +314,7 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             });
             child_field_stmts.push(quote! {
                 let mut __f = ::typed_arrow::arrow_schema::Field::new(
-                    #arrow_field_name,
+                    #arrow_name_ts,
                     <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::data_type(),
                     #nullable_lit,
                 );
@@ -195,7 +326,7 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         } else {
             child_field_stmts.push(quote! {
                 fields.push(::typed_arrow::arrow_schema::Field::new(
-                    #arrow_field_name,
+                    #arrow_name_ts,
                     <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::data_type(),
                     #nullable_lit,
                 ));
@@ -221,6 +352,14 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             }
         }
 
+        child_memory_size_terms.push(quote! {
+            <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::builder_memory_size(
+                __fields[#idx]
+                    .as_any()
+                    .downcast_ref::<<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Builder>()
+                    .expect("child builder type matches"),
+            )
+        });
         // StructMeta: child builder boxed as ArrayBuilder
         child_builder_stmts.push(quote! {
             let b: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Builder =
@@ -232,9 +371,40 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         builder_struct_fields.push(quote! {
             pub #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Builder
         });
+        if nullable {
+            vecs_struct_fields.push(quote! {
+                pub #fname: ::std::vec::Vec<::core::option::Option<#inner_ty_ts>>
+            });
+            vecs_into_fields.push(quote! {
+                #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::array_from_options(self.#fname)
+            });
+        } else {
+            vecs_struct_fields.push(quote! {
+                pub #fname: ::std::vec::Vec<#inner_ty_ts>
+            });
+            vecs_into_fields.push(quote! {
+                #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::array_from_vec(self.#fname)
+            });
+        }
         arrays_struct_fields.push(quote! {
             pub #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Array
         });
+        columns_struct_fields.push(quote! {
+            pub #fname: &#view_lt <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Array
+        });
+        let batch_col_expr = batch_col_exprs.last().expect("pushed above");
+        columns_init_fields.push(quote! {
+            #fname: {
+                let __col = #batch_col_expr;
+                __col
+                    .as_any()
+                    .downcast_ref::<<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Array>()
+                    .ok_or_else(|| ::typed_arrow::error::SchemaError::type_mismatch(
+                        <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::data_type(),
+                        __col.data_type().clone()
+                    ))?
+            }
+        });
         builders_init_fields.push(quote! {
             #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::new_builder(capacity)
         });
@@ -266,9 +436,51 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::append_null(&mut self.#fname);
             });
         }
+        // Fallible append: validate the borrowed row and reserve dictionary keys in
+        // every column before appending, so a failure leaves all columns aligned.
+        let col = i;
+        let at_column =
+            quote! { |e: ::typed_arrow::error::AppendError| e.at_column(#col, #arrow_name_ts) };
+        let child_builder = quote! {
+            __sb.field_builder::<<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Builder>({ #idx })
+                .expect("child builder type matches")
+        };
+        if nullable {
+            validate_field_stmts.push(quote! {
+                if let Some(v) = #fname {
+                    <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::validate_value(v).map_err(#at_column)?;
+                }
+            });
+            reserve_field_stmts.push(quote! {
+                if let Some(v) = #fname {
+                    <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::reserve_value(&mut self.#fname, v).map_err(#at_column)?;
+                }
+            });
+            reserve_struct_stmts.push(quote! {
+                if let Some(v) = #fname {
+                    <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::reserve_value(#child_builder, v).map_err(#at_column)?;
+                }
+            });
+        } else {
+            validate_field_stmts.push(quote! {
+                <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::validate_value(#fname).map_err(#at_column)?;
+            });
+            reserve_field_stmts.push(quote! {
+                <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::reserve_value(&mut self.#fname, #fname).map_err(#at_column)?;
+            });
+            reserve_struct_stmts.push(quote! {
+                <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::reserve_value(#child_builder, #fname).map_err(#at_column)?;
+            });
+        }
         finish_fields.push(quote! {
             #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::finish(self.#fname)
         });
+        finish_reset_fields.push(quote! {
+            #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::finish_and_reset(&mut self.#fname)
+        });
+        finish_cloned_fields.push(quote! {
+            #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::finish_cloned(&self.#fname)
+        });
 
         // Generate AppendStruct implementations' bodies for this struct's fields
         let child_builder_ty =
@@ -321,12 +533,8 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     for info in &col_infos {
         let idx = &info.idx;
         let inner_ty_ts = &info.inner_ty_ts;
-        let nullable_lit = if info.nullable {
-            quote!(true)
-        } else {
-            quote!(<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::NULLABLE)
-        };
-        let arrow_field_name = &info.arrow_field_name;
+        let nullable_lit = &info.nullable_ts;
+        let arrow_name_ts = &info.arrow_name_ts;
 
         col_impls.push(quote! {
             impl #base_impl_generics ::typed_arrow::schema::ColAt<{ #idx }> for #name #base_ty_generics #base_where_clause {
@@ -334,12 +542,215 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 type ColumnArray = < #inner_ty_ts as ::typed_arrow::bridge::ArrowBinding >::Array;
                 type ColumnBuilder = < #inner_ty_ts as ::typed_arrow::bridge::ArrowBinding >::Builder;
                 const NULLABLE: bool = #nullable_lit;
-                const NAME: &'static str = #arrow_field_name;
+                const NAME: &'static str = #arrow_name_ts;
                 fn data_type() -> ::typed_arrow::arrow_schema::DataType { < #inner_ty_ts as ::typed_arrow::bridge::ArrowBinding >::data_type() }
             }
         });
     }
 
+    // Projections look columns up by name, so the batch may be wider than the record.
+    let column_count_check = if project_of.is_some() {
+        quote! {}
+    } else {
+        quote! {
+            if batch.num_columns() != #len {
+                return ::core::result::Result::Err(::typed_arrow::error::SchemaError::invalid(
+                    format!("Column count mismatch: expected {} columns for {}, but RecordBatch has {} columns",
+                        #len, stringify!(#name), batch.num_columns())
+                ));
+            }
+        }
+    };
+    let selects_by_name = if project_of.is_some() {
+        quote! { const SELECTS_COLUMNS_BY_NAME: bool = true; }
+    } else {
+        quote! {}
+    };
+    let projection_impl = match (&project_of, &source_column_enum) {
+        (Some(src), Some(src_col)) => quote! {
+            #(#projection_asserts)*
+
+            impl ::typed_arrow::schema::Projection for #name {
+                type Source = #src;
+                const SOURCE_COLUMNS: &'static [#src_col] = &[#( #source_columns ),*];
+            }
+        },
+        _ => quote! {},
+    };
+
+    let mut columns_generics = base_generics.clone();
+    prepend_view_lifetime(&mut columns_generics, view_lt.clone());
+    add_view_lifetime_bounds(&mut columns_generics, &inner_tys_for_view, &view_lt);
+    let (_columns_impl_generics, columns_ty_generics, columns_where_clause) =
+        columns_generics.split_for_impl();
+
+    // Bulk append: fixed-width primitive columns are gathered into chunked scratch
+    // buffers and appended with `append_slice`; other columns append per row.
+    let mut bulk_decls = Vec::new();
+    let mut bulk_flushes = Vec::new();
+    let mut bulk_owned_stmts = Vec::with_capacity(len);
+    let mut bulk_ref_stmts = Vec::with_capacity(len);
+    for (i, (fname, info)) in field_idents.iter().zip(&col_infos).enumerate() {
+        if !bulk_fields[i] {
+            bulk_owned_stmts.push(append_row_stmts[i].clone());
+            bulk_ref_stmts.push(append_row_ref_stmts[i].clone());
+            continue;
+        }
+        let inner_ty_ts = &info.inner_ty_ts;
+        let scratch = format_ident!("__bulk_{}", i);
+        let (elem_ty, append_fn) = if info.nullable {
+            (
+                quote!(::core::option::Option<#inner_ty_ts>),
+                quote!(append_option_slice),
+            )
+        } else {
+            (quote!(#inner_ty_ts), quote!(append_slice))
+        };
+        bulk_decls.push(quote! {
+            let mut #scratch: ::std::vec::Vec<#elem_ty> = ::std::vec::Vec::with_capacity(__cap);
+        });
+        bulk_flushes.push(quote! {
+            <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::#append_fn(&mut self.#fname, &#scratch);
+            #scratch.clear();
+        });
+        bulk_owned_stmts.push(quote! { #scratch.push(#fname); });
+        bulk_ref_stmts.push(quote! { #scratch.push(*#fname); });
+    }
+    let (append_rows_body, append_rows_ref_body) = if bulk_decls.is_empty() {
+        (
+            quote! { for r in rows { self.append_row(r); } },
+            quote! { for r in rows { self.append_row_ref(r); } },
+        )
+    } else {
+        let chunked = |row_stmts: &[proc_macro2::TokenStream]| {
+            quote! {
+                const __CHUNK: usize = 1024;
+                let mut __rows = rows.into_iter();
+                let __cap = __rows.size_hint().0.min(__CHUNK);
+                #(#bulk_decls)*
+                loop {
+                    let mut __n = 0usize;
+                    for __row in __rows.by_ref().take(__CHUNK) {
+                        let #name { #( #field_idents ),* } = __row;
+                        #(#row_stmts)*
+                        __n += 1;
+                    }
+                    #(#bulk_flushes)*
+                    if __n < __CHUNK {
+                        break;
+                    }
+                }
+            }
+        };
+        (chunked(&bulk_owned_stmts), chunked(&bulk_ref_stmts))
+    };
+
+    let parquet_hints_fn = if parquet_hint_entries.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn parquet_hints() -> ::std::vec::Vec<(&'static str, ::typed_arrow::schema::ParquetColumnHints)> {
+                ::std::vec![#(#parquet_hint_entries),*]
+            }
+        }
+    };
+
+    // Column enum: names and indices are const; data types need a concrete record type.
+    // Fields whose names map to the same variant or constant get their index appended.
+    let column_variants: Vec<Ident> = disambiguate(&variant_names, "", &[])
+        .into_iter()
+        .zip(&field_idents)
+        .map(|(v, f)| Ident::new(&v, f.span()))
+        .collect();
+    // `ALL` is the column list, and a variant would shadow a constant of its name.
+    let mut reserved_constants = vec!["ALL".to_string()];
+    reserved_constants.extend(column_variants.iter().map(ToString::to_string));
+    let typed_columns: Vec<Ident> = disambiguate(&constant_names, "_", &reserved_constants)
+        .into_iter()
+        .zip(&field_idents)
+        .map(|(c, f)| Ident::new(&c, f.span()))
+        .collect();
+    let column_idxs: Vec<usize> = (0..len).collect();
+    let column_names: Vec<&proc_macro2::TokenStream> =
+        col_infos.iter().map(|c| &c.arrow_name_ts).collect();
+    let column_docs: Vec<String> = col_infos
+        .iter()
+        .map(|c| format!("Column `{}`", c.arrow_field_name))
+        .collect();
+    let column_type_methods = if input.generics.params.is_empty() {
+        let col_idx_tokens: Vec<&syn::Index> = col_infos.iter().map(|c| &c.idx).collect();
+        let typed_docs = col_infos
+            .iter()
+            .map(|c| format!("Column `{}` with its value type", c.arrow_field_name));
+        quote! {
+            #(
+                #[doc = #typed_docs]
+                pub const #typed_columns: ::typed_arrow::schema::TypedColumn<#name, #col_idx_tokens> =
+                    ::typed_arrow::schema::TypedColumn::new();
+            )*
+            /// Arrow `DataType` of this column.
+            pub fn data_type(self) -> ::typed_arrow::arrow_schema::DataType {
+                match self {
+                    #( Self::#column_variants => <#name as ::typed_arrow::schema::ColAt<{ #col_idx_tokens }>>::data_type(), )*
+                }
+            }
+            /// Whether this column is nullable.
+            pub const fn is_nullable(self) -> bool {
+                match self {
+                    #( Self::#column_variants => <#name as ::typed_arrow::schema::ColAt<{ #col_idx_tokens }>>::NULLABLE, )*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let builders_len = match field_idents.first() {
+        Some(first) => {
+            quote! { ::typed_arrow::arrow_array::builder::ArrayBuilder::len(&self.#first) }
+        }
+        None => quote! { 0 },
+    };
+    let column_enum = quote! {
+        /// Columns of the record, in schema order.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum #column_ident {
+            #( #[doc = #column_docs] #column_variants, )*
+        }
+
+        impl #column_ident {
+            /// All columns in schema order.
+            pub const ALL: [Self; #len] = [#( Self::#column_variants ),*];
+            /// Zero-based column index.
+            pub const fn index(self) -> usize {
+                match self { #( Self::#column_variants => #column_idxs, )* }
+            }
+            /// Arrow field name.
+            pub const fn name(self) -> &'static str {
+                match self { #( Self::#column_variants => #column_names, )* }
+            }
+            /// Path to this column, extendable into nested struct fields.
+            pub fn path(self) -> ::typed_arrow::schema::ColumnPath {
+                ::typed_arrow::schema::ColumnPath::new(self)
+            }
+            #(
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals)]
+                pub const #field_consts: Self = Self::#column_variants;
+            )*
+            #column_type_methods
+        }
+
+        impl ::typed_arrow::schema::ColumnRef for #column_ident {
+            fn index(self) -> usize { Self::index(self) }
+            fn name(self) -> &'static str { Self::name(self) }
+        }
+
+        impl #base_impl_generics ::typed_arrow::schema::RecordColumns for #name #base_ty_generics #base_where_clause {
+            type Column = #column_ident;
+            const COLUMNS: &'static [#column_ident] = &#column_ident::ALL;
+        }
+    };
+
     let mut view_generics = base_generics.clone();
     prepend_view_lifetime(&mut view_generics, view_lt.clone());
     add_arrow_binding_view_bounds(&mut view_generics, &inner_tys_for_view, false);
@@ -362,6 +773,11 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let (view_record_impl_generics, view_record_ty_generics, view_record_where_clause) =
         view_record_generics.split_for_impl();
 
+    let mut append_view_generics = view_record_generics.clone();
+    add_append_view_bounds(&mut append_view_generics, &inner_tys_for_view);
+    let (append_view_impl_generics, append_view_ty_generics, append_view_where_clause) =
+        append_view_generics.split_for_impl();
+
     // impl Record and ForEachCol
     let rec_impl = quote! {
         impl #base_impl_generics ::typed_arrow::schema::Record for #name #base_ty_generics #base_where_clause {
@@ -393,9 +809,15 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 #(#child_builder_stmts)*
                 ::typed_arrow::arrow_array::builder::StructBuilder::new(fields, builders)
             }
+
+            fn struct_builder_memory_size(__sb: &::typed_arrow::arrow_array::builder::StructBuilder) -> usize {
+                let __fields = __sb.field_builders();
+                __sb.validity_slice().map_or(0, <[u8]>::len) #( + #child_memory_size_terms )*
+            }
         }
 
         impl #base_impl_generics ::typed_arrow::schema::SchemaMeta for #name #base_ty_generics #base_where_clause {
+            #selects_by_name
             fn fields() -> ::std::vec::Vec<::typed_arrow::arrow_schema::Field> {
                 let mut fields = ::std::vec::Vec::with_capacity(#len);
                 #(#child_field_stmts)*
@@ -406,6 +828,7 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 #(#schema_meta_inserts)*
                 __m
             }
+            #parquet_hints_fn
         }
 
         // Row-based: builders + arrays + construction
@@ -417,6 +840,50 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             #(#arrays_struct_fields,)*
         }
 
+        /// Owned column values, one `Vec` per field, for columnar construction.
+        pub struct #vecs_ident #base_ty_generics #base_where_clause {
+            #(#vecs_struct_fields,)*
+        }
+
+        impl #base_impl_generics #vecs_ident #base_ty_generics #base_where_clause {
+            /// Convert every column into its Arrow array. Primitive columns reuse the
+            /// `Vec` allocation as the value buffer; `Option` columns also get a
+            /// validity bitmap.
+            ///
+            /// # Errors
+            /// Returns `SchemaError::InvalidSchema` if the columns differ in length.
+            pub fn into_arrays(self) -> ::core::result::Result<#arrays_ident #base_ty_generics, ::typed_arrow::error::SchemaError> {
+                let __lens: [(&'static str, usize); #len] = [#( (#column_names, self.#field_idents.len()) ),*];
+                if let ::core::option::Option::Some(&(_, __expected)) = __lens.first() {
+                    for &(__name, __len) in &__lens {
+                        if __len != __expected {
+                            return ::core::result::Result::Err(::typed_arrow::error::SchemaError::invalid(
+                                ::std::format!("column `{}` has {} values, expected {}", __name, __len, __expected)
+                            ));
+                        }
+                    }
+                }
+                ::core::result::Result::Ok(#arrays_ident { #(#vecs_into_fields,)* })
+            }
+        }
+
+        /// Typed references to the columns of a RecordBatch.
+        pub struct #columns_ident #columns_ty_generics #columns_where_clause {
+            #(#columns_struct_fields,)*
+        }
+
+        impl #base_impl_generics ::typed_arrow::schema::FromRecordBatchColumns for #name #base_ty_generics #base_where_clause {
+            type Columns<#view_lt> = #columns_ident #columns_ty_generics where Self: #view_lt;
+
+            fn columns_from_record_batch(batch: &::typed_arrow::arrow_array::RecordBatch) -> ::core::result::Result<Self::Columns<'_>, ::typed_arrow::error::SchemaError> {
+                use ::typed_arrow::arrow_array::Array;
+                #column_count_check
+                ::core::result::Result::Ok(#columns_ident {
+                    #(#columns_init_fields,)*
+                })
+            }
+        }
+
         impl #base_impl_generics ::typed_arrow::schema::BuildRows for #name #base_ty_generics #base_where_clause {
             type Builders = #builders_ident #base_ty_generics;
             type Arrays = #arrays_ident #base_ty_generics;
@@ -436,6 +903,29 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 let #name { #( #field_idents ),* } = row;
                 #(#append_row_ref_stmts)*
             }
+            /// Append a non-null row, returning an error instead of panicking when a
+            /// value cannot be represented. Every field is checked before any column
+            /// is appended to; see `RowBuilder::try_append_row` for details.
+            pub fn try_append_row(&mut self, row: #name #base_ty_generics) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                {
+                    let #name { #( #field_idents ),* } = &row;
+                    #(#validate_field_stmts)*
+                    #(#reserve_field_stmts)*
+                }
+                self.append_row(row);
+                ::core::result::Result::Ok(())
+            }
+            /// Append rows until the first failure, which is reported with its row index.
+            /// Rows before the failing one stay appended.
+            pub fn try_append_rows<I: ::core::iter::IntoIterator<Item = #name #base_ty_generics>>(
+                &mut self,
+                rows: I,
+            ) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                for (i, r) in rows.into_iter().enumerate() {
+                    self.try_append_row(r).map_err(|e| e.at_row(i))?;
+                }
+                ::core::result::Result::Ok(())
+            }
             #[inline]
             pub fn append_null_row(&mut self) {
                 #(#append_null_row_stmts)*
@@ -456,7 +946,7 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             }
             #[inline]
             pub fn append_rows<I: ::core::iter::IntoIterator<Item = #name #base_ty_generics>>(&mut self, rows: I) {
-                for r in rows { self.append_row(r); }
+                #append_rows_body
             }
             #[inline]
             pub fn append_rows_ref<'a, I: ::core::iter::IntoIterator<Item = &'a #name #base_ty_generics>>(
@@ -466,7 +956,7 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             where
                 #name #base_ty_generics: 'a,
             {
-                for r in rows { self.append_row_ref(r); }
+                #append_rows_ref_body
             }
             #[inline]
             pub fn append_option_rows<I: ::core::iter::IntoIterator<Item = ::core::option::Option<#name #base_ty_generics>>>(&mut self, rows: I) {
@@ -486,6 +976,37 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             pub fn finish(self) -> #arrays_ident #base_ty_generics {
                 #arrays_ident { #(#finish_fields,)* }
             }
+            /// Finish the appended rows and leave the builders empty, ready for the
+            /// next batch.
+            pub fn finish_and_reset(&mut self) -> #arrays_ident #base_ty_generics {
+                #arrays_ident { #(#finish_reset_fields,)* }
+            }
+            /// Build arrays from the rows appended so far, leaving the builders untouched.
+            pub fn finish_cloned(&self) -> #arrays_ident #base_ty_generics
+            where
+                #( <#inner_tys_for_view as ::typed_arrow::bridge::ArrowBinding>::Array: ::core::clone::Clone, )*
+            {
+                #arrays_ident { #(#finish_cloned_fields,)* }
+            }
+            /// Number of rows appended since the builders were created or last finished.
+            pub fn len(&self) -> usize {
+                #builders_len
+            }
+            /// Whether no rows have been appended.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+            /// Bytes held by the appended rows of `column`, summed from its builder's
+            /// buffers (see [`ArrowBinding::builder_memory_size`](::typed_arrow::bridge::ArrowBinding::builder_memory_size)).
+            pub fn column_memory_size(&self, column: #column_ident) -> usize {
+                match column {
+                    #( #column_ident::#column_variants => <#inner_tys_for_view as ::typed_arrow::bridge::ArrowBinding>::builder_memory_size(&self.#field_idents), )*
+                }
+            }
+            /// Bytes held by the appended rows across all columns.
+            pub fn memory_size(&self) -> usize {
+                #column_ident::ALL.iter().map(|&c| self.column_memory_size(c)).sum()
+            }
         }
 
         // Implement the generic RowBuilder trait for the generated builders
@@ -499,9 +1020,22 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 &mut self,
                 rows: I,
             ) { Self::append_option_rows(self, rows) }
+            fn try_append_row(&mut self, row: #name #base_ty_generics) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                Self::try_append_row(self, row)
+            }
+            fn try_append_rows<I: ::core::iter::IntoIterator<Item = #name #base_ty_generics>>(
+                &mut self,
+                rows: I,
+            ) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> { Self::try_append_rows(self, rows) }
             fn finish(self) -> #arrays_ident #base_ty_generics { Self::finish(self) }
         }
 
+        impl #base_impl_generics ::typed_arrow::schema::ReusableRowBuilder<#name #base_ty_generics> for #builders_ident #base_ty_generics #base_where_clause {
+            fn finish_and_reset(&mut self) -> #arrays_ident #base_ty_generics { Self::finish_and_reset(self) }
+            fn len(&self) -> usize { Self::len(self) }
+            fn memory_size(&self) -> usize { Self::memory_size(self) }
+        }
+
         impl #base_impl_generics #arrays_ident #base_ty_generics #base_where_clause {
             /// Build an Arrow RecordBatch from these arrays and the generated schema.
             pub fn into_record_batch(self) -> ::typed_arrow::arrow_array::RecordBatch {
@@ -511,6 +1045,11 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 #( cols.push(Arc::new(self.#field_idents)); )*
                 ::typed_arrow::arrow_array::RecordBatch::try_new(schema, cols).expect("valid record batch")
             }
+
+            /// Build a [`TypedBatch`](::typed_arrow::TypedBatch), which needs no further schema checks.
+            pub fn into_typed_batch(self) -> ::typed_arrow::TypedBatch<#name #base_ty_generics> {
+                ::typed_arrow::TypedBatch::new_unchecked(self.into_record_batch())
+            }
         }
 
         impl #base_impl_generics ::typed_arrow::schema::IntoRecordBatch for #arrays_ident #base_ty_generics #base_where_clause {
@@ -532,6 +1071,16 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 let #name { #( #field_idents ),* } = self;
                 #(#append_struct_borrowed_stmts)*
             }
+            fn validate_fields(&self) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                let #name { #( #field_idents ),* } = self;
+                #(#validate_field_stmts)*
+                ::core::result::Result::Ok(())
+            }
+            fn reserve_fields(&self, __sb: &mut ::typed_arrow::arrow_array::builder::StructBuilder) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                let #name { #( #field_idents ),* } = self;
+                #(#reserve_struct_stmts)*
+                ::core::result::Result::Ok(())
+            }
         }
     };
 
@@ -584,6 +1133,8 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let mut view_extract_stmts = Vec::with_capacity(len);
     let mut struct_view_extract_stmts = Vec::with_capacity(len);
     let mut view_conversion_exprs = Vec::with_capacity(len);
+    let mut append_view_stmts = Vec::with_capacity(len);
+    let mut append_struct_view_stmts = Vec::with_capacity(len);
 
     for (i, f) in fields.named.iter().enumerate() {
         let fname = f.ident.as_ref().expect("named");
@@ -603,25 +1154,29 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         });
 
         // Initialize views arrays from RecordBatch columns - downcast with error handling
+        let batch_col_expr = &batch_col_exprs[i];
         views_init_fields.push(quote! {
-            #fname: batch.column(#idx)
-                .as_any()
-                .downcast_ref::<<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Array>()
-                .ok_or_else(|| ::typed_arrow::error::SchemaError::type_mismatch(
-                    <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::data_type(),
-                    batch.column(#idx).data_type().clone()
-                ))?,
+            #fname: {
+                let __col = #batch_col_expr;
+                __col
+                    .as_any()
+                    .downcast_ref::<<#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::Array>()
+                    .ok_or_else(|| ::typed_arrow::error::SchemaError::type_mismatch(
+                        <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding>::data_type(),
+                        __col.data_type().clone()
+                    ))?
+            },
         });
 
         // Extract value at index for each field (for iterator)
         if nullable {
             // For nullable fields, use Option<T>::get_view which handles nulls
             view_extract_stmts.push(quote! {
-                #fname: <::core::option::Option<#inner_ty_ts> as ::typed_arrow::bridge::ArrowBindingView>::get_view(self.#fname, self.index)?
+                #fname: <::core::option::Option<#inner_ty_ts> as ::typed_arrow::bridge::ArrowBindingView>::get_view(self.#fname, __row)?
             });
         } else {
             view_extract_stmts.push(quote! {
-                #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBindingView>::get_view(self.#fname, self.index)?
+                #fname: <#inner_ty_ts as ::typed_arrow::bridge::ArrowBindingView>::get_view(self.#fname, __row)?
             });
         }
 
@@ -659,6 +1214,37 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             });
         }
 
+        // Append view fields straight into builders (top-level and nested struct)
+        let append_view_path = quote! { <#inner_ty_ts as ::typed_arrow::bridge::AppendView> };
+        let binding_path = quote! { <#inner_ty_ts as ::typed_arrow::bridge::ArrowBinding> };
+        if nullable {
+            append_view_stmts.push(quote! {
+                match view.#fname {
+                    ::core::option::Option::Some(v) => #append_view_path::append_view(&mut self.#fname, v)?,
+                    ::core::option::Option::None => #binding_path::append_null(&mut self.#fname),
+                }
+            });
+            append_struct_view_stmts.push(quote! {
+                let cb: &mut #binding_path::Builder = __sb
+                    .field_builder::<#binding_path::Builder>({ #idx })
+                    .expect("child builder type matches");
+                match view.#fname {
+                    ::core::option::Option::Some(v) => #append_view_path::append_view(cb, v)?,
+                    ::core::option::Option::None => #binding_path::append_null(cb),
+                }
+            });
+        } else {
+            append_view_stmts.push(quote! {
+                #append_view_path::append_view(&mut self.#fname, view.#fname)?;
+            });
+            append_struct_view_stmts.push(quote! {
+                let cb: &mut #binding_path::Builder = __sb
+                    .field_builder::<#binding_path::Builder>({ #idx })
+                    .expect("child builder type matches");
+                #append_view_path::append_view(cb, view.#fname)?;
+            });
+        }
+
         // Generate view-to-owned conversion expression
         view_conversion_exprs.push(generate_view_conversion_expr(
             fname,
@@ -705,6 +1291,72 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 len: usize,
             }
 
+            impl #view_iter_impl_generics #views_ident #view_iter_ty_generics #view_iter_where_clause {
+                #[inline]
+                fn view_row(&self, __row: usize) -> ::core::result::Result<#view_ident #view_iter_ty_generics, ::typed_arrow::schema::ViewAccessError> {
+                    ::core::result::Result::Ok(#view_ident {
+                        #(#view_extract_stmts,)*
+                        _phantom: ::core::marker::PhantomData,
+                    })
+                }
+
+                /// View of the `i`-th remaining row, without advancing the iterator.
+                ///
+                /// # Errors
+                /// Returns `ViewAccessError::OutOfBounds` if `i` is not less than the number
+                /// of remaining rows, or the error raised while reading the row.
+                pub fn get(&self, i: usize) -> ::core::result::Result<#view_ident #view_iter_ty_generics, ::typed_arrow::schema::ViewAccessError> {
+                    let remaining = self.len - self.index;
+                    if i >= remaining {
+                        return ::core::result::Result::Err(::typed_arrow::schema::ViewAccessError::OutOfBounds {
+                            index: i,
+                            len: remaining,
+                            field_name: ::core::option::Option::None,
+                        });
+                    }
+                    self.view_row(self.index + i)
+                }
+
+                /// Sub-range of the remaining rows, relative to the current position.
+                ///
+                /// # Panics
+                /// Panics if the range is out of bounds or decreasing.
+                #[must_use]
+                pub fn slice<__R: ::core::ops::RangeBounds<usize>>(&self, range: __R) -> Self {
+                    use ::core::ops::Bound;
+                    let remaining = self.len - self.index;
+                    let start = match range.start_bound() {
+                        Bound::Included(&s) => s,
+                        Bound::Excluded(&s) => s + 1,
+                        Bound::Unbounded => 0,
+                    };
+                    let end = match range.end_bound() {
+                        Bound::Included(&e) => e + 1,
+                        Bound::Excluded(&e) => e,
+                        Bound::Unbounded => remaining,
+                    };
+                    assert!(
+                        start <= end && end <= remaining,
+                        "slice range {start}..{end} out of bounds for {remaining} rows"
+                    );
+                    #views_ident {
+                        #(#field_idents: self.#field_idents,)*
+                        index: self.index + start,
+                        len: self.index + end,
+                    }
+                }
+            }
+
+            impl #view_iter_impl_generics ::core::clone::Clone for #views_ident #view_iter_ty_generics #view_iter_where_clause {
+                fn clone(&self) -> Self {
+                    #views_ident {
+                        #(#field_idents: self.#field_idents,)*
+                        index: self.index,
+                        len: self.len,
+                    }
+                }
+            }
+
             impl #view_iter_impl_generics ::core::iter::Iterator for #views_ident #view_iter_ty_generics #view_iter_where_clause {
                 type Item = ::core::result::Result<#view_ident #view_iter_ty_generics, ::typed_arrow::schema::ViewAccessError>;
 
@@ -712,22 +1364,37 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                     if self.index >= self.len {
                         return ::core::option::Option::None;
                     }
-                    let result = (|| -> ::core::result::Result<#view_ident #view_iter_ty_generics, ::typed_arrow::schema::ViewAccessError> {
-                        ::core::result::Result::Ok(#view_ident {
-                            #(#view_extract_stmts,)*
-                            _phantom: ::core::marker::PhantomData,
-                        })
-                    })();
+                    let result = self.view_row(self.index);
                     self.index += 1;
                     ::core::option::Option::Some(result)
                 }
 
+                fn nth(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+                    self.index = self.index.saturating_add(n).min(self.len);
+                    self.next()
+                }
+
                 fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
                     let remaining = self.len - self.index;
                     (remaining, ::core::option::Option::Some(remaining))
                 }
             }
 
+            impl #view_iter_impl_generics ::core::iter::DoubleEndedIterator for #views_ident #view_iter_ty_generics #view_iter_where_clause {
+                fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                    if self.index >= self.len {
+                        return ::core::option::Option::None;
+                    }
+                    self.len -= 1;
+                    ::core::option::Option::Some(self.view_row(self.len))
+                }
+
+                fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
+                    self.len = self.len.saturating_sub(n).max(self.index);
+                    self.next_back()
+                }
+            }
+
             impl #view_iter_impl_generics ::core::iter::ExactSizeIterator for #views_ident #view_iter_ty_generics #view_iter_where_clause {
                 fn len(&self) -> usize {
                     self.len - self.index
@@ -739,13 +1406,7 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 type Views<#view_lt> = #views_ident #view_ty_generics;
 
                 fn from_record_batch(batch: &::typed_arrow::arrow_array::RecordBatch) -> ::core::result::Result<Self::Views<'_>, ::typed_arrow::error::SchemaError> {
-                    // Validate column count
-                    if batch.num_columns() != #len {
-                        return ::core::result::Result::Err(::typed_arrow::error::SchemaError::invalid(
-                            format!("Column count mismatch: expected {} columns for {}, but RecordBatch has {} columns",
-                                #len, stringify!(#name), batch.num_columns())
-                        ));
-                    }
+                    #column_count_check
 
                     // Downcast each column and validate types
                     ::core::result::Result::Ok(#views_ident {
@@ -756,6 +1417,46 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 }
             }
 
+            impl #append_view_impl_generics ::typed_arrow::schema::AppendStructView for #name #append_view_ty_generics #append_view_where_clause {
+                fn append_view_into(
+                    view: <Self as ::typed_arrow::schema::StructView>::View<'_>,
+                    __sb: &mut ::typed_arrow::arrow_array::builder::StructBuilder,
+                ) -> ::core::result::Result<(), ::typed_arrow::schema::ViewAccessError> {
+                    #(#append_struct_view_stmts)*
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            impl #append_view_impl_generics #builders_ident #append_view_ty_generics #append_view_where_clause {
+                /// Append a row view, e.g. from another batch, without converting it to
+                /// an owned record first.
+                ///
+                /// # Errors
+                /// Returns the `ViewAccessError` raised while reading a nested value. This only
+                /// happens for batches not validated against the record; the builders may then
+                /// hold a partial row and should be discarded.
+                ///
+                /// # Panics
+                /// Panics if a dictionary column's key type cannot index another distinct
+                /// value.
+                pub fn append_view(
+                    &mut self,
+                    view: <#name #append_view_ty_generics as ::typed_arrow::schema::StructView>::View<'_>,
+                ) -> ::core::result::Result<(), ::typed_arrow::schema::ViewAccessError> {
+                    #(#append_view_stmts)*
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            impl #append_view_impl_generics ::typed_arrow::schema::AppendViewRow<#name #append_view_ty_generics> for #builders_ident #append_view_ty_generics #append_view_where_clause {
+                fn append_view(
+                    &mut self,
+                    view: <#name #append_view_ty_generics as ::typed_arrow::schema::StructView>::View<'_>,
+                ) -> ::core::result::Result<(), ::typed_arrow::schema::ViewAccessError> {
+                    Self::append_view(self, view)
+                }
+            }
+
             impl #view_record_impl_generics ::typed_arrow::schema::StructView for #name #view_record_ty_generics #view_record_where_clause {
                 type View<#view_lt> = #view_ident #view_ty_generics;
 
@@ -779,6 +1480,8 @@ fn impl_record(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
 
     let expanded = quote! {
         #(#col_impls)*
+        #column_enum
+        #projection_impl
         #rec_impl
         #view_impl
         #(#record_macro_invocations)*
@@ -810,8 +1513,15 @@ fn check_no_legacy_nested_attr(attrs: &[Attribute]) -> syn::Result<()> {
                     // parsing
                     if let Ok(v) = meta.value() {
                         let _expr: syn::Expr = v.parse()?;
-                    } else {
-                        let _ = meta.parse_nested_meta(|_| Ok(()));
+                    } else if !meta.input.is_empty() {
+                        meta.parse_nested_meta(|inner| {
+                            if let Ok(v2) = inner.value() {
+                                let _expr: syn::Expr = v2.parse()?;
+                            } else if !inner.input.is_empty() {
+                                let _ = inner.parse_nested_meta(|_| Ok(()));
+                            }
+                            Ok(())
+                        })?;
                     }
                 }
                 Ok(())
@@ -828,6 +1538,61 @@ fn check_no_legacy_nested_attr(attrs: &[Attribute]) -> syn::Result<()> {
     Ok(())
 }
 
+/// `snake_case` (or raw) field identifier to an `UpperCamelCase` variant name.
+fn upper_camel(ident: &Ident) -> String {
+    let raw = ident.to_string();
+    let raw = raw.strip_prefix("r#").unwrap_or(&raw);
+    let mut out = String::with_capacity(raw.len());
+    let mut upper_next = true;
+    for ch in raw.chars() {
+        if ch == '_' {
+            upper_next = true;
+        } else if upper_next {
+            out.extend(ch.to_uppercase());
+            upper_next = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    // Variants cannot start with a digit (`_1`) or be the `Self` keyword (`self_`).
+    if out.is_empty() || out.starts_with(|c: char| c.is_ascii_digit()) || out == "Self" {
+        out.insert_str(0, "Column");
+    }
+    out
+}
+
+fn upper_snake(ident: &Ident) -> String {
+    let raw = ident.to_string();
+    raw.strip_prefix("r#").unwrap_or(&raw).to_uppercase()
+}
+
+/// Hidden column-enum constant naming the column of field `ident`, unique per field.
+fn field_const_ident(ident: &Ident) -> Ident {
+    let raw = ident.to_string();
+    let raw = raw.strip_prefix("r#").unwrap_or(&raw);
+    Ident::new(&format!("__field_{raw}"), ident.span())
+}
+
+/// Make per-field item names unique: a name shared by several fields, or in `reserved`,
+/// gets `{sep}{index}` appended until it clashes with no other name.
+fn disambiguate(names: &[String], sep: &str, reserved: &[String]) -> Vec<String> {
+    let shared = |n: &String| names.iter().filter(|m| *m == n).count() > 1;
+    let mut out: Vec<String> = Vec::with_capacity(names.len());
+    for (i, n) in names.iter().enumerate() {
+        let mut name = n.clone();
+        if shared(n) || reserved.contains(n) {
+            loop {
+                name.push_str(&format!("{sep}{i}"));
+                if !names.contains(&name) && !reserved.contains(&name) && !out.contains(&name) {
+                    break;
+                }
+            }
+        }
+        out.push(name);
+    }
+    out
+}
+
 fn unwrap_option(ty: &Type) -> (Type, bool) {
     if let Type::Path(tp) = ty
         && let Some(seg) = tp.path.segments.last()
@@ -951,6 +1716,18 @@ fn add_arrow_binding_view_bounds(
     }
 }
 
+fn add_append_view_bounds(generics: &mut Generics, inner_tys: &[proc_macro2::TokenStream]) {
+    if inner_tys.is_empty() {
+        return;
+    }
+    let where_clause = generics.make_where_clause();
+    for ty in inner_tys {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::typed_arrow::bridge::AppendView));
+    }
+}
+
 fn add_view_lifetime_bounds(
     generics: &mut Generics,
     inner_tys: &[proc_macro2::TokenStream],
@@ -1007,6 +1784,31 @@ fn generate_view_type(ty: &Type, nullable: bool, view_lt: &Lifetime) -> proc_mac
     }
 }
 
+/// Whether `ty` is a std primitive whose binding appends slices in one call.
+fn is_bulk_primitive(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty
+        && type_path.qself.is_none()
+        && type_path.path.segments.len() == 1
+    {
+        let segment = &type_path.path.segments[0];
+        return segment.arguments.is_empty()
+            && matches!(
+                segment.ident.to_string().as_str(),
+                "i8" | "i16"
+                    | "i32"
+                    | "i64"
+                    | "u8"
+                    | "u16"
+                    | "u32"
+                    | "u64"
+                    | "f32"
+                    | "f64"
+                    | "bool"
+            );
+    }
+    false
+}
+
 /// Check if a type is a Copy value type where View<'a> = Self.
 /// This includes primitives and temporal types.
 fn is_copy_primitive(ty: &Type) -> bool {
@@ -1052,6 +1854,10 @@ fn is_string(ty: &Type) -> bool {
     false
 }
 
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("bool"))
+}
+
 /// Check if a type is a fixed-size byte array [u8; N].
 fn is_fixed_size_binary(ty: &Type) -> bool {
     if let Type::Array(type_array) = ty
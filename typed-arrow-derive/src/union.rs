@@ -70,6 +70,8 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let mut builder_idents: Vec<Ident> = Vec::with_capacity(n);
     let mut match_arms_append = Vec::with_capacity(n);
     let mut field_pairs = Vec::with_capacity(n);
+    let mut validate_arms = Vec::with_capacity(n);
+    let mut reserve_arms = Vec::with_capacity(n);
     for (idx, (v_ident, v_ty)) in var_idents.iter().zip(var_types.iter()).enumerate() {
         let bname = Ident::new(&format!("b{idx}"), name.span());
         builder_idents.push(bname.clone());
@@ -90,6 +92,13 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
             }
         });
 
+        validate_arms.push(quote! {
+            #name::#v_ident(inner) => <#v_ty as ::typed_arrow::bridge::ArrowBinding>::validate_value(inner)
+        });
+        reserve_arms.push(quote! {
+            #name::#v_ident(inner) => <#v_ty as ::typed_arrow::bridge::ArrowBinding>::reserve_value(&mut b.#bname, inner)
+        });
+
         // Field pair for UnionFields
         let v_name_str = &field_names[idx];
         field_pairs.push(quote! { (#tag, ::std::sync::Arc::new(::typed_arrow::arrow_schema::Field::new(#v_name_str, <#v_ty as ::typed_arrow::bridge::ArrowBinding>::data_type(), true))) });
@@ -161,6 +170,14 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 match v { #(#match_arms_append,)* }
             }
 
+            fn validate_value(v: &Self) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                match v { #(#validate_arms,)* }
+            }
+
+            fn reserve_value(b: &mut Self::Builder, v: &Self) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                match v { #(#reserve_arms,)* }
+            }
+
             fn append_null(b: &mut Self::Builder) {
                 // Encode nulls into the configured null-carrying variant
                 b.type_ids.push(#null_tag);
@@ -179,6 +196,11 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 let offsets: ::typed_arrow::arrow_buffer::ScalarBuffer<i32> = b.offsets.into_iter().collect();
                 ::typed_arrow::arrow_array::UnionArray::try_new(fields, type_ids, Some(offsets), children).expect("valid dense union")
             }
+
+            fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+                let array = ::typed_arrow::arrow_array::builder::ArrayBuilder::finish(b);
+                ::typed_arrow::arrow_array::cast::AsArray::as_union(&*array).clone()
+            }
         }
 
         // Implement ArrayBuilder so this union can be used as a struct field builder
@@ -295,6 +317,14 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 match v { #(#sparse_match_arms,)* }
             }
 
+            fn validate_value(v: &Self) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                match v { #(#validate_arms,)* }
+            }
+
+            fn reserve_value(b: &mut Self::Builder, v: &Self) -> ::core::result::Result<(), ::typed_arrow::error::AppendError> {
+                match v { #(#reserve_arms,)* }
+            }
+
             fn append_null(b: &mut Self::Builder) {
                 b.type_ids.push(#null_tag);
                 #(#sparse_append_null_all)*
@@ -308,6 +338,11 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 let type_ids: ::typed_arrow::arrow_buffer::ScalarBuffer<i8> = b.type_ids.into_iter().collect();
                 ::typed_arrow::arrow_array::UnionArray::try_new(fields, type_ids, None, children).expect("valid sparse union")
             }
+
+            fn finish_and_reset(b: &mut Self::Builder) -> Self::Array {
+                let array = ::typed_arrow::arrow_array::builder::ArrayBuilder::finish(b);
+                ::typed_arrow::arrow_array::cast::AsArray::as_union(&*array).clone()
+            }
         }
 
         // Implement ArrayBuilder so this union can be used as a struct field builder
@@ -381,6 +416,35 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
         });
     }
 
+    // Append view arms: write the child first so an error leaves no dangling type id
+    let mut append_view_arms = Vec::with_capacity(n);
+    for (idx, (v_ident, v_ty)) in var_idents.iter().zip(var_types.iter()).enumerate() {
+        let tag = tags_i8[idx];
+        let bi = &builder_idents[idx];
+        if is_sparse {
+            let null_others = var_types.iter().enumerate().filter(|(j, _)| *j != idx).map(|(j, v_ty_j)| {
+                let bj = &builder_idents[j];
+                quote! { <#v_ty_j as ::typed_arrow::bridge::ArrowBinding>::append_null(&mut b.#bj); }
+            });
+            append_view_arms.push(quote! {
+                #view_ident::#v_ident(inner) => {
+                    <#v_ty as ::typed_arrow::bridge::AppendView>::append_view(&mut b.#bi, inner)?;
+                    #(#null_others)*
+                    b.type_ids.push(#tag);
+                }
+            });
+        } else {
+            append_view_arms.push(quote! {
+                #view_ident::#v_ident(inner) => {
+                    <#v_ty as ::typed_arrow::bridge::AppendView>::append_view(&mut b.#bi, inner)?;
+                    b.type_ids.push(#tag);
+                    b.offsets.push(b.slots[#idx] as i32);
+                    b.slots[#idx] += 1;
+                }
+            });
+        }
+    }
+
     let view_impl = if cfg!(feature = "views") {
         quote! {
             // View enum for union types
@@ -426,6 +490,21 @@ fn impl_union(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
                 }
             }
 
+            impl ::typed_arrow::bridge::AppendView for #name
+            where
+                #(#var_types: ::typed_arrow::bridge::AppendView + 'static,)*
+            {
+                fn append_view(
+                    b: &mut Self::Builder,
+                    v: Self::View<'_>,
+                ) -> ::core::result::Result<(), ::typed_arrow::schema::ViewAccessError> {
+                    match v {
+                        #(#append_view_arms)*
+                    }
+                    ::core::result::Result::Ok(())
+                }
+            }
+
             // TryFrom implementation for converting view to owned
             impl<'a> ::core::convert::TryFrom<#view_ident<'a>> for #name
             where